@@ -1,12 +1,23 @@
 use eframe::egui;
 
 use crate::app::config_ui;
+use crate::app::diagnostics_ui;
 use crate::app::game_ui;
+use crate::app::settings::{
+    AppSettings, BuzzerSource, LaunchMode, PanelPosition, ScorePrivacy, TeamNaming,
+};
+use crate::app::spectator_ui;
 use crate::core::storage::{self, Snapshot};
 use crate::core::{Board, ConfigState};
 use crate::game::GameEngine;
+use crate::game::buzzer::{self, BuzzerInput};
+use crate::game::events::{EventTiming, StealDirection, StealRounding};
+use crate::game::rules::FirstTeamPolicy;
+use crate::theme::animations::AnimationIntensity;
+use crate::theme::performance::{self, QualityPreset};
+use crate::theme::transitions::PerformanceMonitor;
 use crate::theme::{self, Palette};
-use crate::ui::{HeaderAnimationManager, HeaderState};
+use crate::ui::{FocusTrap, HeaderAnimationManager, HeaderState, ToastKind, ToastQueue};
 
 #[derive(Debug)]
 pub enum AppMode {
@@ -19,30 +30,127 @@ pub struct PartyJeopardyApp {
     // UI state
     show_save_dialog: bool,
     show_load_dialog: bool,
+    show_settings_dialog: bool,
+    // Second OS window for the projector while a game is running
+    show_display_window: bool,
     save_name: String,
+    settings: AppSettings,
+    // Short-lived notifications drawn top-right
+    toasts: ToastQueue,
     // Enhanced UI systems
     header_animation_manager: HeaderAnimationManager,
+    // Physical buzzers, when the host launched with --buzzers
+    buzzer: Option<Box<dyn BuzzerInput>>,
+    // The large-board quality hint is only shown once per session
+    quality_hint_shown: bool,
+    // F3 debug readout and the frame timing it reports
+    show_diagnostics: bool,
+    frame_monitor: PerformanceMonitor,
 }
 
 impl PartyJeopardyApp {
-    pub fn new(_cc: &eframe::CreationContext<'_>) -> Self {
-        theme::apply_global_style(&_cc.egui_ctx);
-        let default_board = Board::default_with_dimensions(6, 5);
-        let config = ConfigState {
-            board: default_board,
+    pub fn new(
+        _cc: &eframe::CreationContext<'_>,
+        launch: LaunchMode,
+        buzzers: Option<BuzzerSource>,
+    ) -> Self {
+        theme::apply_global_style(&_cc.egui_ctx, theme::ContrastMode::Standard);
+        egui_extras::install_image_loaders(&_cc.egui_ctx);
+        let mut toasts = ToastQueue::default();
+        // Preload the host's startup board when there is one
+        let startup =
+            storage::startup_board_path().and_then(|path| storage::load_startup_board(&path));
+        let board = match startup {
+            Ok(Some(board)) => board,
+            Ok(None) => Board::default_with_dimensions(6, 5),
+            Err(err) => {
+                log::warn!("ignoring startup board: {}", err);
+                toasts.push(
+                    ToastKind::Error,
+                    format!("Couldn't load startup_board.json: {}", err),
+                );
+                Board::default_with_dimensions(6, 5)
+            }
         };
+        let buzzer = buzzers.and_then(|source| match source.open() {
+            Ok(input) => Some(Box::new(input) as Box<dyn BuzzerInput>),
+            Err(err) => {
+                toasts.push(ToastKind::Error, format!("Couldn't open buzzers: {}", err));
+                None
+            }
+        });
+        let config = ConfigState { board };
         Self {
             mode: AppMode::Config(config),
             show_save_dialog: false,
             show_load_dialog: false,
+            show_settings_dialog: false,
+            show_display_window: false,
             save_name: String::new(),
+            settings: AppSettings {
+                hide_top_bar: launch == LaunchMode::Kiosk,
+                ..AppSettings::default()
+            },
+            toasts,
             header_animation_manager: HeaderAnimationManager::new(),
+            buzzer,
+            quality_hint_shown: false,
+            show_diagnostics: false,
+            frame_monitor: PerformanceMonitor::new(),
+        }
+    }
+}
+
+/// Pick numbered or preset default team names; the preset list is one name per line
+fn team_naming_editor(ui: &mut egui::Ui, naming: &mut TeamNaming) {
+    let list_id = egui::Id::new("team_naming_list");
+    ui.horizontal(|ui| {
+        ui.label("New team names");
+        let numbered = matches!(naming, TeamNaming::Numbered(_));
+        if ui.radio(numbered, "Numbered").clicked() && !numbered {
+            *naming = TeamNaming::default();
+        }
+        if ui.radio(!numbered, "From a list").clicked() && numbered {
+            *naming = TeamNaming::FromList(Vec::new());
+            ui.data_mut(|d| d.remove::<String>(list_id));
+        }
+    });
+    match naming {
+        TeamNaming::Numbered(pattern) => {
+            ui.add(egui::TextEdit::singleline(pattern).hint_text("Squad {n}"))
+                .on_hover_text("{n} becomes the team's number");
+        }
+        TeamNaming::FromList(names) => {
+            // Keep the raw text so blank lines survive while typing
+            let mut text: String = ui
+                .data_mut(|d| d.get_temp(list_id))
+                .unwrap_or_else(|| names.join("\n"));
+            if ui
+                .add(
+                    egui::TextEdit::multiline(&mut text)
+                        .hint_text("One name per line")
+                        .desired_rows(3),
+                )
+                .changed()
+            {
+                *names = text
+                    .lines()
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                    .map(str::to_string)
+                    .collect();
+            }
+            ui.data_mut(|d| d.insert_temp(list_id, text));
         }
     }
 }
 
 impl eframe::App for PartyJeopardyApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        // Time from one frame's start to the next, so the readout reflects real frame rate
+        self.frame_monitor.frame_end();
+        self.frame_monitor.frame_start();
+
         // Update header animations
         let header_needs_repaint = self.header_animation_manager.update();
         if header_needs_repaint {
@@ -61,13 +169,45 @@ impl eframe::App for PartyJeopardyApp {
                 .transition_to(target_header_state);
         }
 
+        // Buzzes only matter while a game runs; keep polling so presses show up promptly
+        let buzzed = match (self.buzzer.as_deref_mut(), &mut self.mode) {
+            (Some(input), AppMode::Game(game_engine)) => buzzer::feed_buzzes(game_engine, input)
+                .map(|team_id| {
+                    game_engine
+                        .get_state()
+                        .teams
+                        .iter()
+                        .find(|t| t.id == team_id)
+                        .map(|t| t.label())
+                        .unwrap_or_else(|| format!("Team #{}", team_id))
+                }),
+            _ => None,
+        };
+        if let Some(name) = buzzed {
+            self.toasts
+                .push(ToastKind::Success, format!("{} buzzed in", name));
+        }
+        if self.buzzer.is_some() {
+            ctx.request_repaint_after(std::time::Duration::from_millis(50));
+        }
+
+        performance::set_quality(ctx, self.settings.quality);
+
+        // F10 brings the top bar back (or hides it again) in kiosk mode
+        if ctx.input(|i| i.key_pressed(egui::Key::F10)) {
+            self.settings.hide_top_bar = !self.settings.hide_top_bar;
+        }
+        if ctx.input(|i| i.key_pressed(egui::Key::F3)) {
+            self.show_diagnostics = !self.show_diagnostics;
+        }
+
         egui::TopBottomPanel::top("top_bar")
             .frame(
                 egui::Frame::none()
                     .fill(Palette::BG_DARK)
                     .inner_margin(egui::Margin::symmetric(12.0, 8.0)),
             )
-            .show(ctx, |ui| {
+            .show_animated(ctx, !self.settings.hide_top_bar, |ui| {
                 ui.horizontal(|ui| {
                     // Update header elements based on current mode
                     let title_pos = ui.next_widget_position();
@@ -131,6 +271,33 @@ impl eframe::App for PartyJeopardyApp {
                             if theme::accent_button(ui, "Save").clicked() {
                                 self.show_save_dialog = true;
                             }
+
+                            if theme::secondary_button(ui, "Settings").clicked() {
+                                self.show_settings_dialog = true;
+                            }
+
+                            let contrast_label = match self.settings.contrast {
+                                theme::ContrastMode::Standard => "High Contrast",
+                                theme::ContrastMode::High => "Standard Contrast",
+                            };
+                            if theme::secondary_button(ui, contrast_label).clicked() {
+                                self.settings.contrast = match self.settings.contrast {
+                                    theme::ContrastMode::Standard => theme::ContrastMode::High,
+                                    theme::ContrastMode::High => theme::ContrastMode::Standard,
+                                };
+                                theme::apply_global_style(ui.ctx(), self.settings.contrast);
+                            }
+
+                            if !in_config {
+                                let label = if self.show_display_window {
+                                    "Close Display"
+                                } else {
+                                    "Open Display"
+                                };
+                                if theme::secondary_button(ui, label).clicked() {
+                                    self.show_display_window = !self.show_display_window;
+                                }
+                            }
                         },
                     );
                 });
@@ -145,38 +312,52 @@ impl eframe::App for PartyJeopardyApp {
                 .resizable(false)
                 .frame(theme::window_frame())
                 .show(ctx, |ui| {
+                    let mut focus = FocusTrap::new("save_dialog");
                     ui.set_min_width(320.0);
                     ui.label(
                         egui::RichText::new("Enter a name for the save file").color(Palette::CYAN),
                     );
-                    ui.text_edit_singleline(&mut self.save_name);
+                    focus.add(&ui.text_edit_singleline(&mut self.save_name));
                     ui.add_space(6.0);
                     ui.horizontal(|ui| {
-                        if theme::accent_button(ui, "Save").clicked() {
+                        let save = theme::accent_button(ui, "Save");
+                        focus.add(&save);
+                        if save.clicked() {
                             let snapshot = match &self.mode {
                                 AppMode::Config(cfg) => Snapshot {
                                     board: cfg.board.clone(),
                                     game: None,
+                                    rules: self.settings.rules.clone(),
+                                    events: self.settings.events.clone(),
                                 },
-                                AppMode::Game(game_engine) => Snapshot {
-                                    board: game_engine.get_state().board.clone(),
-                                    game: Some(game_engine.get_state().clone()),
-                                },
+                                AppMode::Game(game_engine) => Snapshot::of_game(game_engine),
                             };
-                            if let Ok(path) =
-                                storage::save_snapshot_named(&self.save_name, &snapshot)
-                            {
-                                self.show_save_dialog = false;
-                                self.save_name.clear();
-                                ui.output_mut(|o| {
-                                    o.copied_text = format!("Saved: {}", path.display())
-                                });
+                            match storage::save_snapshot_named(&self.save_name, &snapshot) {
+                                Ok(path) => {
+                                    self.show_save_dialog = false;
+                                    self.save_name.clear();
+                                    ui.output_mut(|o| {
+                                        o.copied_text = format!("Saved: {}", path.display())
+                                    });
+                                    self.toasts.push(
+                                        ToastKind::Success,
+                                        format!("Saved {}", path.display()),
+                                    );
+                                }
+                                Err(err) => self
+                                    .toasts
+                                    .push(ToastKind::Error, format!("Save failed: {}", err)),
                             }
                         }
-                        if theme::secondary_button(ui, "Cancel").clicked() {
+                        let cancel = theme::secondary_button(ui, "Cancel");
+                        focus.add(&cancel);
+                        if cancel.clicked() {
                             self.show_save_dialog = false;
                         }
                     });
+                    if focus.finish(ui.ctx()) {
+                        self.show_save_dialog = false;
+                    }
                 });
             self.show_save_dialog = open && self.show_save_dialog; // respect close button
         }
@@ -190,6 +371,7 @@ impl eframe::App for PartyJeopardyApp {
                 .resizable(false)
                 .frame(theme::window_frame())
                 .show(ctx, |ui| {
+                    let mut focus = FocusTrap::new("load_dialog");
                     ui.set_min_width(340.0);
                     match storage::list_saves() {
                         Ok(files) => {
@@ -206,22 +388,36 @@ impl eframe::App for PartyJeopardyApp {
                             for path in files {
                                 let label =
                                     path.file_stem().and_then(|s| s.to_str()).unwrap_or("?");
-                                if theme::secondary_button(ui, label).clicked() {
-                                    if let Ok(snapshot) = storage::load_snapshot_from_path(&path) {
-                                        match snapshot.game {
-                                            Some(game_state) => {
-                                                let mut game_engine =
-                                                    GameEngine::new(game_state.board.clone());
-                                                *game_engine.get_state_mut() = game_state;
+                                let button = theme::secondary_button(ui, label);
+                                focus.add(&button);
+                                if button.clicked() {
+                                    let loaded = storage::load_snapshot_from_path(&path);
+                                    if let Err(err) = &loaded {
+                                        self.toasts.push(
+                                            ToastKind::Error,
+                                            format!("Couldn't load {}: {}", label, err),
+                                        );
+                                    }
+                                    if let Ok(snapshot) = loaded {
+                                        // Settings feed the engine every frame, so adopt the
+                                        // saved rules there
+                                        self.settings.rules = snapshot.rules.clone();
+                                        self.settings.events = snapshot.events.clone();
+                                        let board = snapshot.board.clone();
+                                        match snapshot.into_engine() {
+                                            Some(game_engine) => {
                                                 self.mode = AppMode::Game(game_engine);
+                                                // Drop any flash or pending answer left from the
+                                                // game that was on screen
+                                                game_ui::forget_in_flight_state(ui.ctx());
                                             }
                                             None => {
-                                                self.mode = AppMode::Config(ConfigState {
-                                                    board: snapshot.board,
-                                                })
+                                                self.mode = AppMode::Config(ConfigState { board })
                                             }
                                         }
                                         self.show_load_dialog = false;
+                                        self.toasts
+                                            .push(ToastKind::Success, format!("Loaded {}", label));
                                     }
                                 }
                             }
@@ -233,24 +429,479 @@ impl eframe::App for PartyJeopardyApp {
                             );
                         }
                     }
-                    if theme::accent_button(ui, "Close").clicked() {
+                    let close = theme::accent_button(ui, "Close");
+                    focus.add(&close);
+                    let escaped = focus.finish(ui.ctx());
+                    if close.clicked() || escaped {
                         self.show_load_dialog = false;
                     }
                 });
             self.show_load_dialog = open && self.show_load_dialog;
         }
 
+        // Settings window
+        if self.show_settings_dialog {
+            let mut open = true;
+            egui::Window::new("Settings")
+                .open(&mut open)
+                .collapsible(false)
+                .resizable(false)
+                .frame(theme::window_frame())
+                .show(ctx, |ui| {
+                    ui.set_min_width(320.0);
+                    ui.label(egui::RichText::new("Pacing").color(Palette::CYAN));
+                    let mut clock_enabled = self.settings.selection_time_limit_secs.is_some();
+                    if ui.checkbox(&mut clock_enabled, "Selection clock").changed() {
+                        self.settings.selection_time_limit_secs =
+                            if clock_enabled { Some(30) } else { None };
+                    }
+                    if let Some(secs) = self.settings.selection_time_limit_secs.as_mut() {
+                        ui.horizontal(|ui| {
+                            ui.label("Seconds to pick a clue");
+                            ui.add(egui::DragValue::new(secs).clamp_range(5..=300));
+                        });
+                    }
+                    let mut steal_clock = self.settings.steal_timer_secs.is_some();
+                    if ui.checkbox(&mut steal_clock, "Steal clock").changed() {
+                        self.settings.steal_timer_secs = if steal_clock { Some(10) } else { None };
+                    }
+                    if let Some(secs) = self.settings.steal_timer_secs.as_mut() {
+                        ui.horizontal(|ui| {
+                            ui.label("Seconds per steal attempt");
+                            ui.add(egui::DragValue::new(secs).clamp_range(3..=120));
+                        });
+                    }
+                    ui.checkbox(
+                        &mut self.settings.typewriter_reveal,
+                        "Reveal questions progressively",
+                    );
+                    ui.checkbox(
+                        &mut self.settings.clue_zoom,
+                        "Zoom the clicked cell into the question",
+                    );
+                    ui.horizontal(|ui| {
+                        ui.label("Answer flash (ms)");
+                        let mut ms = self.settings.flash_duration().as_millis() as u32;
+                        if ui
+                            .add(
+                                egui::DragValue::new(&mut ms)
+                                    .clamp_range(200..=3000)
+                                    .speed(10.0),
+                            )
+                            .changed()
+                        {
+                            self.settings.flash_duration_ms = Some(ms);
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Answer animations");
+                        let intensity = &mut self.settings.animation_intensity;
+                        ui.radio_value(intensity, AnimationIntensity::Subtle, "Subtle");
+                        ui.radio_value(intensity, AnimationIntensity::Normal, "Normal");
+                        ui.radio_value(intensity, AnimationIntensity::Intense, "Intense");
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Quality");
+                        for preset in QualityPreset::ALL {
+                            ui.radio_value(&mut self.settings.quality, preset, preset.label());
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Suggest lower quality above");
+                        let mut clues = self.settings.large_board_threshold();
+                        if ui
+                            .add(
+                                egui::DragValue::new(&mut clues)
+                                    .clamp_range(1..=200)
+                                    .suffix(" clues"),
+                            )
+                            .changed()
+                        {
+                            self.settings.large_board_clues = Some(clues);
+                        }
+                    });
+                    let mut aspect_locked = self.settings.board_aspect.is_some();
+                    if ui
+                        .checkbox(&mut aspect_locked, "Lock board to 16:9 (projector)")
+                        .changed()
+                    {
+                        self.settings.board_aspect = aspect_locked.then_some(16.0 / 9.0);
+                    }
+                    ui.checkbox(
+                        &mut self.settings.hide_top_bar,
+                        "Kiosk mode: hide the top bar (F10 toggles)",
+                    );
+                    ui.checkbox(
+                        &mut self.settings.skip_team_wizard,
+                        "Skip the team setup prompt",
+                    );
+                    ui.checkbox(
+                        &mut self.settings.review_solved,
+                        "Click solved clues to review them",
+                    );
+                    ui.checkbox(
+                        &mut self.settings.host_hover_preview,
+                        "Preview questions on hover (host only)",
+                    );
+                    ui.checkbox(
+                        &mut self.settings.show_answer_to_host_on_steal,
+                        "Show the answer during steals (host only)",
+                    );
+                    ui.checkbox(
+                        &mut self.settings.reveal_answer_on_click,
+                        "Reveal answers on a click after each clue",
+                    );
+                    ui.checkbox(
+                        &mut self.settings.answer_assist,
+                        "Answer match hint while judging (host only)",
+                    )
+                    .on_hover_text(
+                        "Type what a team said to see how close it is; you still decide",
+                    );
+                    ui.checkbox(
+                        &mut self.settings.announce_events,
+                        "Announce events and wait for acknowledgement",
+                    );
+                    ui.horizontal(|ui| {
+                        ui.label("Teams panel");
+                        let position = &mut self.settings.team_panel_position;
+                        ui.radio_value(position, PanelPosition::Left, "Left");
+                        ui.radio_value(position, PanelPosition::Right, "Right");
+                        ui.radio_value(position, PanelPosition::Top, "Top");
+                        ui.radio_value(position, PanelPosition::Bottom, "Bottom");
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Scores during play");
+                        let privacy = &mut self.settings.score_privacy;
+                        ui.radio_value(privacy, ScorePrivacy::Open, "Everyone's");
+                        ui.radio_value(privacy, ScorePrivacy::ActiveTeamOnly, "Active team's")
+                            .on_hover_text("Other teams show — until the game is finished");
+                        ui.radio_value(privacy, ScorePrivacy::Hidden, "Hidden");
+                    });
+                    let mut confirm_enabled = self.settings.confirm_high_value_at.is_some();
+                    if ui
+                        .checkbox(&mut confirm_enabled, "Confirm high-value outcomes")
+                        .changed()
+                    {
+                        self.settings.confirm_high_value_at = confirm_enabled.then_some(1000);
+                    }
+                    if let Some(threshold) = self.settings.confirm_high_value_at.as_mut() {
+                        ui.horizontal(|ui| {
+                            ui.label("Ask for clues worth at least");
+                            ui.add(
+                                egui::DragValue::new(threshold)
+                                    .clamp_range(100..=10_000)
+                                    .speed(50.0),
+                            );
+                        });
+                    }
+                    ui.add_space(6.0);
+                    ui.label(egui::RichText::new("Rules").color(Palette::CYAN));
+                    let mut steal_factor = self.settings.rules.steal_points_factor;
+                    ui.horizontal(|ui| {
+                        ui.label("Steal award");
+                        ui.radio_value(&mut steal_factor, 1.0, "Full value");
+                        ui.radio_value(&mut steal_factor, 0.5, "Half value");
+                    });
+                    self.settings.rules.set_steal_points_factor(steal_factor);
+                    ui.checkbox(
+                        &mut self.settings.rules.double_points_on_steal,
+                        "Double Points doubles steals too",
+                    );
+                    let mut reverse_multiplier = self.settings.rules.reverse_question_multiplier;
+                    ui.horizontal(|ui| {
+                        ui.label("Reverse Question points ×");
+                        ui.add(
+                            egui::DragValue::new(&mut reverse_multiplier)
+                                .clamp_range(0.0..=3.0)
+                                .speed(0.05)
+                                .fixed_decimals(2),
+                        );
+                    });
+                    self.settings
+                        .rules
+                        .set_reverse_question_multiplier(reverse_multiplier);
+                    ui.horizontal(|ui| {
+                        ui.label("First pick");
+                        ui.radio_value(
+                            &mut self.settings.rules.first_team,
+                            FirstTeamPolicy::FirstAdded,
+                            "First team added",
+                        );
+                        ui.radio_value(
+                            &mut self.settings.rules.first_team,
+                            FirstTeamPolicy::Random,
+                            "Random team",
+                        );
+                    });
+                    ui.checkbox(
+                        &mut self.settings.rules.require_ascending_selection,
+                        "Clues must be taken in point order",
+                    );
+                    ui.checkbox(
+                        &mut self.settings.rules.refund_owner_on_failed_steals,
+                        "Refund owner when every steal fails",
+                    );
+                    ui.checkbox(
+                        &mut self.settings.rules.audience_claims,
+                        "Audience can claim a clue every stealer missed",
+                    );
+                    ui.add_enabled(
+                        self.settings.rules.audience_claims,
+                        egui::Checkbox::new(
+                            &mut self.settings.rules.audience_claim_scores,
+                            "Keep an audience score",
+                        ),
+                    );
+                    ui.checkbox(
+                        &mut self.settings.rules.show_cell_values,
+                        "Show point values on the board",
+                    );
+                    ui.add_enabled(
+                        !self.settings.rules.show_cell_values,
+                        egui::Checkbox::new(
+                            &mut self.settings.rules.hide_points_until_resolved,
+                            "Keep values hidden until the clue resolves",
+                        ),
+                    );
+                    ui.checkbox(
+                        &mut self.settings.rules.shuffle_categories,
+                        "Shuffle category order each game",
+                    );
+                    ui.checkbox(
+                        &mut self.settings.rules.keep_turn_on_correct,
+                        "Correct answers keep the turn",
+                    );
+                    ui.checkbox(
+                        &mut self.settings.rules.control_to_stealer,
+                        "A correct steal takes control",
+                    );
+                    ui.horizontal(|ui| {
+                        ui.label("Random two-attempt clues");
+                        ui.add(
+                            egui::DragValue::new(&mut self.settings.rules.bonus_attempt_clues)
+                                .clamp_range(0..=10),
+                        )
+                        .on_hover_text("Drawn each game on top of the clues worth over 500");
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Consolation for a missed answer");
+                        ui.add(
+                            egui::DragValue::new(&mut self.settings.rules.consolation_points)
+                                .clamp_range(0..=1000)
+                                .speed(10.0),
+                        );
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Score steal rounding");
+                        let rounding = &mut self.settings.events.steal_rounding;
+                        ui.radio_value(rounding, StealRounding::Floor, "Down");
+                        ui.radio_value(rounding, StealRounding::Round, "Nearest");
+                        ui.radio_value(rounding, StealRounding::Ceil, "Up");
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Score steal goes");
+                        let direction = &mut self.settings.events.steal_direction;
+                        ui.radio_value(direction, StealDirection::LeaderToTrailer, "To trailer");
+                        ui.radio_value(direction, StealDirection::TrailerToLeader, "To leader");
+                        ui.radio_value(direction, StealDirection::Random, "Random");
+                    });
+                    ui.label("Event weights");
+                    let (chances, no_event_chance) = self.settings.events.draw_chances();
+                    egui::Grid::new("event_weights").show(ui, |ui| {
+                        for event in game_ui::MANUAL_EVENTS {
+                            ui.label(game_ui::manual_event_label(&event));
+                            ui.add(egui::Slider::new(
+                                self.settings.events.weights.get_mut(&event),
+                                0..=100,
+                            ));
+                            let chance = chances.iter().find(|(e, _)| *e == event);
+                            ui.label(match chance {
+                                Some((_, chance)) => format!("{:.0}%", chance),
+                                None => "off".to_string(),
+                            });
+                            ui.end_row();
+                        }
+                        ui.label("No event");
+                        ui.add(egui::Slider::new(
+                            &mut self.settings.events.no_event_weight,
+                            0..=500,
+                        ))
+                        .on_hover_text("Higher values skip more triggers");
+                        ui.label(format!("{:.0}%", no_event_chance));
+                        ui.end_row();
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Event cooldown");
+                        ui.add(
+                            egui::DragValue::new(&mut self.settings.events.cooldown)
+                                .clamp_range(0..=3),
+                        )
+                        .on_hover_text("How many recent events can't be drawn again right away");
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Events trigger");
+                        let timing = &mut self.settings.events.timing;
+                        ui.radio_value(timing, EventTiming::OnClose, "On close");
+                        ui.radio_value(timing, EventTiming::OnResolve, "On resolve")
+                            .on_hover_text("Announce the event before the Close button");
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Max teams");
+                        ui.add(
+                            egui::DragValue::new(&mut self.settings.rules.max_teams)
+                                .clamp_range(2..=16),
+                        );
+                    });
+                    team_naming_editor(ui, &mut self.settings.team_name_template);
+                    ui.horizontal(|ui| {
+                        ui.label("Max categories");
+                        ui.add(
+                            egui::DragValue::new(&mut self.settings.board_limits.max_categories)
+                                .clamp_range(1..=20),
+                        );
+                        ui.label("Max rows");
+                        ui.add(
+                            egui::DragValue::new(&mut self.settings.board_limits.max_rows)
+                                .clamp_range(1..=15),
+                        );
+                    });
+                    ui.checkbox(
+                        &mut self.settings.scroll_board,
+                        "Scroll clue rows under pinned headers (touchscreens)",
+                    );
+                    ui.horizontal(|ui| {
+                        let style = &mut self.settings.board_style;
+                        ui.label("Grid gap");
+                        ui.add(
+                            egui::DragValue::new(&mut style.grid_gap)
+                                .clamp_range(crate::ui::BoardStyle::GAP_RANGE)
+                                .suffix("px"),
+                        );
+                        ui.label("Cell rounding");
+                        ui.add(
+                            egui::DragValue::new(&mut style.cell_rounding)
+                                .clamp_range(crate::ui::BoardStyle::ROUNDING_RANGE)
+                                .suffix("px"),
+                        );
+                    });
+                    ui.add_space(6.0);
+                    ui.horizontal(|ui| {
+                        if theme::secondary_button(ui, "Export Settings").clicked() {
+                            let saved = storage::settings_path().and_then(|path| {
+                                storage::save_settings(&path, &self.settings).map(|_| path)
+                            });
+                            match saved {
+                                Ok(path) => self.toasts.push(
+                                    ToastKind::Success,
+                                    format!("Exported settings to {}", path.display()),
+                                ),
+                                Err(err) => self
+                                    .toasts
+                                    .push(ToastKind::Error, format!("Export failed: {}", err)),
+                            }
+                        }
+                        if theme::secondary_button(ui, "Import Settings").clicked() {
+                            let loaded = storage::settings_path()
+                                .and_then(|path| storage::load_settings(&path));
+                            match loaded {
+                                Ok(settings) => {
+                                    self.settings = settings;
+                                    theme::apply_global_style(ui.ctx(), self.settings.contrast);
+                                    self.toasts.push(ToastKind::Success, "Imported settings");
+                                }
+                                Err(err) => self
+                                    .toasts
+                                    .push(ToastKind::Error, format!("Import failed: {}", err)),
+                            }
+                        }
+                    });
+                    ui.add_space(6.0);
+                    if theme::accent_button(ui, "Close").clicked() {
+                        self.show_settings_dialog = false;
+                    }
+                });
+            self.show_settings_dialog = open && self.show_settings_dialog;
+        }
+
+        // Projector window. An immediate viewport renders inside this frame, so it can
+        // borrow the same engine the host is driving and always shows the latest state.
+        if let AppMode::Game(game_engine) = &self.mode {
+            if self.show_display_window {
+                let engine: &GameEngine = game_engine;
+                let privacy = self.settings.score_privacy;
+                let close_requested = ctx.show_viewport_immediate(
+                    egui::ViewportId::from_hash_of("display_window"),
+                    egui::ViewportBuilder::default()
+                        .with_title("Jacko's Jeopardy — Display")
+                        .with_inner_size([1280.0, 720.0]),
+                    |ctx, _class| {
+                        egui::CentralPanel::default()
+                            .frame(egui::Frame::none().fill(Palette::BG_DARK))
+                            .show(ctx, |ui| {
+                                spectator_ui::render_spectator(ui, engine, privacy)
+                            });
+                        ctx.input(|i| i.viewport().close_requested())
+                    },
+                );
+                if close_requested {
+                    self.show_display_window = false;
+                }
+            }
+        } else {
+            self.show_display_window = false;
+        }
+
         match &mut self.mode {
             AppMode::Config(config_state) => {
-                if let Some(new_game_engine) = config_ui::show(ctx, config_state) {
+                if let Some(new_game_engine) =
+                    config_ui::show(ctx, config_state, self.settings.board_limits)
+                {
+                    let clues = new_game_engine.get_state().board.categories.iter();
+                    let clues: usize = clues.map(|c| c.clues.len()).sum();
+                    let threshold = self.settings.large_board_threshold();
+                    if !self.quality_hint_shown
+                        && performance::should_suggest_lower_quality(
+                            clues,
+                            threshold,
+                            self.settings.quality,
+                        )
+                    {
+                        self.quality_hint_shown = true;
+                        self.toasts.push(
+                            ToastKind::Warning,
+                            format!(
+                                "{} clues is a big board: try Medium or Low quality in Settings if it stutters",
+                                clues
+                            ),
+                        );
+                    }
                     self.mode = AppMode::Game(new_game_engine);
                 }
             }
             AppMode::Game(game_engine) => {
-                if let Some(next_mode) = game_ui::show(ctx, game_engine) {
+                game_engine.rules_mut().clone_from(&self.settings.rules);
+                game_engine
+                    .event_config_mut()
+                    .clone_from(&self.settings.events);
+                if let Some(next_mode) =
+                    game_ui::show(ctx, game_engine, &mut self.settings, &mut self.toasts)
+                {
                     self.mode = next_mode;
                 }
             }
         }
+
+        if self.show_diagnostics {
+            let game_engine = match &self.mode {
+                AppMode::Game(game_engine) => Some(game_engine),
+                AppMode::Config(_) => None,
+            };
+            diagnostics_ui::draw_diagnostics(ctx, game_engine, &self.frame_monitor.metrics);
+            // Keep the frame rate live while the readout is up
+            ctx.request_repaint();
+        }
+
+        self.toasts.show(ctx);
     }
 }
\ No newline at end of file
@@ -0,0 +1,83 @@
+use eframe::egui;
+
+/// Board area (in points) of a capture waiting on its screenshot
+fn pending_id() -> egui::Id {
+    egui::Id::new("board_capture_pending")
+}
+
+/// Ask the backend for a screenshot of this frame; the board inside `board_rect`
+/// is cut out of it once it arrives, in `take_capture`
+pub fn request_capture(ctx: &egui::Context, board_rect: egui::Rect) {
+    ctx.data_mut(|d| d.insert_temp(pending_id(), board_rect));
+    ctx.send_viewport_cmd(egui::ViewportCommand::Screenshot);
+}
+
+/// The board image, once the screenshot for a pending capture has come back
+pub fn take_capture(ctx: &egui::Context) -> Option<egui::ColorImage> {
+    let board_rect: egui::Rect = ctx.data(|d| d.get_temp(pending_id()))?;
+    let screenshot = ctx.input(|i| {
+        i.raw.events.iter().find_map(|e| match e {
+            egui::Event::Screenshot { image, .. } => Some(image.clone()),
+            _ => None,
+        })
+    })?;
+    ctx.data_mut(|d| d.remove::<egui::Rect>(pending_id()));
+    crop_to_board(&screenshot, board_rect, ctx.pixels_per_point())
+}
+
+/// Cut the board out of a full-window screenshot. `board_rect` is in points and
+/// is clipped to the image, so a board scrolled partly off screen still captures.
+pub fn crop_to_board(
+    screenshot: &egui::ColorImage,
+    board_rect: egui::Rect,
+    pixels_per_point: f32,
+) -> Option<egui::ColorImage> {
+    let [width, height] = screenshot.size;
+    let bounds = egui::Rect::from_min_size(
+        egui::Pos2::ZERO,
+        egui::vec2(width as f32, height as f32) / pixels_per_point,
+    );
+    let region = board_rect.intersect(bounds);
+    if !region.is_positive() {
+        return None;
+    }
+    let image = screenshot.region(&region, Some(pixels_per_point));
+    (image.width() > 0 && image.height() > 0).then_some(image)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_board_crop_has_board_dimensions_and_saves_as_png() {
+        // A 400×300 px window at 2× scale with the board drawn solid blue
+        let mut screenshot = egui::ColorImage::new([400, 300], egui::Color32::BLACK);
+        let board_rect =
+            egui::Rect::from_min_size(egui::pos2(20.0, 30.0), egui::vec2(150.0, 100.0));
+        for y in 60..260 {
+            for x in 40..340 {
+                screenshot[(x, y)] = egui::Color32::BLUE;
+            }
+        }
+
+        let board = crop_to_board(&screenshot, board_rect, 2.0).unwrap();
+        assert_eq!(board.size, [300, 200]);
+        assert!(board.pixels.iter().all(|&p| p == egui::Color32::BLUE));
+
+        // Only the on-screen part of a board hanging off the window is kept
+        let overhanging = board_rect.translate(egui::vec2(100.0, 0.0));
+        let clipped = crop_to_board(&screenshot, overhanging, 2.0).unwrap();
+        assert_eq!(clipped.size, [160, 200]);
+        let off_screen = board_rect.translate(egui::vec2(500.0, 0.0));
+        assert!(crop_to_board(&screenshot, off_screen, 2.0).is_none());
+
+        let dir = std::env::temp_dir().join(format!("board_capture_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("board.png");
+        crate::core::storage::save_png(&path, &board).unwrap();
+        let decoded = image::open(&path).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (300, 200));
+    }
+}
@@ -1,9 +1,9 @@
 use eframe::egui;
 
-use crate::core::{Board, Category, ConfigState};
+use crate::core::{Board, BoardIssue, BoardLimits, Category, ClueMedia, ConfigState, Difficulty};
 use crate::game::GameEngine;
 use crate::theme::{self, Palette, TransitionController};
-use crate::ui::{BoardEditorTransitionSystem, CellId, CellManager, ConfigLayoutState};
+use crate::ui::{BoardEditorTransitionSystem, CellId, CellManager, ConfigLayoutState, FocusTrap};
 
 // Enhanced config UI state (stored in egui memory)
 #[derive(Clone)]
@@ -15,6 +15,28 @@ struct EnhancedConfigUIState {
     editing_cell: Option<(usize, usize)>, // (col, row)
     edit_question: String,
     edit_answer: String,
+    // Alternate answers, one per line
+    edit_alt_answers: String,
+    edit_difficulty: Option<Difficulty>,
+    edit_no_flash: bool,
+    // Round the clue unlocks in, if it's gated
+    edit_unlock_round: Option<u32>,
+    edit_media_path: String,
+    edit_media_caption: String,
+    // Category whose intro is being edited, and the text so far
+    editing_intro: Option<usize>,
+    edit_intro: String,
+    // Value of the first row when "New Board" builds a fresh board
+    point_base: u32,
+    // Tint cells by difficulty to help balance the board
+    show_heatmap: bool,
+    // Result of the last "Fill from Bank" attempt
+    bank_status: Option<String>,
+    // Result of the last "Export HTML" attempt
+    export_status: Option<String>,
+    // Swap tool: active flag and the first cell picked (col, row)
+    swap_mode: bool,
+    swap_first: Option<(usize, usize)>,
 }
 
 impl Default for EnhancedConfigUIState {
@@ -26,11 +48,29 @@ impl Default for EnhancedConfigUIState {
             editing_cell: None,
             edit_question: String::new(),
             edit_answer: String::new(),
+            edit_alt_answers: String::new(),
+            edit_difficulty: None,
+            edit_no_flash: false,
+            edit_unlock_round: None,
+            edit_media_path: String::new(),
+            edit_media_caption: String::new(),
+            editing_intro: None,
+            edit_intro: String::new(),
+            point_base: 100,
+            show_heatmap: false,
+            bank_status: None,
+            export_status: None,
+            swap_mode: false,
+            swap_first: None,
         }
     }
 }
 
-pub fn show(ctx: &egui::Context, state: &mut ConfigState) -> Option<GameEngine> {
+pub fn show(
+    ctx: &egui::Context,
+    state: &mut ConfigState,
+    limits: BoardLimits,
+) -> Option<GameEngine> {
     let mut start_game: Option<GameEngine> = None;
 
     // Get or create enhanced UI state
@@ -42,21 +82,72 @@ pub fn show(ctx: &egui::Context, state: &mut ConfigState) -> Option<GameEngine>
         .frame(theme::panel_frame())
         .show(ctx, |ui| {
             ui.heading(egui::RichText::new("Board Editor").color(Palette::CYAN));
+            let fill_ratio = state.board.fill_ratio();
+            ui.add(
+                egui::ProgressBar::new(fill_ratio)
+                    .text(format!("{:.0}% of clues filled", fill_ratio * 100.0)),
+            );
+            let issues = state.board.validate();
+            for issue in &issues {
+                ui.label(
+                    egui::RichText::new(board_issue_text(&state.board, issue))
+                        .color(egui::Color32::YELLOW),
+                );
+            }
+            let ladder_scrambled = issues
+                .iter()
+                .any(|issue| matches!(issue, BoardIssue::NonMonotonicPoints { .. }));
+            if ladder_scrambled && theme::secondary_button(ui, "Normalize Points").clicked() {
+                state.board.normalize_points();
+            }
+            ui.horizontal(|ui| {
+                ui.label("Point scale");
+                for base in [100, 200, 500] {
+                    ui.radio_value(&mut ui_state.point_base, base, base.to_string());
+                }
+            });
             if theme::secondary_button(ui, "New Board").clicked() {
-                state.board = Board::default();
+                state.board = Board::default_scaled(6, 5, ui_state.point_base);
+            }
+            if theme::secondary_button(ui, "Fill from Bank").clicked() {
+                ui_state.bank_status = Some(fill_board_from_bank(&mut state.board));
+            }
+            if let Some(status) = &ui_state.bank_status {
+                ui.label(egui::RichText::new(status).color(Palette::SUBTLE_TEAL));
+            }
+            if theme::secondary_button(ui, "Export HTML").clicked() {
+                ui_state.export_status =
+                    Some(match crate::core::save_board_html("board", &state.board) {
+                        Ok(path) => format!("Exported to {}", path.display()),
+                        Err(err) => format!("Export failed: {}", err),
+                    });
             }
+            if let Some(status) = &ui_state.export_status {
+                ui.label(egui::RichText::new(status).color(Palette::SUBTLE_TEAL));
+            }
+            ui.checkbox(&mut ui_state.show_heatmap, "Difficulty heatmap");
             if theme::accent_button(ui, "Start Game").clicked() {
                 start_game = Some(GameEngine::new(state.board.clone()));
             }
+            if theme::secondary_button(ui, "Quick Demo Game")
+                .on_hover_text("Play a 3×3 board filled from question_bank.json right away")
+                .clicked()
+            {
+                match quick_demo_board() {
+                    // The demo plays on its own board; the one being edited stays as it is
+                    Ok(board) => start_game = Some(GameEngine::new(board)),
+                    Err(err) => ui_state.bank_status = Some(format!("Quick demo failed: {}", err)),
+                }
+            }
 
             ui.separator();
             // Board layout controls
             ui.label(egui::RichText::new("Layout").color(Palette::MAGENTA));
             if theme::accent_button(ui, "Add Category").clicked() {
-                let cols = state.board.categories.len();
-                if cols >= 10 {
+                if !limits.can_add_category(&state.board) {
                     ui.label(
-                        egui::RichText::new("Max 10 categories").color(egui::Color32::YELLOW),
+                        egui::RichText::new(format!("Max {} categories", limits.max_categories))
+                            .color(egui::Color32::YELLOW),
                     );
                 } else {
                     let rows = state
@@ -67,6 +158,8 @@ pub fn show(ctx: &egui::Context, state: &mut ConfigState) -> Option<GameEngine>
                         .unwrap_or(5);
                     state.board.categories.push(Category {
                         name: "New Category".into(),
+                        inverted: false,
+                        intro: None,
                         clues: Board::default_with_dimensions(1, rows)
                             .categories
                             .remove(0)
@@ -79,17 +172,36 @@ pub fn show(ctx: &egui::Context, state: &mut ConfigState) -> Option<GameEngine>
                 }
             }
 
+            let swap_label = if ui_state.swap_mode {
+                "Cancel Swap"
+            } else {
+                "Swap Clues"
+            };
+            if theme::secondary_button(ui, swap_label).clicked() {
+                ui_state.swap_mode = !ui_state.swap_mode;
+                ui_state.swap_first = None;
+            }
+            if ui_state.swap_mode {
+                let hint = if ui_state.swap_first.is_some() {
+                    "Pick the clue to swap with"
+                } else {
+                    "Pick the first clue"
+                };
+                ui.label(egui::RichText::new(hint).color(Palette::SUBTLE_TEAL));
+            }
+
             if theme::secondary_button(ui, "Add Row").clicked() {
-                // Add a new row of clues across all categories, max 8 rows
+                // Add a new row of clues across all categories, up to the row limit
                 let rows = state
                     .board
                     .categories
                     .get(0)
                     .map(|c| c.clues.len())
                     .unwrap_or(0);
-                if rows >= 8 {
+                if !limits.can_add_row(&state.board) {
                     ui.label(
-                        egui::RichText::new("Max 8 rows").color(egui::Color32::YELLOW),
+                        egui::RichText::new(format!("Max {} rows", limits.max_rows))
+                            .color(egui::Color32::YELLOW),
                     );
                 } else {
                     let mut next_id: u32 = state
@@ -110,6 +222,13 @@ pub fn show(ctx: &egui::Context, state: &mut ConfigState) -> Option<GameEngine>
                             answer: String::new(),
                             revealed: false,
                             solved: false,
+                            difficulty: None,
+                            host_note: None,
+                            no_flash: false,
+                            bonus_attempt: false,
+                            unlock_round: None,
+                            media: None,
+                            alt_answers: Vec::new(),
                         });
                         next_id += 1;
                     }
@@ -146,7 +265,11 @@ pub fn show(ctx: &egui::Context, state: &mut ConfigState) -> Option<GameEngine>
         let spacing_y = ui.spacing().item_spacing.y;
         // Match game mode sizing behavior
         let total_spacing_x = spacing_x * (cols.saturating_sub(1)) as f32;
-        let col_w = ((available.x - total_spacing_x) / cols as f32).max(60.0);
+        // Left gutter showing the point ladder beside each row
+        let gutter_w = 48.0;
+        let grid_w = available.x - gutter_w - spacing_x;
+        let col_w = ((grid_w - total_spacing_x) / cols as f32).max(60.0);
+        let point_ladder = state.board.point_ladder();
         // Header uses 15% height, clamped 40..60 like game mode
         let header_h = (available.y * 0.15).max(40.0).min(60.0);
         let total_spacing_y = spacing_y * rows as f32; // spacing between header and rows
@@ -158,8 +281,12 @@ pub fn show(ctx: &egui::Context, state: &mut ConfigState) -> Option<GameEngine>
         };
 
         // Enhanced category headers with smooth transitions
+        let can_add_category = limits.can_add_category(&state.board);
+        let mut duplicate: Option<usize> = None;
+        let mut editing_intro: Option<usize> = None;
         ui.horizontal(|ui| {
             ui.set_width(available.x);
+            ui.allocate_exact_size(egui::vec2(gutter_w, header_h), egui::Sense::hover());
             for (ci, category) in state.board.categories.iter_mut().enumerate() {
                 let (rect, _response) =
                     ui.allocate_exact_size(egui::vec2(col_w, header_h), egui::Sense::hover());
@@ -169,6 +296,7 @@ pub fn show(ctx: &egui::Context, state: &mut ConfigState) -> Option<GameEngine>
                     &ui.painter_at(rect),
                     rect,
                     &format!("Category {}", ci + 1),
+                    crate::ui::BoardStyle::default().cell_rounding,
                 );
 
                 // Enhanced title editing with better visual feedback
@@ -188,14 +316,80 @@ pub fn show(ctx: &egui::Context, state: &mut ConfigState) -> Option<GameEngine>
                 if title_response.changed() {
                     category.name = title;
                 }
+
+                // Minefield toggle in the header's top-right corner
+                let toggle_rect = egui::Rect::from_min_size(
+                    rect.right_top() + egui::vec2(-22.0, 2.0),
+                    egui::vec2(20.0, 16.0),
+                );
+                if ui
+                    .put(
+                        toggle_rect,
+                        egui::SelectableLabel::new(category.inverted, "⚠"),
+                    )
+                    .on_hover_text("Inverted: correct answers lose points, wrong answers gain them")
+                    .clicked()
+                {
+                    category.inverted = !category.inverted;
+                }
+
+                // Intro card editor, left of the minefield toggle
+                let intro_rect = egui::Rect::from_min_size(
+                    rect.right_top() + egui::vec2(-44.0, 2.0),
+                    egui::vec2(20.0, 16.0),
+                );
+                let has_intro = category.intro.is_some();
+                if ui
+                    .put(intro_rect, egui::SelectableLabel::new(has_intro, "💬"))
+                    .on_hover_text(category.intro.as_deref().unwrap_or("Add an intro card"))
+                    .clicked()
+                {
+                    editing_intro = Some(ci);
+                }
+
+                // Duplicate button in the top-left corner
+                let duplicate_rect = egui::Rect::from_min_size(
+                    rect.left_top() + egui::vec2(2.0, 2.0),
+                    egui::vec2(20.0, 16.0),
+                );
+                if ui
+                    .put(duplicate_rect, egui::Button::new("⧉").small().frame(false))
+                    .on_hover_text(if can_add_category {
+                        "Duplicate category".to_string()
+                    } else {
+                        format!("Max {} categories", limits.max_categories)
+                    })
+                    .clicked()
+                    && can_add_category
+                {
+                    duplicate = Some(ci);
+                }
             }
         });
+        if let Some(ci) = duplicate {
+            state.board.clone_category(ci);
+        }
+        if let Some(ci) = editing_intro {
+            ui_state.editing_intro = Some(ci);
+            ui_state.edit_intro = state.board.categories[ci].intro.clone().unwrap_or_default();
+        }
 
         // Cells: adopt game mode visual layout and click to edit dialog
         let mut clicked: Option<(usize, usize)> = None;
         for row_idx in 0..rows {
             ui.horizontal(|ui| {
                 ui.set_width(available.x);
+                let (gutter_rect, _) =
+                    ui.allocate_exact_size(egui::vec2(gutter_w, cell_h), egui::Sense::hover());
+                if let Some(points) = point_ladder.get(row_idx) {
+                    ui.painter_at(gutter_rect).text(
+                        gutter_rect.center(),
+                        egui::Align2::CENTER_CENTER,
+                        points.to_string(),
+                        egui::FontId::proportional(14.0),
+                        Palette::SUBTLE_TEAL,
+                    );
+                }
                 for (col_idx, category) in state.board.categories.iter().enumerate() {
                     let clue = &category.clues[row_idx];
                     let (rect, response) = ui.allocate_exact_size(
@@ -205,6 +399,21 @@ pub fn show(ctx: &egui::Context, state: &mut ConfigState) -> Option<GameEngine>
                     let painter = ui.painter_at(rect);
                     let is_filled = !clue.question.trim().is_empty() && !clue.answer.trim().is_empty();
                     crate::ui::paint_config_clue_cell(&painter, rect, clue.points, is_filled, response.hovered());
+                    if ui_state.show_heatmap {
+                        let heat = crate::ui::difficulty_heat_color(clue.difficulty);
+                        painter.rect_filled(
+                            rect.shrink(2.0),
+                            egui::Rounding::same(6.0),
+                            egui::Color32::from_rgba_unmultiplied(heat.r(), heat.g(), heat.b(), 90),
+                        );
+                    }
+                    if ui_state.swap_first == Some((col_idx, row_idx)) {
+                        painter.rect_stroke(
+                            rect.shrink(2.0),
+                            egui::Rounding::same(6.0),
+                            egui::Stroke::new(3.0, Palette::MAGENTA),
+                        );
+                    }
                     if response.clicked() {
                         clicked = Some((col_idx, row_idx));
                     }
@@ -212,12 +421,31 @@ pub fn show(ctx: &egui::Context, state: &mut ConfigState) -> Option<GameEngine>
             });
         }
 
-        if let Some((c, r)) = clicked {
+        if let (true, Some(cell)) = (ui_state.swap_mode, clicked) {
+            // Swap mode: first click picks A, second click swaps A with B
+            match ui_state.swap_first.take() {
+                Some(first) => {
+                    state.board.swap_clue_content(first, cell);
+                    ui_state.swap_mode = false;
+                }
+                None => ui_state.swap_first = Some(cell),
+            }
+        } else if let Some((c, r)) = clicked {
             ui_state.editing_cell = Some((c, r));
             if let Some(cat) = state.board.categories.get(c) {
                 if let Some(clue) = cat.clues.get(r) {
                     ui_state.edit_question = clue.question.clone();
                     ui_state.edit_answer = clue.answer.clone();
+                    ui_state.edit_alt_answers = clue.alt_answers.join("\n");
+                    ui_state.edit_difficulty = clue.difficulty;
+                    ui_state.edit_no_flash = clue.no_flash;
+                    ui_state.edit_unlock_round = clue.unlock_round;
+                    let media = clue.media.clone().unwrap_or(ClueMedia {
+                        path: String::new(),
+                        caption: String::new(),
+                    });
+                    ui_state.edit_media_path = media.path;
+                    ui_state.edit_media_caption = media.caption;
                 }
             }
         }
@@ -244,6 +472,7 @@ pub fn show(ctx: &egui::Context, state: &mut ConfigState) -> Option<GameEngine>
                     );
                     let mut inner = ui.child_ui(modal_rect, egui::Layout::top_down(egui::Align::LEFT));
                     inner.scope(|ui| {
+                        let mut focus = FocusTrap::new("edit_clue_modal");
                         ui.spacing_mut().item_spacing = egui::vec2(8.0, 10.0);
                         ui.add_space(6.0);
                         ui.heading(
@@ -254,33 +483,150 @@ pub fn show(ctx: &egui::Context, state: &mut ConfigState) -> Option<GameEngine>
                         ui.separator();
 
                         ui.label("Question");
-                        ui.add(
+                        focus.add(&ui.add(
                             egui::TextEdit::multiline(&mut ui_state.edit_question)
                                 .desired_rows(5)
                                 .hint_text("Enter question..."),
-                        );
+                        ));
                         ui.add_space(4.0);
                         ui.label("Answer");
-                        ui.add(
+                        focus.add(&ui.add(
                             egui::TextEdit::multiline(&mut ui_state.edit_answer)
                                 .desired_rows(3)
                                 .hint_text("Enter answer..."),
-                        );
+                        ));
+                        ui.label("Also accepted");
+                        focus.add(&ui.add(
+                            egui::TextEdit::multiline(&mut ui_state.edit_alt_answers)
+                                .desired_rows(2)
+                                .hint_text("One alternate answer per line"),
+                        ));
+                        ui.horizontal(|ui| {
+                            ui.label("Difficulty");
+                            focus.add(&ui.radio_value(&mut ui_state.edit_difficulty, None, "Unrated"));
+                            for difficulty in Difficulty::ALL {
+                                focus.add(&ui.radio_value(
+                                    &mut ui_state.edit_difficulty,
+                                    Some(difficulty),
+                                    difficulty.label(),
+                                ));
+                            }
+                        });
+                        focus.add(&ui.checkbox(&mut ui_state.edit_no_flash, "Quiet reveal (no flash)"));
+                        ui.horizontal(|ui| {
+                            let mut gated = ui_state.edit_unlock_round.is_some();
+                            let toggle = ui.checkbox(&mut gated, "Locked until round");
+                            focus.add(&toggle);
+                            if toggle.changed() {
+                                ui_state.edit_unlock_round = gated.then_some(2);
+                            }
+                            if let Some(round) = &mut ui_state.edit_unlock_round {
+                                focus.add(&ui.add(egui::DragValue::new(round).clamp_range(2..=10)));
+                            }
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("Image");
+                            focus.add(&ui.add(
+                                egui::TextEdit::singleline(&mut ui_state.edit_media_path)
+                                    .hint_text("Path to a PNG (optional)"),
+                            ));
+                            ui.label("Caption");
+                            focus.add(&ui.add(
+                                egui::TextEdit::singleline(&mut ui_state.edit_media_caption)
+                                    .hint_text("Shown if the image can't load"),
+                            ));
+                        });
                         ui.add_space(10.0);
                         ui.horizontal(|ui| {
-                            if crate::theme::accent_button(ui, "Save").clicked() {
+                            let save = crate::theme::accent_button(ui, "Save");
+                            focus.add(&save);
+                            if save.clicked() {
                                 if let Some(cat) = state.board.categories.get_mut(c) {
                                     if let Some(clue) = cat.clues.get_mut(r) {
                                         clue.question = ui_state.edit_question.clone();
                                         clue.answer = ui_state.edit_answer.clone();
+                                        clue.alt_answers = ui_state
+                                            .edit_alt_answers
+                                            .lines()
+                                            .map(str::trim)
+                                            .filter(|l| !l.is_empty())
+                                            .map(str::to_string)
+                                            .collect();
+                                        clue.difficulty = ui_state.edit_difficulty;
+                                        clue.no_flash = ui_state.edit_no_flash;
+                                        clue.unlock_round = ui_state.edit_unlock_round;
+                                        let path = ui_state.edit_media_path.trim();
+                                        clue.media = (!path.is_empty()).then(|| ClueMedia {
+                                            path: path.to_string(),
+                                            caption: ui_state.edit_media_caption.trim().to_string(),
+                                        });
                                     }
                                 }
                                 ui_state.editing_cell = None;
                             }
-                            if crate::theme::secondary_button(ui, "Cancel").clicked() {
+                            let cancel = crate::theme::secondary_button(ui, "Cancel");
+                            focus.add(&cancel);
+                            if cancel.clicked() {
                                 ui_state.editing_cell = None;
                             }
                         });
+                        if focus.finish(ui.ctx()) {
+                            ui_state.editing_cell = None;
+                        }
+                    });
+                });
+        }
+
+        // Editing modal for a category's intro card
+        if let Some(ci) = ui_state.editing_intro {
+            let screen = ui.ctx().screen_rect();
+            egui::Area::new("config_edit_intro_modal".into())
+                .order(egui::Order::Foreground)
+                .movable(false)
+                .interactable(true)
+                .fixed_pos(screen.min)
+                .show(ui.ctx(), |ui| {
+                    let painter = ui.painter_at(screen);
+                    crate::ui::paint_subtle_modal_background(&painter, screen);
+
+                    let modal_w = (screen.width() * 0.5).clamp(360.0, 640.0);
+                    let modal_rect =
+                        egui::Rect::from_center_size(screen.center(), egui::vec2(modal_w, 220.0));
+                    let layout = egui::Layout::top_down(egui::Align::LEFT);
+                    let mut inner = ui.child_ui(modal_rect, layout);
+                    inner.scope(|ui| {
+                        let mut focus = FocusTrap::new("edit_intro_modal");
+                        ui.spacing_mut().item_spacing = egui::vec2(8.0, 10.0);
+                        let name = state.board.categories.get(ci).map(|c| c.name.as_str());
+                        ui.heading(
+                            egui::RichText::new("Category Intro").color(Palette::CYAN).size(20.0),
+                        );
+                        ui.label(egui::RichText::new(name.unwrap_or("")).color(Palette::MAGENTA));
+                        focus.add(&ui.add(
+                            egui::TextEdit::multiline(&mut ui_state.edit_intro)
+                                .desired_rows(3)
+                                .hint_text("This category is all about 90s cartoons"),
+                        ));
+                        ui.horizontal(|ui| {
+                            let save = crate::theme::accent_button(ui, "Save");
+                            focus.add(&save);
+                            if save.clicked() {
+                                if let Some(category) = state.board.categories.get_mut(ci) {
+                                    let intro = ui_state.edit_intro.trim();
+                                    category.intro =
+                                        (!intro.is_empty()).then(|| intro.to_string());
+                                }
+                                ui_state.editing_intro = None;
+                            }
+                            let cancel = crate::theme::secondary_button(ui, "Cancel");
+                            focus.add(&cancel);
+                            if cancel.clicked() {
+                                ui_state.editing_intro = None;
+                            }
+                        });
+                        if focus.finish(ui.ctx()) {
+                            ui_state.editing_intro = None;
+                        }
                     });
                 });
         }
@@ -295,3 +641,38 @@ pub fn show(ctx: &egui::Context, state: &mut ConfigState) -> Option<GameEngine>
 
     start_game
 }
+
+/// Warning line for the validation report
+fn board_issue_text(board: &Board, issue: &BoardIssue) -> String {
+    match issue {
+        BoardIssue::NonMonotonicPoints { category } => {
+            let name = board
+                .categories
+                .get(*category)
+                .map(|c| c.name.as_str())
+                .unwrap_or("?");
+            format!("⚠ Points in \"{}\" don't rise row by row", name)
+        }
+    }
+}
+
+/// Small board filled entirely from ./question_bank.json for an instant demo
+fn quick_demo_board() -> anyhow::Result<Board> {
+    let bank = crate::core::bank::default_bank_path()
+        .and_then(|path| crate::core::bank::QuestionBank::load_from_path(&path))?;
+    let (categories, rows) = crate::core::bank::DEMO_BOARD_SIZE;
+    Board::quick_demo(&bank, categories, rows, &mut rand::thread_rng())
+}
+
+/// Load ./question_bank.json and fill the board's empty clues, returning a status line
+fn fill_board_from_bank(board: &mut Board) -> String {
+    let bank = crate::core::bank::default_bank_path()
+        .and_then(|path| crate::core::bank::QuestionBank::load_from_path(&path));
+    match bank {
+        Ok(bank) => {
+            let filled = board.fill_from_bank(&bank, &mut rand::thread_rng());
+            format!("Filled {} clue(s) from {} bank entries", filled, bank.entries.len())
+        }
+        Err(err) => format!("Couldn't load question_bank.json: {}", err),
+    }
+}
@@ -0,0 +1,143 @@
+use eframe::egui;
+
+use crate::game::events::EventState;
+use crate::game::{GameEngine, PlayPhase};
+use crate::theme::Palette;
+use crate::theme::transitions::PerformanceMetrics;
+
+/// One-line description of a phase with every field that matters when triaging a bug
+pub fn phase_summary(phase: &PlayPhase) -> String {
+    match phase {
+        PlayPhase::Lobby => "Lobby".to_string(),
+        PlayPhase::Selecting { team_id } => format!("Selecting: team {}", team_id),
+        PlayPhase::Showing {
+            clue,
+            owner_team_id,
+            attempt_count,
+            max_attempts,
+        } => format!(
+            "Showing {:?}: owner {}, attempt {}/{}",
+            clue, owner_team_id, attempt_count, max_attempts
+        ),
+        PlayPhase::Steal {
+            clue,
+            queue,
+            current,
+            owner_team_id,
+            attempted,
+            ..
+        } => format!(
+            "Steal {:?}: current {}, queue {:?}, owner {}, attempted {:?}",
+            clue, current, queue, owner_team_id, attempted
+        ),
+        PlayPhase::Resolved { clue, next_team_id } => {
+            format!("Resolved {:?}: next team {}", clue, next_team_id)
+        }
+        PlayPhase::Intermission => "Intermission".to_string(),
+        PlayPhase::Finished => "Finished".to_string(),
+    }
+}
+
+fn event_summary(events: &EventState) -> String {
+    format!(
+        "active {:?}, queued {:?}, animating {}, answered {}, history {}",
+        events.active_event,
+        events.queued_event,
+        events.animation_playing,
+        events.questions_answered,
+        events.event_history.len()
+    )
+}
+
+/// Read-only debug readout (F3) in the bottom-left corner: the engine's phase,
+/// active team and events while a game runs, and frame timing always
+pub fn draw_diagnostics(
+    ctx: &egui::Context,
+    game_engine: Option<&GameEngine>,
+    metrics: &PerformanceMetrics,
+) {
+    egui::Area::new("diagnostics_overlay".into())
+        .order(egui::Order::Tooltip)
+        .anchor(egui::Align2::LEFT_BOTTOM, egui::vec2(8.0, -8.0))
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::none()
+                .fill(egui::Color32::from_black_alpha(200))
+                .inner_margin(egui::Margin::same(8.0))
+                .rounding(4.0)
+                .show(ui, |ui| {
+                    let line = |ui: &mut egui::Ui, text: String| {
+                        ui.label(
+                            egui::RichText::new(text)
+                                .monospace()
+                                .size(12.0)
+                                .color(Palette::TEXT),
+                        );
+                    };
+                    line(
+                        ui,
+                        format!(
+                            "{:.0} fps ({:.1} ms), {} slow frames",
+                            metrics.current_fps,
+                            metrics.average_frame_time.as_secs_f32() * 1000.0,
+                            metrics.dropped_frames
+                        ),
+                    );
+                    let Some(game_engine) = game_engine else {
+                        line(ui, "Board editor".to_string());
+                        return;
+                    };
+                    let state = game_engine.get_state();
+                    line(ui, format!("phase: {}", phase_summary(&state.phase)));
+                    line(
+                        ui,
+                        format!("active team: {}, round {}", state.active_team, state.round),
+                    );
+                    line(ui, format!("events: {}", event_summary(&state.event_state)));
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_phase_summary_covers_every_phase() {
+        assert_eq!(phase_summary(&PlayPhase::Lobby), "Lobby");
+        assert_eq!(
+            phase_summary(&PlayPhase::Selecting { team_id: 2 }),
+            "Selecting: team 2"
+        );
+        assert_eq!(
+            phase_summary(&PlayPhase::Showing {
+                clue: (1, 3),
+                owner_team_id: 2,
+                attempt_count: 1,
+                max_attempts: 2,
+            }),
+            "Showing (1, 3): owner 2, attempt 1/2"
+        );
+        assert_eq!(
+            phase_summary(&PlayPhase::Steal {
+                clue: (0, 4),
+                queue: VecDeque::from([3, 4]),
+                current: 1,
+                owner_team_id: 2,
+                attempted: vec![2],
+                owner_penalty: 400,
+            }),
+            "Steal (0, 4): current 1, queue [3, 4], owner 2, attempted [2]"
+        );
+        assert_eq!(
+            phase_summary(&PlayPhase::Resolved {
+                clue: (2, 0),
+                next_team_id: 3,
+            }),
+            "Resolved (2, 0): next team 3"
+        );
+        assert_eq!(phase_summary(&PlayPhase::Intermission), "Intermission");
+        assert_eq!(phase_summary(&PlayPhase::Finished), "Finished");
+    }
+}
@@ -1,76 +1,351 @@
 use eframe::egui;
 
 use crate::app::app::AppMode;
-use crate::core::Board;
+use crate::app::board_capture;
+use crate::app::settings::{AppSettings, PanelPosition, ScorePrivacy, TeamNaming};
+use crate::core::{Board, Category, Clue};
+use crate::game::actions::GameEffect;
 use crate::game::events::{EventAnimationController, EventAnimationType, GameEvent};
-use crate::game::{GameAction, GameActionResult, GameEngine, PlayPhase};
+use crate::game::rules::{FirstTeamPolicy, HIDDEN_POINTS_LABEL, points_label};
+use crate::game::{GameAction, GameActionResult, GameEngine, GameState, PlayPhase};
+use crate::theme::AnimationState;
 use crate::theme::Palette;
+use crate::theme::animations::{BurstParams, ease_in_out, lerp_rect};
 use crate::theme::{ModalButtonType, enhanced_modal_button};
 use crate::ui::{
-    ManualPointsModal, paint_enhanced_category_header, paint_enhanced_clue_cell,
-    paint_subtle_modal_background, show_manual_points_modal,
+    BoardStyle, ClueCellState, FocusTrap, ManualPointsModal, SCROLL_MIN_CELL_HEIGHT, ToastKind,
+    ToastQueue, board_cell_sizes, compute_board_layout, paint_enhanced_category_header,
+    paint_enhanced_clue_cell, paint_subtle_modal_background, show_clue_media,
+    show_manual_points_modal, split_board_regions,
 };
 use std::time::{Duration, Instant};
 
+/// Overlay button clicks this soon after the last handled one are ignored
+const OVERLAY_CLICK_DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// Whether a click at `now` is far enough from the last handled click to count
+fn debounce_allows(last: Option<Instant>, now: Instant, window: Duration) -> bool {
+    last.is_none_or(|last| now.saturating_duration_since(last) >= window)
+}
+
+/// Debounce for overlay buttons so a fast double-click can't act twice before
+/// the flash guard engages. Records the click when it's accepted.
+fn accept_overlay_click(ctx: &egui::Context) -> bool {
+    let id = egui::Id::new("overlay_last_click");
+    let now = Instant::now();
+    let last: Option<Instant> = ctx.memory_mut(|m| m.data.get_temp(id));
+    if !debounce_allows(last, now, OVERLAY_CLICK_DEBOUNCE) {
+        return false;
+    }
+    ctx.memory_mut(|m| m.data.insert_temp(id, now));
+    true
+}
+
+/// How long a clicked cell takes to grow into the question overlay
+const CLUE_ZOOM_DURATION: Duration = Duration::from_millis(300);
+
+/// A clicked cell growing out to the full screen before its question shows
+#[derive(Clone)]
+struct ClueZoom {
+    clue: (usize, usize),
+    from: egui::Rect,
+    animation: AnimationState,
+}
+
+impl ClueZoom {
+    fn start(clue: (usize, usize), from: egui::Rect) -> Self {
+        let mut animation = AnimationState::new(CLUE_ZOOM_DURATION, ease_in_out);
+        animation.start();
+        Self {
+            clue,
+            from,
+            animation,
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq)]
 enum AnswerFlash {
     Correct,
     Incorrect,
 }
 
+/// How the answer flash is drawn: the full burst, or a plain colour fade
+/// for clues the host marked `no_flash`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FlashStyle {
+    Burst,
+    Fade,
+}
+
+fn flash_style(no_flash: bool) -> FlashStyle {
+    if no_flash {
+        FlashStyle::Fade
+    } else {
+        FlashStyle::Burst
+    }
+}
+
+/// What a click on a board cell does while a team is selecting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CellClick {
+    Select,
+    /// Reopen a solved clue read-only
+    Review,
+    Ignore,
+}
+
+fn cell_click(solved: bool, in_order: bool, review_solved: bool) -> CellClick {
+    if solved {
+        if review_solved {
+            CellClick::Review
+        } else {
+            CellClick::Ignore
+        }
+    } else if in_order {
+        CellClick::Select
+    } else {
+        CellClick::Ignore
+    }
+}
+
+/// What assistive tech announces for a board cell, e.g. "Science, 200 points, available".
+/// A mystery board keeps the value back here too.
+fn clue_cell_accessible_label(
+    category: &str,
+    points: u32,
+    state: ClueCellState,
+    show_value: bool,
+) -> String {
+    let value = if show_value {
+        format!("{} points", points)
+    } else {
+        "mystery value".to_string()
+    };
+    let status = match state {
+        ClueCellState::Open => "available",
+        ClueCellState::RevealedUnsolved => "shown, unanswered",
+        ClueCellState::Solved => "solved",
+    };
+    format!("{}, {}, {}", category, value, status)
+}
+
 #[derive(Clone, Copy, PartialEq)]
 enum StealOutcome {
     Correct,
     Incorrect,
 }
 
-pub fn show(ctx: &egui::Context, game_engine: &mut GameEngine) -> Option<AppMode> {
+/// Which egui panel hosts the Teams list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TeamPanelLayout {
+    Side(egui::panel::Side),
+    Strip(egui::panel::TopBottomSide),
+}
+
+fn team_panel_layout(position: PanelPosition) -> TeamPanelLayout {
+    match position {
+        PanelPosition::Left => TeamPanelLayout::Side(egui::panel::Side::Left),
+        PanelPosition::Right => TeamPanelLayout::Side(egui::panel::Side::Right),
+        PanelPosition::Top => TeamPanelLayout::Strip(egui::panel::TopBottomSide::Top),
+        PanelPosition::Bottom => TeamPanelLayout::Strip(egui::panel::TopBottomSide::Bottom),
+    }
+}
+
+fn draw_teams_panel(
+    ui: &mut egui::Ui,
+    game_engine: &mut GameEngine,
+    settings: &mut AppSettings,
+    manual_points_modal: &mut ManualPointsModal,
+    toasts: &mut ToastQueue,
+) {
+    ui.heading(egui::RichText::new("Teams").color(Palette::CYAN));
+    let in_lobby = matches!(game_engine.get_phase(), PlayPhase::Lobby);
+    let finished = matches!(game_engine.get_phase(), PlayPhase::Finished);
+    let active_team = game_engine.get_state().active_team;
+    let mut starting_scores: Vec<(u32, i32)> = Vec::new();
+    for team in &mut game_engine.get_state_mut().teams {
+        ui.horizontal(|ui| {
+            if in_lobby {
+                // Star marks the team that will pick first
+                let picks_first = settings.rules.first_team == FirstTeamPolicy::Specific(team.id);
+                if ui
+                    .selectable_label(picks_first, "★")
+                    .on_hover_text("Picks the first clue")
+                    .clicked()
+                {
+                    settings.rules.first_team = if picks_first {
+                        FirstTeamPolicy::FirstAdded
+                    } else {
+                        FirstTeamPolicy::Specific(team.id)
+                    };
+                }
+                draw_team_emoji_picker(ui, team);
+                ui.add(egui::TextEdit::singleline(&mut team.name));
+                // Starting score doubles as a handicap for weaker teams
+                let mut score = team.score;
+                if crate::ui::score_input(
+                    ui,
+                    &mut score,
+                    crate::ui::score_input::SCORE_MIN,
+                    crate::ui::score_input::SCORE_MAX,
+                )
+                .on_hover_text("Starting score")
+                .changed()
+                {
+                    starting_scores.push((team.id, score));
+                }
+            } else {
+                let score = settings.score_privacy.displayed_score(
+                    team.score,
+                    team.id == active_team,
+                    finished,
+                );
+                ui.label(format!("{} — {}", team.label(), score));
+            }
+        });
+    }
+    for (team_id, score) in starting_scores {
+        dispatch(
+            game_engine,
+            GameAction::SetStartingScore { team_id, score },
+            toasts,
+        );
+    }
+    let can_add_team = game_engine.rules().can_add_team(game_engine.get_state());
+    let add_team_clicked = ui
+        .add_enabled_ui(can_add_team, |ui| {
+            crate::theme::accent_button(ui, "Add Team").clicked()
+        })
+        .inner;
+    if add_team_clicked {
+        let action = GameAction::AddTeam {
+            name: settings
+                .team_name_template
+                .name_for(game_engine.team_count()),
+        };
+        dispatch(game_engine, action, toasts);
+    }
+    let can_shuffle = in_lobby && game_engine.team_count() > 1;
+    let shuffle_clicked = ui
+        .add_enabled_ui(can_shuffle, |ui| {
+            crate::theme::secondary_button(ui, "Shuffle Teams").clicked()
+        })
+        .inner;
+    if shuffle_clicked {
+        dispatch(game_engine, GameAction::ShuffleTeams, toasts);
+    }
+    if in_lobby
+        && game_engine.team_count() > 0
+        && crate::theme::secondary_button(ui, "Remove Last Team").clicked()
+    {
+        request_team_removal(ui.ctx(), TeamRemoval::RemoveLast, game_engine.team_count());
+    }
+    if in_lobby && !can_add_team {
+        ui.label(
+            egui::RichText::new(format!("Max {} teams", game_engine.rules().max_teams))
+                .color(egui::Color32::YELLOW),
+        );
+    }
+    if in_lobby {
+        draw_bulk_team_entry(ui, game_engine, settings, toasts);
+        draw_team_removal_confirm(ui.ctx(), game_engine, settings, toasts);
+    }
+
+    if game_engine.rules().audience_claim_scores && !in_lobby {
+        ui.label(
+            egui::RichText::new(format!(
+                "👥 Audience — {}",
+                game_engine.get_state().audience_score
+            ))
+            .color(Palette::SUBTLE_TEAL),
+        );
+    }
+
+    // Add manual points adjustment button for active game phases
+    if !in_lobby && !game_engine.get_state().teams.is_empty() {
+        ui.add_space(10.0);
+        if crate::theme::secondary_button(ui, "Adjust Points").clicked() {
+            manual_points_modal.initialize_inputs(&game_engine.get_state().teams);
+            manual_points_modal.show();
+        }
+    }
+}
+
+pub fn show(
+    ctx: &egui::Context,
+    game_engine: &mut GameEngine,
+    settings: &mut AppSettings,
+    toasts: &mut ToastQueue,
+) -> Option<AppMode> {
     let mut manual_points_modal: ManualPointsModal = ctx
         .memory_mut(|m| m.data.get_temp(egui::Id::new("manual_points_modal")))
         .unwrap_or_default();
 
-    egui::SidePanel::left("teams")
-        .frame(crate::theme::panel_frame())
-        .show(ctx, |ui| {
-            ui.heading(egui::RichText::new("Teams").color(Palette::CYAN));
-            let in_lobby = matches!(game_engine.get_phase(), PlayPhase::Lobby);
-            for team in &mut game_engine.get_state_mut().teams {
-                ui.horizontal(|ui| {
-                    if in_lobby {
-                        ui.add(egui::TextEdit::singleline(&mut team.name));
-                        ui.label(format!(" — {}", team.score));
-                    } else {
-                        ui.label(format!("{} — {}", team.name, team.score));
-                    }
+    // The board's CentralPanel takes whatever space the Teams panel leaves
+    match team_panel_layout(settings.team_panel_position) {
+        TeamPanelLayout::Side(side) => {
+            egui::SidePanel::new(side, "teams")
+                .frame(crate::theme::panel_frame())
+                .show(ctx, |ui| {
+                    draw_teams_panel(ui, game_engine, settings, &mut manual_points_modal, toasts)
                 });
-            }
-            if crate::theme::accent_button(ui, "Add Team").clicked() {
-                let action = GameAction::AddTeam {
-                    name: format!("Team {}", game_engine.team_count() + 1),
-                };
-                let _ = game_engine.handle_action(action);
-            }
+        }
+        TeamPanelLayout::Strip(side) => {
+            egui::TopBottomPanel::new(side, "teams")
+                .frame(crate::theme::panel_frame())
+                .resizable(true)
+                .show(ctx, |ui| {
+                    egui::ScrollArea::vertical().show(ui, |ui| {
+                        draw_teams_panel(
+                            ui,
+                            game_engine,
+                            settings,
+                            &mut manual_points_modal,
+                            toasts,
+                        )
+                    });
+                });
+        }
+    }
 
-            // Add manual points adjustment button for active game phases
-            if !in_lobby && !game_engine.get_state().teams.is_empty() {
-                ui.add_space(10.0);
-                if crate::theme::secondary_button(ui, "Adjust Points").clicked() {
-                    manual_points_modal.initialize_inputs(&game_engine.get_state().teams);
-                    manual_points_modal.show();
-                }
-            }
-        });
+    if !settings.skip_team_wizard {
+        draw_team_wizard(ctx, game_engine, &settings.team_name_template, toasts);
+    }
+
+    // The screenshot asked for by "Capture Board" arrives a frame later
+    if let Some(image) = board_capture::take_capture(ctx) {
+        let saved = crate::core::board_capture_path()
+            .and_then(|path| crate::core::save_png(&path, &image).map(|_| path));
+        match saved {
+            Ok(path) => toasts.push(
+                ToastKind::Success,
+                format!("Captured board to {}", path.display()),
+            ),
+            Err(err) => toasts.push(ToastKind::Error, format!("Capture failed: {}", err)),
+        }
+    }
 
     let mut next_mode: Option<AppMode> = None;
     egui::CentralPanel::default().show(ctx, |ui| {
         crate::theme::paint_board_background(ui);
-        ui.heading(egui::RichText::new("Game Board").color(Palette::CYAN));
+        ui.horizontal(|ui| {
+            ui.heading(egui::RichText::new("Game Board").color(Palette::CYAN));
+            // Keep an in-effect event visible after its animation has finished
+            if let Some(event) = &game_engine.get_state().event_state.active_event {
+                draw_active_event_banner(ui, event);
+            } else if matches!(game_engine.get_phase(), PlayPhase::Selecting { .. }) {
+                draw_event_countdown(ui, game_engine);
+            }
+        });
         let mut requested_phase: Option<PlayPhase> = None;
-        let flash_id = ui.id().with("answer_flash");
-        let pending_answer_id = ui.id().with("pending_answer");
-        let pending_steal_id = ui.id().with("pending_steal");
-        let event_animation_id = ui.id().with("event_animation");
-        let mut flash: Option<(AnswerFlash, Instant)> =
+        let [
+            flash_id,
+            pending_answer_id,
+            pending_steal_id,
+            event_animation_id,
+        ] = in_flight_ids();
+        let mut flash: Option<(AnswerFlash, FlashStyle, Instant)> =
             ui.memory_mut(|m| m.data.get_temp(flash_id)).unwrap_or(None);
         let mut pending_answer: Option<(AnswerFlash, (usize, usize), u32)> = ui
             .memory_mut(|m| m.data.get_temp(pending_answer_id))
@@ -87,7 +362,7 @@ pub fn show(ctx: &egui::Context, game_engine: &mut GameEngine) -> Option<AppMode
                 ui.label("Lobby: Add teams and press Start");
                 if crate::theme::accent_button(ui, "Start").clicked() {
                     let action = GameAction::StartGame;
-                    if let Ok(result) = game_engine.handle_action(action) {
+                    if let Some(result) = dispatch(game_engine, action, toasts) {
                         match result {
                             GameActionResult::Success { new_phase } => {
                                 requested_phase = Some(new_phase)
@@ -99,11 +374,69 @@ pub fn show(ctx: &egui::Context, game_engine: &mut GameEngine) -> Option<AppMode
                     }
                 }
             }
-            PlayPhase::Selecting { team_id } => {
-                ui.label(
-                    egui::RichText::new(format!("Selecting — Active Team: {}", team_id))
-                        .color(Palette::MAGENTA),
-                );
+            PlayPhase::Selecting { .. } => {
+                // Selection clock: arm it for the active team and rotate control once it runs out
+                if let Some(limit) = settings.selection_time_limit() {
+                    if clue_selection_open(game_engine.get_state()) {
+                        game_engine.get_state_mut().arm_selection_clock(limit);
+                    }
+                } else {
+                    game_engine.get_state_mut().selection_deadline = None;
+                }
+                let remaining = game_engine.get_state().selection_time_remaining();
+                if remaining.is_some_and(|r| r.is_zero()) {
+                    let action = GameAction::SelectionTimeout;
+                    if let Some(result) = dispatch(game_engine, action, toasts) {
+                        match result {
+                            GameActionResult::Success { new_phase } => {
+                                requested_phase = Some(new_phase)
+                            }
+                            GameActionResult::StateChanged { new_phase, .. } => {
+                                requested_phase = Some(new_phase)
+                            }
+                        }
+                    }
+                }
+                let team_id = match game_engine.get_phase() {
+                    PlayPhase::Selecting { team_id } => *team_id,
+                    _ => game_engine.get_state().active_team,
+                };
+                let mut capture_board = false;
+
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new(format!("Selecting — Active Team: {}", team_id))
+                            .color(Palette::MAGENTA),
+                    );
+                    if let Some(remaining) = remaining {
+                        ui.add_space(12.0);
+                        ui.label(
+                            egui::RichText::new(format!("⏱ {}s", remaining.as_secs_f32().ceil()))
+                                .color(Palette::AMBER_GLOW),
+                        );
+                        ctx.request_repaint_after(Duration::from_millis(250));
+                    }
+                    ui.add_space(12.0);
+                    if crate::theme::secondary_button(ui, "Pause").clicked() {
+                        let action = GameAction::Pause;
+                        if let Some(result) = dispatch(game_engine, action, toasts) {
+                            match result {
+                                GameActionResult::Success { new_phase } => {
+                                    requested_phase = Some(new_phase)
+                                }
+                                GameActionResult::StateChanged { new_phase, .. } => {
+                                    requested_phase = Some(new_phase)
+                                }
+                            }
+                        }
+                    }
+                    ui.add_space(8.0);
+                    draw_manual_event_menu(ui, game_engine, toasts);
+                    ui.add_space(8.0);
+                    capture_board = crate::theme::secondary_button(ui, "📷 Capture Board")
+                        .on_hover_text("Save the board as it looks now to saves/board.png")
+                        .clicked();
+                });
                 let cols = game_engine.get_state().board.categories.len().max(1);
                 let rows = game_engine
                     .get_state()
@@ -112,82 +445,187 @@ pub fn show(ctx: &egui::Context, game_engine: &mut GameEngine) -> Option<AppMode
                     .get(0)
                     .map(|c| c.clues.len())
                     .unwrap_or(0);
-                let available = ui.available_size();
-                let spacing_x = ui.spacing().item_spacing.x;
-                let spacing_y = ui.spacing().item_spacing.y;
-
-                // Calculate cell dimensions based on available space
-                let total_spacing_x = spacing_x * (cols.saturating_sub(1)) as f32;
-                let total_spacing_y = spacing_y * rows as f32; // spacing between header and rows
-                let cell_w = ((available.x - total_spacing_x) / cols as f32).max(60.0);
-
-                // Reserve space for header and distribute remaining height among rows
-                let header_h = (available.y * 0.15).max(40.0).min(60.0); // 15% of height, min 40px, max 60px
-                let remaining_height = available.y - header_h - total_spacing_y;
-                let cell_h = if rows > 0 {
-                    (remaining_height / rows as f32).max(50.0)
-                } else {
-                    70.0
-                };
+                // With an aspect lock the board sits centered inside the free space,
+                // leaving the panel background visible around it
+                let free = ui.available_rect_before_wrap();
+                let board_rect = compute_board_layout(free, settings.board_aspect);
+                if capture_board {
+                    board_capture::request_capture(ctx, board_rect);
+                }
+                let inset_x = board_rect.left() - free.left();
+                ui.add_space(board_rect.top() - free.top());
+                let available = board_rect.size();
+                let style = settings.board_style.clamped();
+                ui.spacing_mut().item_spacing = egui::vec2(style.grid_gap, style.grid_gap);
+                let sizes = board_cell_sizes(available, cols, rows, style.grid_gap);
                 ui.horizontal(|ui| {
-                    ui.set_width(available.x);
-                    for cat in &game_engine.get_state().board.categories {
-                        let (rect, _) = ui.allocate_exact_size(
-                            egui::vec2(cell_w, header_h),
-                            egui::Sense::hover(),
-                        );
+                    ui.set_width(inset_x + available.x);
+                    ui.add_space(inset_x);
+                    for (ci, cat) in game_engine.get_state().board.categories.iter().enumerate() {
+                        // Headers with an intro play it as a card when the host clicks them
+                        let intro = intro_card_text(cat);
+                        let sense = if intro.is_some() {
+                            egui::Sense::click()
+                        } else {
+                            egui::Sense::hover()
+                        };
+                        let (rect, response) = ui.allocate_exact_size(sizes.header, sense);
+                        response.widget_info(|| {
+                            egui::WidgetInfo::labeled(egui::WidgetType::Label, &cat.name)
+                        });
                         let painter = ui.painter_at(rect);
-                        paint_enhanced_category_header(&painter, rect, &cat.name);
+                        paint_enhanced_category_header(
+                            &painter,
+                            rect,
+                            &cat.name,
+                            style.cell_rounding,
+                        );
+                        if let Some(intro) = intro {
+                            if response.clicked() {
+                                ctx.memory_mut(|m| {
+                                    m.data.insert_temp(intro_card_id(), (ci, Instant::now()))
+                                });
+                            }
+                            response.on_hover_text(intro);
+                        }
                     }
                 });
+                draw_category_intro_card(ctx, &game_engine.get_state().board);
                 let mut clicked_clue: Option<(usize, usize)> = None;
-                for r in 0..rows {
+                let mut clicked_rect: Option<egui::Rect> = None;
+                let review_id = egui::Id::new("review_clue");
+                let mut review_clue: Option<(usize, usize)> = ctx
+                    .memory_mut(|m| m.data.get_temp(review_id))
+                    .unwrap_or(None);
+                let cell_size = if settings.scroll_board {
+                    egui::vec2(sizes.cell.x, sizes.cell.y.max(SCROLL_MIN_CELL_HEIGHT))
+                } else {
+                    sizes.cell
+                };
+                let mut draw_row = |ui: &mut egui::Ui, r: usize| {
                     ui.horizontal(|ui| {
-                        ui.set_width(available.x);
+                        ui.set_width(inset_x + available.x);
+                        ui.add_space(inset_x);
                         for (ci, cat) in game_engine.get_state().board.categories.iter().enumerate()
                         {
                             let clue = &cat.clues[r];
-                            let (rect, response) = ui.allocate_exact_size(
-                                egui::vec2(cell_w, cell_h),
-                                egui::Sense::click(),
-                            );
+                            let (rect, response) =
+                                ui.allocate_exact_size(cell_size, egui::Sense::click());
                             let painter = ui.painter_at(rect);
+                            let locked = clue.is_locked(game_engine.get_state().round);
+                            let in_order = !locked
+                                && game_engine
+                                    .rules()
+                                    .is_selection_in_order(game_engine.get_state(), (ci, r));
+                            let cell_state = ClueCellState::from_flags(clue.revealed, clue.solved);
+                            let show_value = game_engine.rules().show_cell_values;
+                            response.widget_info(|| {
+                                egui::WidgetInfo::labeled(
+                                    egui::WidgetType::Button,
+                                    clue_cell_accessible_label(
+                                        &cat.name,
+                                        clue.points,
+                                        cell_state,
+                                        show_value,
+                                    ),
+                                )
+                            });
                             paint_enhanced_clue_cell(
                                 &painter,
                                 rect,
                                 clue.points,
-                                clue.solved,
-                                response.hovered(),
+                                cell_state,
+                                response.hovered() && in_order,
+                                show_value,
+                                style.cell_rounding,
                             );
-                            if !clue.solved && response.clicked() {
-                                clicked_clue = Some((ci, r));
+                            if !clue.solved && clue.bonus_attempt {
+                                // Small corner mark for a clue drawn for a second attempt
+                                painter.text(
+                                    rect.right_top() + egui::vec2(-8.0, 6.0),
+                                    egui::Align2::RIGHT_TOP,
+                                    "🔁",
+                                    egui::FontId::proportional(12.0),
+                                    Palette::SUBTLE_TEAL,
+                                );
+                            }
+                            if !clue.solved && !in_order {
+                                // Dim clues that can't be picked until cheaper ones are done
+                                painter.rect_filled(
+                                    rect,
+                                    egui::Rounding::same(style.cell_rounding),
+                                    egui::Color32::from_black_alpha(150),
+                                );
+                            }
+                            if !clue.solved && locked {
+                                painter.text(
+                                    rect.left_top() + egui::vec2(8.0, 6.0),
+                                    egui::Align2::LEFT_TOP,
+                                    format!("🔒 R{}", clue.unlock_round.unwrap_or_default()),
+                                    egui::FontId::proportional(12.0),
+                                    Palette::SUBTLE_TEAL,
+                                );
+                            }
+                            if let Some(preview) =
+                                host_hover_preview(clue, settings.host_hover_preview)
+                            {
+                                response.clone().on_hover_text(preview);
+                            }
+                            if response.clicked() {
+                                match cell_click(clue.solved, in_order, settings.review_solved) {
+                                    CellClick::Select => {
+                                        clicked_clue = Some((ci, r));
+                                        clicked_rect = Some(rect);
+                                    }
+                                    CellClick::Review => review_clue = Some((ci, r)),
+                                    CellClick::Ignore => {}
+                                }
                             }
                         }
                     });
+                };
+                if settings.scroll_board {
+                    // Headers stay put above; only the clue rows scroll
+                    let regions = split_board_regions(board_rect, sizes.header.y, style.grid_gap);
+                    egui::ScrollArea::vertical()
+                        .max_height(regions.rows.height())
+                        .auto_shrink([false, true])
+                        .show(ui, |ui| {
+                            for r in 0..rows {
+                                draw_row(ui, r);
+                            }
+                        });
+                } else {
+                    for r in 0..rows {
+                        draw_row(ui, r);
+                    }
+                }
+
+                // Solved clue the host is looking back at; never touches the score
+                let review_closed = review_clue
+                    .is_some_and(|clue| draw_review_overlay(ctx, game_engine.get_state(), clue));
+                if review_closed {
+                    review_clue = None;
                 }
+                ctx.memory_mut(|m| m.data.insert_temp(review_id, review_clue));
 
                 // Handle clue selection outside the iteration
-                if let Some(clue) = clicked_clue {
-                    // Check if there's a queued event that should play animation first
-                    if game_engine.get_state().event_state.has_queued_event()
-                        && !game_engine.get_state().event_state.is_animation_playing()
-                    {
-                        // Start the queued event animation instead of selecting the clue
-                        if let Some(queued_event) =
-                            game_engine.get_state().event_state.queued_event.clone()
-                        {
-                            let action = GameAction::PlayEventAnimation {
-                                event: queued_event,
-                            };
-                            let _ = game_engine.handle_action(action);
+                match next_selecting_action(game_engine.get_state(), clicked_clue) {
+                    SelectingIntent::PlayEvent(_) => {
+                        // The click starts the queued event's animation instead of a clue
+                        if event_animation.is_none() {
+                            event_animation = start_queued_event_animation(game_engine, toasts);
                         }
-                    } else if !game_engine.get_state().event_state.is_animation_playing() {
-                        // Only allow clue selection if no animation is playing
-                        let action = GameAction::SelectClue {
-                            clue,
-                            team_id: *team_id,
-                        };
-                        if let Ok(result) = game_engine.handle_action(action) {
+                    }
+                    SelectingIntent::Select(clue) => {
+                        let action = GameAction::SelectClue { clue, team_id };
+                        if let Some(result) = dispatch(game_engine, action, toasts) {
+                            if let Some(from) = clicked_rect.filter(|_| settings.clue_zoom) {
+                                let zoom = ClueZoom::start(clue, from);
+                                ctx.memory_mut(|m| {
+                                    m.data.insert_temp(egui::Id::new("clue_zoom"), zoom)
+                                });
+                            }
                             match result {
                                 GameActionResult::Success { new_phase } => {
                                     requested_phase = Some(new_phase)
@@ -198,6 +636,7 @@ pub fn show(ctx: &egui::Context, game_engine: &mut GameEngine) -> Option<AppMode
                             }
                         }
                     }
+                    SelectingIntent::Ignore => {}
                 }
             }
             PlayPhase::Showing {
@@ -206,25 +645,32 @@ pub fn show(ctx: &egui::Context, game_engine: &mut GameEngine) -> Option<AppMode
                 attempt_count,
                 max_attempts,
             } => {
-                draw_showing_overlay(
-                    ctx,
-                    game_engine,
-                    *clue,
-                    *owner_team_id,
-                    *attempt_count,
-                    *max_attempts,
-                    &mut flash,
-                    &mut requested_phase,
-                    &mut pending_answer,
-                );
+                // The question appears once the clicked cell has filled the screen
+                if !draw_clue_zoom(ctx, *clue, settings.board_style.clamped()) {
+                    draw_showing_overlay(
+                        ctx,
+                        game_engine,
+                        *clue,
+                        *owner_team_id,
+                        *attempt_count,
+                        *max_attempts,
+                        &mut flash,
+                        &mut pending_answer,
+                        settings,
+                        toasts,
+                    );
+                }
             }
             PlayPhase::Steal {
                 clue,
                 queue: _,
                 current,
                 owner_team_id: _,
+                attempted: _,
+                owner_penalty: _,
             } => {
                 let current_team_id = *current;
+                let clue = *clue;
                 let has_more =
                     if let PlayPhase::Steal { queue, .. } = &game_engine.get_state().phase {
                         !queue.is_empty()
@@ -232,37 +678,86 @@ pub fn show(ctx: &egui::Context, game_engine: &mut GameEngine) -> Option<AppMode
                         false
                     };
                 // Precompute immutable data needed for overlay
-                let (question, points) = game_engine
-                    .get_state()
-                    .board
-                    .categories
-                    .get(clue.0)
-                    .and_then(|cat| cat.clues.get(clue.1))
-                    .map(|c| (c.question.clone(), c.points))
+                let (question, points, no_flash, host_answer) = game_engine
+                    .current_clue()
+                    .map(|c| {
+                        let answer = steal_host_answer(c, settings.show_answer_to_host_on_steal);
+                        (c.question.clone(), c.points, c.no_flash, answer)
+                    })
                     .unwrap_or_default();
+                // Show what a steal is actually worth under the current rules and events
+                let events = &game_engine.get_state().event_state;
+                let effective = game_engine.rules().effective_points(
+                    points,
+                    events.is_event_active(&GameEvent::DoublePoints),
+                    events.is_event_active(&GameEvent::ReverseQuestion),
+                    true,
+                );
+                let points_text = if game_engine.rules().show_points_before_resolved() {
+                    points_label(points, effective)
+                } else {
+                    HIDDEN_POINTS_LABEL.to_string()
+                };
                 let team_name = game_engine
                     .get_state()
                     .teams
                     .iter()
                     .find(|t| t.id == current_team_id)
-                    .map(|t| t.name.clone())
+                    .map(|t| t.label())
                     .unwrap_or_else(|| format!("#{}", current_team_id));
-                if let Some(outcome) = draw_steal_overlay(
-                    ctx,
-                    &question,
-                    points,
-                    &team_name,
-                    has_more,
-                    &mut flash,
-                    &mut pending_steal,
-                ) {
-                    // Store pending steal action to be executed after animation completes
-                    if pending_steal.is_none() {
-                        pending_steal = Some((outcome, *clue, current_team_id));
+                // Steal clock: separate from the selection clock, fresh for each stealer
+                if let Some(limit) = settings.steal_timer() {
+                    game_engine.get_state_mut().arm_steal_clock(limit);
+                } else {
+                    game_engine.get_state_mut().steal_deadline = None;
+                }
+                let remaining = game_engine.get_state().steal_time_remaining();
+                if remaining.is_some() {
+                    ctx.request_repaint_after(Duration::from_millis(250));
+                }
+                // Block interactions during flash animation
+                let interaction_blocked = flash.is_some() || pending_steal.is_some();
+                let prompt = StealPrompt {
+                    question,
+                    points_text,
+                    team_name,
+                    has_more_contenders: has_more,
+                    time_remaining: remaining,
+                    host_answer,
+                };
+                if let Some(outcome) = draw_steal_overlay(ctx, &prompt, interaction_blocked) {
+                    // Start animation first; the steal is applied once it completes
+                    let kind = match outcome {
+                        StealOutcome::Correct => AnswerFlash::Correct,
+                        StealOutcome::Incorrect => AnswerFlash::Incorrect,
+                    };
+                    flash = Some((kind, flash_style(no_flash), Instant::now()));
+                    pending_steal = Some((outcome, clue, current_team_id));
+                }
+                // Out of time counts as a miss, unless the host already judged this stealer
+                if remaining.is_some_and(|r| r.is_zero()) && pending_steal.is_none() {
+                    let action = GameAction::StealTimeout;
+                    if let Some(result) = dispatch(game_engine, action, toasts) {
+                        match result {
+                            GameActionResult::Success { new_phase } => {
+                                requested_phase = Some(new_phase)
+                            }
+                            GameActionResult::StateChanged { new_phase, .. } => {
+                                requested_phase = Some(new_phase)
+                            }
+                        }
                     }
                 }
             }
             PlayPhase::Resolved { clue, next_team_id } => {
+                // Decide once, as the clue resolves, whether its answer waits for a click
+                let reveal_id = answer_reveal_id(*clue);
+                ctx.memory_mut(|m| {
+                    if m.data.get_temp::<AnswerReveal>(reveal_id).is_none() {
+                        let reveal = AnswerReveal::initial(settings.reveal_answer_on_click);
+                        m.data.insert_temp(reveal_id, reveal);
+                    }
+                });
                 draw_resolved_overlay(
                     ctx,
                     game_engine,
@@ -270,13 +765,35 @@ pub fn show(ctx: &egui::Context, game_engine: &mut GameEngine) -> Option<AppMode
                     *next_team_id,
                     &mut requested_phase,
                     &flash,
+                    toasts,
                 );
             }
             PlayPhase::Intermission => {
-                ui.label("Intermission");
+                draw_intermission_overlay(
+                    ctx,
+                    game_engine,
+                    settings.score_privacy,
+                    &mut requested_phase,
+                    toasts,
+                );
             }
             PlayPhase::Finished => {
                 ui.label("Finished");
+                draw_final_wagers(ui, game_engine, toasts);
+                let state = game_engine.get_state();
+                if let Some(winner) = state
+                    .determine_winner()
+                    .and_then(|id| state.get_team_by_id(id))
+                {
+                    ui.heading(
+                        egui::RichText::new(format!("Winner: {}", winner.label()))
+                            .color(Palette::CYBER_YELLOW),
+                    );
+                }
+                if crate::theme::accent_button(ui, "Play Again").clicked() {
+                    game_engine.reset_to_lobby();
+                    requested_phase = Some(PlayPhase::Lobby);
+                }
                 if crate::theme::secondary_button(ui, "Back to Config").clicked() {
                     next_mode = Some(AppMode::Config(crate::core::ConfigState {
                         board: Board::default(),
@@ -285,11 +802,8 @@ pub fn show(ctx: &egui::Context, game_engine: &mut GameEngine) -> Option<AppMode
             }
         }
 
-        if let Some((kind, start)) = flash {
-            let elapsed = start.elapsed();
-            let duration = Duration::from_millis(1200); // Extended duration for more expressive animation
-            if elapsed < duration {
-                let t = (elapsed.as_secs_f32() / duration.as_secs_f32()).clamp(0.0, 1.0);
+        if let Some((kind, style, start)) = flash {
+            if let Some(t) = flash_progress(start, Instant::now(), settings.flash_duration()) {
                 let ctx = ui.ctx();
                 let rect = ctx.screen_rect();
                 egui::Area::new("answer_flash_overlay".into())
@@ -299,20 +813,22 @@ pub fn show(ctx: &egui::Context, game_engine: &mut GameEngine) -> Option<AppMode
                     .fixed_pos(rect.min)
                     .show(ctx, |ui| {
                         let painter = ui.painter_at(rect);
-                        match kind {
-                            AnswerFlash::Correct => {
+                        let params = settings.animation_intensity.burst_params();
+                        match (style, kind) {
+                            (FlashStyle::Fade, _) => draw_fade_flash(&painter, rect, t, kind),
+                            (FlashStyle::Burst, AnswerFlash::Correct) => {
                                 // Success burst animation with multiple layers
-                                draw_success_animation(&painter, rect, t);
+                                draw_success_animation(&painter, rect, t, &params);
                             }
-                            AnswerFlash::Incorrect => {
+                            (FlashStyle::Burst, AnswerFlash::Incorrect) => {
                                 // Use the same burst animation style but red variant
-                                draw_failure_animation(&painter, rect, t);
+                                draw_failure_animation(&painter, rect, t, &params);
                             }
                         }
                     });
 
                 ctx.request_repaint();
-                ui.memory_mut(|m| m.data.insert_temp(flash_id, Some((kind, start))));
+                ui.memory_mut(|m| m.data.insert_temp(flash_id, Some((kind, style, start))));
             } else {
                 // Animation finished -> if we have a pending answer or steal, now apply the game action
                 if let Some((pending_kind, clue, owner_team_id)) = pending_answer.take() {
@@ -326,7 +842,7 @@ pub fn show(ctx: &egui::Context, game_engine: &mut GameEngine) -> Option<AppMode
                             team_id: owner_team_id,
                         },
                     };
-                    if let Ok(result) = game_engine.handle_action(action) {
+                    if let Some(result) = dispatch(game_engine, action, toasts) {
                         match result {
                             GameActionResult::Success { new_phase } => {
                                 requested_phase = Some(new_phase)
@@ -335,8 +851,7 @@ pub fn show(ctx: &egui::Context, game_engine: &mut GameEngine) -> Option<AppMode
                                 new_phase, effects, ..
                             } => {
                                 requested_phase = Some(new_phase);
-                                // Effects already represented visually by animation; nothing extra for now
-                                let _ = effects; // suppress unused warning if any
+                                push_effect_toasts(toasts, &effects);
                             }
                         }
                     }
@@ -350,7 +865,7 @@ pub fn show(ctx: &egui::Context, game_engine: &mut GameEngine) -> Option<AppMode
                         team_id,
                         correct,
                     };
-                    if let Ok(result) = game_engine.handle_action(action) {
+                    if let Some(result) = dispatch(game_engine, action, toasts) {
                         match result {
                             GameActionResult::Success { new_phase } => {
                                 requested_phase = Some(new_phase)
@@ -359,15 +874,15 @@ pub fn show(ctx: &egui::Context, game_engine: &mut GameEngine) -> Option<AppMode
                                 new_phase, effects, ..
                             } => {
                                 requested_phase = Some(new_phase);
-                                // Effects already represented visually by animation; nothing extra for now
-                                let _ = effects; // suppress unused warning if any
+                                push_effect_toasts(toasts, &effects);
                             }
                         }
                     }
                 }
 
                 ui.memory_mut(|m| {
-                    m.data.remove::<Option<(AnswerFlash, Instant)>>(flash_id);
+                    m.data
+                        .remove::<Option<(AnswerFlash, FlashStyle, Instant)>>(flash_id);
                     m.data
                         .remove::<Option<(AnswerFlash, (usize, usize), u32)>>(pending_answer_id);
                     m.data
@@ -375,17 +890,22 @@ pub fn show(ctx: &egui::Context, game_engine: &mut GameEngine) -> Option<AppMode
                 });
             }
         } else {
-            ui.memory_mut(|m| m.data.remove::<Option<(AnswerFlash, Instant)>>(flash_id));
+            ui.memory_mut(|m| {
+                m.data
+                    .remove::<Option<(AnswerFlash, FlashStyle, Instant)>>(flash_id)
+            });
         }
 
         // Handle event animations
         if let Some(mut controller) = event_animation.take() {
             if controller.update() {
                 // Animation completed - reset animation playing state
-                game_engine
-                    .get_state_mut()
-                    .event_state
-                    .set_animation_playing(false);
+                let event_state = &mut game_engine.get_state_mut().event_state;
+                event_state.set_animation_playing(false);
+                if settings.announce_events {
+                    event_state.pending_announcement =
+                        controller.active_animation.map(|a| a.event_type);
+                }
                 event_animation = None;
             } else {
                 // Animation still running, render it
@@ -430,42 +950,13 @@ pub fn show(ctx: &egui::Context, game_engine: &mut GameEngine) -> Option<AppMode
             }
         }
 
-        // Check for new event animations from game effects
-        // This would be triggered by GameEffect::EventAnimation
+        // A queued event plays as soon as nothing else is animating
         if event_animation.is_none() {
-            // Check if there's a queued event that should trigger an animation
-            if game_engine.get_state().event_state.has_queued_event()
-                && !game_engine.get_state().event_state.is_animation_playing()
-            {
-                if let Some(queued_event) = game_engine.get_state().event_state.queued_event.clone()
-                {
-                    let mut controller = EventAnimationController::new();
-                    let duration = match queued_event {
-                        GameEvent::DoublePoints => Duration::from_millis(3000),
-                        GameEvent::HardReset => Duration::from_millis(4000),
-                        GameEvent::ReverseQuestion => Duration::from_millis(2500),
-                        GameEvent::ScoreSteal => Duration::from_millis(3200),
-                    };
-                    controller.start_animation(queued_event.clone(), duration);
-
-                    // Mark animation as playing and consume the queued event
-                    game_engine
-                        .get_state_mut()
-                        .event_state
-                        .set_animation_playing(true);
-                    let _ = game_engine.get_state_mut().event_state.take_queued_event();
-
-                    // For non-Hard Reset/ScoreSteal events, activate them now for the next cell
-                    if !matches!(queued_event, GameEvent::HardReset | GameEvent::ScoreSteal) {
-                        game_engine
-                            .get_state_mut()
-                            .event_state
-                            .activate_event(queued_event);
-                    }
+            event_animation = start_queued_event_animation(game_engine, toasts);
+        }
 
-                    event_animation = Some(controller);
-                }
-            }
+        if event_animation.is_none() {
+            draw_event_announcement(ctx, game_engine, toasts);
         }
 
         // Persist pending answer and steal if still waiting (flash active)
@@ -492,7 +983,8 @@ pub fn show(ctx: &egui::Context, game_engine: &mut GameEngine) -> Option<AppMode
                 if let Some(active) = flash {
                     m.data.insert_temp(flash_id, Some(active));
                 } else {
-                    m.data.remove::<Option<(AnswerFlash, Instant)>>(flash_id);
+                    m.data
+                        .remove::<Option<(AnswerFlash, FlashStyle, Instant)>>(flash_id);
                 }
                 // Pending answer and steal only used in their respective phases
                 m.data
@@ -510,10 +1002,32 @@ pub fn show(ctx: &egui::Context, game_engine: &mut GameEngine) -> Option<AppMode
         &game_engine.get_state().teams,
     ) {
         for (team_id, new_points) in changes {
-            let _ = game_engine.handle_action(GameAction::ManualPointsAdjustment {
+            let action = GameAction::ManualPointsAdjustment {
                 team_id,
                 new_points,
-            });
+            };
+            if let Some(GameActionResult::StateChanged { effects, .. }) =
+                dispatch(game_engine, action, toasts)
+            {
+                for effect in effects {
+                    if let GameEffect::ManualScoreAdjustment {
+                        team_id,
+                        old_score,
+                        new_score,
+                    } = effect
+                    {
+                        let name = game_engine
+                            .get_state()
+                            .get_team_by_id(team_id)
+                            .map(|t| t.label())
+                            .unwrap_or_default();
+                        toasts.push(
+                            ToastKind::Success,
+                            format!("{}: {} → {}", name, old_score, new_score),
+                        );
+                    }
+                }
+            }
         }
     }
 
@@ -526,48 +1040,237 @@ pub fn show(ctx: &egui::Context, game_engine: &mut GameEngine) -> Option<AppMode
     next_mode
 }
 
-fn draw_showing_overlay(
-    ctx: &egui::Context,
-    game_engine: &mut GameEngine,
-    clue: (usize, usize),
-    owner_team_id: u32,
-    attempt_count: u32,
-    max_attempts: u32,
-    flash: &mut Option<(AnswerFlash, Instant)>,
-    _requested_phase: &mut Option<PlayPhase>,
-    pending_answer: &mut Option<(AnswerFlash, (usize, usize), u32)>,
-) {
-    let screen = ctx.screen_rect();
-    egui::Area::new("question_full_overlay".into())
-        .order(egui::Order::Foreground)
-        .movable(false)
-        .interactable(true)
-        .fixed_pos(screen.min)
-        .show(ctx, |ui| {
-            let rect = screen;
-            let painter = ui.painter_at(rect);
-
-            // Subtle modal background for dialogue
-            paint_subtle_modal_background(&painter, rect);
-
-            let (question, points) = game_engine
-                .get_state()
-                .board
-                .categories
-                .get(clue.0)
-                .and_then(|cat| cat.clues.get(clue.1))
-                .map(|c| (c.question.clone(), c.points))
-                .unwrap_or_default();
-
-            ui.allocate_ui_with_layout(
-                rect.size(),
-                egui::Layout::top_down(egui::Align::Center),
+/// Final round once the board is done: each team locks in a wager, then the
+/// host judges its answer
+fn draw_final_wagers(ui: &mut egui::Ui, game_engine: &mut GameEngine, toasts: &mut ToastQueue) {
+    ui.label(egui::RichText::new("Final Wagers").color(Palette::CYAN));
+    let teams: Vec<(u32, String, i32)> = game_engine
+        .get_state()
+        .teams
+        .iter()
+        .map(|t| (t.id, t.label(), t.score))
+        .collect();
+    for (team_id, name, score) in teams {
+        ui.horizontal(|ui| {
+            ui.label(format!("{} ({})", name, score));
+            if let Some((wager, correct)) = game_engine.get_state().wager_result(team_id) {
+                let (verdict, color) = if correct {
+                    ("won", Palette::CYAN)
+                } else {
+                    ("lost", Palette::MAGENTA)
+                };
+                ui.label(egui::RichText::new(format!("{} {}", verdict, wager)).color(color));
+            } else if let Some(wager) = game_engine.get_state().wager_for(team_id) {
+                ui.label(format!("wagered {}", wager));
+                for (label, correct) in [("✓ Correct", true), ("✗ Wrong", false)] {
+                    if ui.button(label).clicked() {
+                        let action = GameAction::ResolveWager { team_id, correct };
+                        dispatch(game_engine, action, toasts);
+                    }
+                }
+            } else {
+                // Teams with nothing to risk can only lock in 0
+                let draft_id = egui::Id::new("wager_draft").with(team_id);
+                let mut wager: i32 = ui.data_mut(|d| d.get_temp(draft_id)).unwrap_or(0);
+                crate::ui::score_input(ui, &mut wager, 0, score.max(0));
+                ui.data_mut(|d| d.insert_temp(draft_id, wager));
+                if ui.button("Lock In").clicked() {
+                    dispatch(
+                        game_engine,
+                        GameAction::SubmitWager { team_id, wager },
+                        toasts,
+                    );
+                }
+            }
+        });
+    }
+    ui.add_space(8.0);
+}
+
+/// How long a category's intro card stays up unless the host clicks it away
+const CATEGORY_INTRO_DURATION: Duration = Duration::from_secs(6);
+
+/// The intro sentence a category's card shows; None skips the card
+fn intro_card_text(category: &Category) -> Option<&str> {
+    category
+        .intro
+        .as_deref()
+        .map(str::trim)
+        .filter(|intro| !intro.is_empty())
+}
+
+fn intro_card_id() -> egui::Id {
+    egui::Id::new("category_intro_card")
+}
+
+/// Card with a category's name and intro, under the board header, for a few
+/// seconds after the host clicks the header
+fn draw_category_intro_card(ctx: &egui::Context, board: &Board) {
+    let Some((ci, started)) =
+        ctx.memory_mut(|m| m.data.get_temp::<(usize, Instant)>(intro_card_id()))
+    else {
+        return;
+    };
+    let remaining = CATEGORY_INTRO_DURATION.saturating_sub(started.elapsed());
+    let card = board
+        .categories
+        .get(ci)
+        .and_then(|cat| Some((cat.name.as_str(), intro_card_text(cat)?)));
+    let Some((name, intro)) = card.filter(|_| !remaining.is_zero()) else {
+        ctx.memory_mut(|m| m.data.remove::<(usize, Instant)>(intro_card_id()));
+        return;
+    };
+    let response = egui::Area::new("category_intro_card".into())
+        .order(egui::Order::Foreground)
+        .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 96.0))
+        .show(ctx, |ui| {
+            egui::Frame::none()
+                .fill(Palette::BG_PANEL)
+                .stroke(egui::Stroke::new(2.0, Palette::CYAN))
+                .inner_margin(egui::Margin::same(16.0))
+                .rounding(8.0)
+                .show(ui, |ui| {
+                    ui.set_max_width(480.0);
+                    ui.vertical_centered(|ui| {
+                        ui.label(
+                            egui::RichText::new(name)
+                                .color(Palette::CYAN)
+                                .size(24.0)
+                                .strong(),
+                        );
+                        ui.add_space(6.0);
+                        ui.label(egui::RichText::new(intro).color(Palette::TEXT).size(18.0));
+                    });
+                });
+        })
+        .response;
+    if response.interact(egui::Sense::click()).clicked() {
+        ctx.memory_mut(|m| m.data.remove::<(usize, Instant)>(intro_card_id()));
+    } else {
+        ctx.request_repaint_after(remaining);
+    }
+}
+
+/// Paint the clicked cell growing toward the full screen, its corners easing from
+/// the board's cell rounding to square; true while it's still growing
+fn draw_clue_zoom(ctx: &egui::Context, clue: (usize, usize), style: BoardStyle) -> bool {
+    let id = egui::Id::new("clue_zoom");
+    let Some(mut zoom) = ctx.memory_mut(|m| m.data.get_temp::<ClueZoom>(id)) else {
+        return false;
+    };
+    let t = zoom.animation.update();
+    if zoom.clue != clue || zoom.animation.is_complete() {
+        ctx.memory_mut(|m| m.data.remove::<ClueZoom>(id));
+        return false;
+    }
+    let rect = lerp_rect(zoom.from, ctx.screen_rect(), t);
+    let painter = ctx.layer_painter(egui::LayerId::new(egui::Order::Foreground, id));
+    painter.rect(
+        rect,
+        egui::Rounding::same(style.cell_rounding * (1.0 - t)),
+        Palette::BG_PANEL,
+        egui::Stroke::new(2.0, Palette::CYAN),
+    );
+    ctx.memory_mut(|m| m.data.insert_temp(id, zoom));
+    ctx.request_repaint();
+    true
+}
+
+fn draw_showing_overlay(
+    ctx: &egui::Context,
+    game_engine: &mut GameEngine,
+    clue: (usize, usize),
+    owner_team_id: u32,
+    attempt_count: u32,
+    max_attempts: u32,
+    flash: &mut Option<(AnswerFlash, FlashStyle, Instant)>,
+    pending_answer: &mut Option<(AnswerFlash, (usize, usize), u32)>,
+    settings: &AppSettings,
+    toasts: &mut ToastQueue,
+) {
+    let typewriter_reveal = settings.typewriter_reveal;
+    // Typewriter progress for this clue: (started, skipped)
+    let reveal_id = egui::Id::new(("question_reveal", clue));
+    let (reveal_started, mut reveal_skipped): (Instant, bool) = ctx
+        .memory_mut(|m| m.data.get_temp(reveal_id))
+        .unwrap_or((Instant::now(), !typewriter_reveal));
+    // Host's free-text capture for survey rounds, kept while the clue is open
+    let note_id = egui::Id::new(("capture_note", clue));
+    let mut note: String = ctx
+        .memory_mut(|m| m.data.get_temp(note_id))
+        .unwrap_or_default();
+    let mut note_captured = false;
+    // Outcome waiting on the host's confirm for a high-value clue
+    let confirm_id = egui::Id::new(("outcome_confirm", clue));
+    let mut confirming: Option<AnswerFlash> = ctx
+        .memory_mut(|m| m.data.get_temp(confirm_id))
+        .unwrap_or(None);
+    // What the host heard a team say, for the answer-similarity hint
+    let heard_id = egui::Id::new(("answer_assist", clue));
+    let mut heard: String = ctx
+        .memory_mut(|m| m.data.get_temp(heard_id))
+        .unwrap_or_default();
+    let (question, points, media, no_flash) = game_engine
+        .clue_at(clue.0, clue.1)
+        .map(|c| (c.question.clone(), c.points, c.media.clone(), c.no_flash))
+        .unwrap_or_default();
+    let similarity = settings
+        .answer_assist
+        .then(|| {
+            game_engine
+                .clue_at(clue.0, clue.1)
+                .map(|c| c.best_answer_similarity(&heard))
+        })
+        .flatten();
+    let style = flash_style(no_flash);
+    // Show what the clue is really worth under any active event
+    let events = &game_engine.get_state().event_state;
+    let effective = game_engine.rules().effective_points(
+        points,
+        events.is_event_active(&GameEvent::DoublePoints),
+        events.is_event_active(&GameEvent::ReverseQuestion),
+        false,
+    );
+    let show_points = game_engine.rules().show_points_before_resolved();
+    let screen = ctx.screen_rect();
+    egui::Area::new("question_full_overlay".into())
+        .order(egui::Order::Foreground)
+        .movable(false)
+        .interactable(true)
+        .fixed_pos(screen.min)
+        .show(ctx, |ui| {
+            let rect = screen;
+            let painter = ui.painter_at(rect);
+
+            // Subtle modal background for dialogue
+            paint_subtle_modal_background(&painter, rect);
+
+            let total_chars = question.chars().count();
+            let visible_chars = crate::theme::animations::typewriter_visible_chars(
+                total_chars,
+                reveal_started.elapsed(),
+                Duration::from_millis(1500),
+                reveal_skipped,
+            );
+            let fully_revealed = visible_chars >= total_chars;
+            if !fully_revealed {
+                ctx.request_repaint();
+            }
+
+            ui.allocate_ui_with_layout(
+                rect.size(),
+                egui::Layout::top_down(egui::Align::Center),
                 |ui| {
                     ui.add_space(50.0);
 
                     // Enhanced points display with amber glow
+                    let points_text = if show_points {
+                        points_label(points, effective)
+                    } else {
+                        HIDDEN_POINTS_LABEL.to_string()
+                    };
                     ui.heading(
-                        egui::RichText::new(format!("{} pts", points))
+                        egui::RichText::new(points_text)
                             .color(Palette::AMBER_GLOW)
                             .size(36.0),
                     );
@@ -589,16 +1292,57 @@ fn draw_showing_overlay(
 
                     ui.add_space(30.0);
 
+                    if let Some(media) = &media {
+                        let max_size = egui::vec2(rect.width() * 0.5, rect.height() * 0.35);
+                        show_clue_media(ui, clue, media, max_size);
+                        ui.add_space(20.0);
+                    }
+
                     // Enhanced question text with yellow styling
                     let wrap_width = rect.width() * 0.85;
+                    let shown: String = question.chars().take(visible_chars).collect();
                     let label = egui::Label::new(
-                        egui::RichText::new(question)
+                        egui::RichText::new(shown)
                             .size(30.0)
                             .color(Palette::CYBER_YELLOW),
                     )
                     .wrap(true)
-                    .truncate(false);
-                    ui.add_sized([wrap_width, 0.0], label);
+                    .truncate(false)
+                    .sense(egui::Sense::click());
+                    // Clicking the text skips the rest of the reveal
+                    if ui.add_sized([wrap_width, 0.0], label).clicked() {
+                        reveal_skipped = true;
+                    }
+                    if !fully_revealed {
+                        ui.add_space(8.0);
+                        ui.label(
+                            egui::RichText::new("Click the question to reveal it all")
+                                .color(Palette::SUBTLE_TEAL),
+                        );
+                    }
+
+                    ui.add_space(24.0);
+                    ui.add(
+                        egui::TextEdit::multiline(&mut note)
+                            .hint_text("Host note (not scored)")
+                            .desired_rows(2)
+                            .desired_width(wrap_width * 0.6),
+                    );
+                    if settings.answer_assist {
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.add(
+                                egui::TextEdit::singleline(&mut heard)
+                                    .hint_text("What the team said")
+                                    .desired_width(wrap_width * 0.4),
+                            );
+                            if let Some(hint) =
+                                similarity.and_then(|s| answer_match_hint(s, heard.trim()))
+                            {
+                                ui.label(hint);
+                            }
+                        });
+                    }
                 },
             );
 
@@ -615,18 +1359,30 @@ fn draw_showing_overlay(
                     |ui| {
                         ui.set_width(bottom_rect.width());
                         ui.horizontal(|ui| {
+                            // Answers are judged only once the whole question is on screen
+                            ui.set_enabled(fully_revealed);
                             // Block interactions during flash animation
-                            let interaction_blocked = flash.is_some() || pending_answer.is_some();
+                            let interaction_blocked =
+                                flash.is_some() || pending_answer.is_some() || confirming.is_some();
+                            let needs_confirm = settings.needs_outcome_confirmation(points);
 
                             if enhanced_modal_button(ui, "Correct", ModalButtonType::Correct)
                                 .clicked()
                                 && !interaction_blocked
+                                && accept_overlay_click(ui.ctx())
                             {
-                                // Start animation first; delay state mutation until animation completes
-                                if flash.is_none() && pending_answer.is_none() {
-                                    *flash = Some((AnswerFlash::Correct, Instant::now()));
-                                    *pending_answer =
-                                        Some((AnswerFlash::Correct, clue, owner_team_id));
+                                if needs_confirm {
+                                    confirming = Some(AnswerFlash::Correct);
+                                } else {
+                                    // Start animation first; delay state mutation until animation completes
+                                    start_answer(
+                                        flash,
+                                        pending_answer,
+                                        AnswerFlash::Correct,
+                                        style,
+                                        clue,
+                                        owner_team_id,
+                                    );
                                 }
                             }
 
@@ -635,18 +1391,135 @@ fn draw_showing_overlay(
                             if enhanced_modal_button(ui, "Incorrect", ModalButtonType::Incorrect)
                                 .clicked()
                                 && !interaction_blocked
+                                && accept_overlay_click(ui.ctx())
                             {
-                                if flash.is_none() && pending_answer.is_none() {
-                                    *flash = Some((AnswerFlash::Incorrect, Instant::now()));
-                                    *pending_answer =
-                                        Some((AnswerFlash::Incorrect, clue, owner_team_id));
+                                if needs_confirm {
+                                    confirming = Some(AnswerFlash::Incorrect);
+                                } else {
+                                    start_answer(
+                                        flash,
+                                        pending_answer,
+                                        AnswerFlash::Incorrect,
+                                        style,
+                                        clue,
+                                        owner_team_id,
+                                    );
                                 }
                             }
+
+                            ui.add_space(40.0);
+
+                            // Record the note and close without judging anyone
+                            if enhanced_modal_button(ui, "Save Note", ModalButtonType::Close)
+                                .clicked()
+                                && !interaction_blocked
+                                && accept_overlay_click(ui.ctx())
+                            {
+                                let action = GameAction::CaptureNote {
+                                    clue,
+                                    note: note.clone(),
+                                };
+                                note_captured = dispatch(game_engine, action, toasts).is_some();
+                            }
                         });
                     },
                 );
             });
         });
+    if let Some(outcome) = confirming {
+        let team_name = game_engine
+            .get_state()
+            .get_team_by_id(owner_team_id)
+            .map(|t| t.label())
+            .unwrap_or_default();
+        let question = match outcome {
+            AnswerFlash::Correct => format!("Award {} to {}?", effective, team_name),
+            AnswerFlash::Incorrect => {
+                format!("Mark {} incorrect on a {}-point clue?", team_name, points)
+            }
+        };
+        egui::Area::new("outcome_confirm".into())
+            .order(egui::Order::Tooltip)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                crate::theme::window_frame().show(ui, |ui| {
+                    ui.label(
+                        egui::RichText::new(question)
+                            .size(22.0)
+                            .color(Palette::CYBER_YELLOW),
+                    );
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        if crate::theme::accent_button(ui, "Confirm").clicked()
+                            && accept_overlay_click(ui.ctx())
+                        {
+                            start_answer(
+                                flash,
+                                pending_answer,
+                                outcome,
+                                style,
+                                clue,
+                                owner_team_id,
+                            );
+                            confirming = None;
+                        }
+                        if crate::theme::secondary_button(ui, "Cancel").clicked() {
+                            confirming = None;
+                        }
+                    });
+                });
+            });
+    }
+
+    ctx.memory_mut(|m| {
+        m.data.insert_temp(confirm_id, confirming);
+        m.data
+            .insert_temp(reveal_id, (reveal_started, reveal_skipped));
+        if note_captured {
+            m.data.remove::<String>(note_id);
+        } else {
+            m.data.insert_temp(note_id, note);
+        }
+        m.data.insert_temp(heard_id, heard);
+    });
+}
+
+/// Host-only hint for how well a typed answer matches; the host still judges
+fn answer_match_hint(similarity: f32, heard: &str) -> Option<egui::RichText> {
+    if heard.is_empty() {
+        return None;
+    }
+    let percent = (similarity * 100.0).round();
+    let (text, color) = if similarity >= 0.85 {
+        (format!("Looks right ({}%)", percent), Palette::CYAN)
+    } else if similarity >= 0.6 {
+        (format!("Close ({}%)", percent), Palette::AMBER_GLOW)
+    } else {
+        (format!("Doesn't match ({}%)", percent), Palette::MAGENTA)
+    };
+    Some(egui::RichText::new(text).color(color))
+}
+
+/// How far through the flash we are at `now` (0..1), or None once it has run its
+/// course and the pending answer or steal should be applied
+fn flash_progress(start: Instant, now: Instant, duration: Duration) -> Option<f32> {
+    let elapsed = now.saturating_duration_since(start);
+    (elapsed < duration).then(|| elapsed.as_secs_f32() / duration.as_secs_f32())
+}
+
+/// Kick off the answer flash; the engine is told once the animation completes
+fn start_answer(
+    flash: &mut Option<(AnswerFlash, FlashStyle, Instant)>,
+    pending_answer: &mut Option<(AnswerFlash, (usize, usize), u32)>,
+    outcome: AnswerFlash,
+    style: FlashStyle,
+    clue: (usize, usize),
+    owner_team_id: u32,
+) {
+    if flash.is_none() && pending_answer.is_none() {
+        *flash = Some((outcome, style, Instant::now()));
+        *pending_answer = Some((outcome, clue, owner_team_id));
+    }
 }
 
 // Heist animation for ScoreSteal event
@@ -773,14 +1646,456 @@ fn draw_score_steal_animation(
     }
 }
 
-fn draw_steal_overlay(
+/// Split bulk team entry into names: one per line, trimmed, blanks skipped.
+/// With `unique` set, names matching an existing team or an earlier line
+/// (ignoring case) are dropped.
+pub(crate) fn parse_team_lines(text: &str, existing: &[String], unique: bool) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for line in text.lines() {
+        let name = line.trim();
+        if name.is_empty() {
+            continue;
+        }
+        let taken = existing
+            .iter()
+            .chain(names.iter())
+            .any(|n| n.trim().eq_ignore_ascii_case(name));
+        if unique && taken {
+            continue;
+        }
+        names.push(name.to_string());
+    }
+    names
+}
+
+fn draw_bulk_team_entry(
+    ui: &mut egui::Ui,
+    game_engine: &mut GameEngine,
+    settings: &mut AppSettings,
+    toasts: &mut ToastQueue,
+) {
+    let text_id = egui::Id::new("bulk_team_entry");
+    let mut text: String = ui
+        .memory_mut(|m| m.data.get_temp(text_id))
+        .unwrap_or_default();
+
+    ui.add_space(10.0);
+    ui.collapsing("Bulk add", |ui| {
+        ui.add(
+            egui::TextEdit::multiline(&mut text)
+                .hint_text("One team per line")
+                .desired_rows(5),
+        );
+        ui.checkbox(&mut settings.unique_team_names, "Skip duplicate names");
+        ui.horizontal(|ui| {
+            if crate::theme::accent_button(ui, "Add Teams").clicked() {
+                let existing: Vec<String> = game_engine
+                    .get_state()
+                    .teams
+                    .iter()
+                    .map(|t| t.name.clone())
+                    .collect();
+                for name in parse_team_lines(&text, &existing, settings.unique_team_names) {
+                    // Stop at the team limit rather than dropping names silently in the middle
+                    if dispatch(game_engine, GameAction::AddTeam { name }, toasts).is_none() {
+                        break;
+                    }
+                }
+                text.clear();
+            }
+            if crate::theme::secondary_button(ui, "Clear all teams").clicked() {
+                request_team_removal(ui.ctx(), TeamRemoval::ClearAll, game_engine.team_count());
+            }
+        });
+    });
+
+    ui.memory_mut(|m| m.data.insert_temp(text_id, text));
+}
+
+/// Lobby team operations that throw away names the host has typed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TeamRemoval {
+    ClearAll,
+    RemoveLast,
+}
+
+const TEAM_REMOVAL_ID: &str = "team_removal_confirm";
+
+/// Only a roster with someone on it is worth asking about
+fn team_removal_needs_confirm(team_count: usize) -> bool {
+    team_count > 0
+}
+
+/// Ask before a destructive team operation; with no teams there's nothing to lose
+fn request_team_removal(ctx: &egui::Context, removal: TeamRemoval, team_count: usize) {
+    if team_removal_needs_confirm(team_count) {
+        ctx.memory_mut(|m| m.data.insert_temp(egui::Id::new(TEAM_REMOVAL_ID), removal));
+    }
+}
+
+fn draw_team_removal_confirm(
     ctx: &egui::Context,
-    question: &str,
-    points: u32,
-    team_name: &str,
+    game_engine: &mut GameEngine,
+    settings: &mut AppSettings,
+    toasts: &mut ToastQueue,
+) {
+    let id = egui::Id::new(TEAM_REMOVAL_ID);
+    let Some(removal) = ctx.memory_mut(|m| m.data.get_temp::<TeamRemoval>(id)) else {
+        return;
+    };
+    let (question, action) = match removal {
+        TeamRemoval::ClearAll => (
+            format!("Remove all {} teams?", game_engine.team_count()),
+            GameAction::ClearTeams,
+        ),
+        TeamRemoval::RemoveLast => {
+            let name = game_engine
+                .get_state()
+                .teams
+                .last()
+                .map(|t| t.label())
+                .unwrap_or_default();
+            (format!("Remove {}?", name), GameAction::RemoveLastTeam)
+        }
+    };
+    let mut done = false;
+    egui::Area::new(id)
+        .order(egui::Order::Tooltip)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            crate::theme::window_frame().show(ui, |ui| {
+                ui.label(
+                    egui::RichText::new(question)
+                        .size(22.0)
+                        .color(Palette::CYBER_YELLOW),
+                );
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    if crate::theme::danger_button(ui, "Remove").clicked() {
+                        dispatch(game_engine, action, toasts);
+                        // A starred team may no longer exist
+                        let state = game_engine.get_state();
+                        if matches!(settings.rules.first_team,
+                            FirstTeamPolicy::Specific(team_id) if state.get_team_by_id(team_id).is_none())
+                        {
+                            settings.rules.first_team = FirstTeamPolicy::FirstAdded;
+                        }
+                        done = true;
+                    }
+                    if crate::theme::secondary_button(ui, "Cancel").clicked() {
+                        done = true;
+                    }
+                });
+            });
+        });
+    if done || ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+        ctx.memory_mut(|m| m.data.remove::<TeamRemoval>(id));
+    }
+}
+
+/// AddTeam actions for the names typed into the setup wizard, in order;
+/// a blank entry takes its default name from `naming`
+fn wizard_add_team_actions(names: &[String], naming: &TeamNaming) -> Vec<GameAction> {
+    names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let name = name.trim();
+            let name = if name.is_empty() {
+                naming.name_for(i)
+            } else {
+                name.to_string()
+            };
+            GameAction::AddTeam { name }
+        })
+        .collect()
+}
+
+/// First-run prompt for how many teams are playing and what they're called.
+/// Shown in the lobby while there are no teams, until the host creates them or skips.
+fn draw_team_wizard(
+    ctx: &egui::Context,
+    game_engine: &mut GameEngine,
+    naming: &TeamNaming,
+    toasts: &mut ToastQueue,
+) {
+    let wizard_id = egui::Id::new("team_wizard");
+    // (team names, skipped)
+    let (mut names, mut skipped): (Vec<String>, bool) = ctx
+        .memory_mut(|m| m.data.get_temp(wizard_id))
+        .unwrap_or_else(|| (vec![String::new(); 2], false));
+    if skipped
+        || !matches!(game_engine.get_phase(), PlayPhase::Lobby)
+        || game_engine.team_count() > 0
+    {
+        return;
+    }
+
+    let max_teams = game_engine.rules().max_teams.max(1);
+    let mut create = false;
+    egui::Area::new("team_wizard".into())
+        .order(egui::Order::Foreground)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            crate::theme::window_frame().show(ui, |ui| {
+                let mut focus = FocusTrap::new("team_wizard");
+                ui.set_min_width(320.0);
+                ui.heading(egui::RichText::new("Who's playing?").color(Palette::CYAN));
+                ui.add_space(8.0);
+                let mut count = names.len();
+                ui.horizontal(|ui| {
+                    ui.label("Teams");
+                    focus.add(&ui.add(egui::DragValue::new(&mut count).clamp_range(1..=max_teams)));
+                });
+                names.resize(count, String::new());
+                for (i, name) in names.iter_mut().enumerate() {
+                    focus.add(
+                        &ui.add(egui::TextEdit::singleline(name).hint_text(naming.name_for(i))),
+                    );
+                }
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    let create_button = crate::theme::accent_button(ui, "Create Teams");
+                    focus.add(&create_button);
+                    create = create_button.clicked();
+                    let skip_button = crate::theme::secondary_button(ui, "Skip");
+                    focus.add(&skip_button);
+                    skipped = skip_button.clicked();
+                });
+                skipped |= focus.finish(ui.ctx());
+            });
+        });
+
+    if create {
+        for action in wizard_add_team_actions(&names, naming) {
+            if dispatch(game_engine, action, toasts).is_none() {
+                break;
+            }
+        }
+    }
+    ctx.memory_mut(|m| m.data.insert_temp(wizard_id, (names, skipped)));
+}
+
+fn draw_team_emoji_picker(ui: &mut egui::Ui, team: &mut crate::core::Team) {
+    let current = team.emoji.clone().unwrap_or_else(|| "➕".to_string());
+    ui.menu_button(current, |ui| {
+        ui.set_max_width(160.0);
+        ui.horizontal_wrapped(|ui| {
+            for emoji in crate::core::TEAM_EMOJI_CHOICES {
+                if ui.button(egui::RichText::new(*emoji).size(18.0)).clicked() {
+                    team.set_emoji(Some(emoji));
+                    ui.close_menu();
+                }
+            }
+        });
+        if team.emoji.is_some() && ui.button("No avatar").clicked() {
+            team.set_emoji(None);
+            ui.close_menu();
+        }
+    });
+}
+
+/// Run a host action, surfacing a rejection as an error toast so the click isn't lost silently.
+/// A reason already on screen isn't repeated, so an action retried every frame can't flood the corner.
+fn dispatch(
+    game_engine: &mut GameEngine,
+    action: GameAction,
+    toasts: &mut ToastQueue,
+) -> Option<GameActionResult> {
+    match game_engine.handle_action(action) {
+        Ok(result) => Some(result),
+        Err(err) => {
+            toasts.push_unique(ToastKind::Error, err.to_string());
+            None
+        }
+    }
+}
+
+/// Surface effects the answer animation doesn't already show as toasts
+fn push_effect_toasts(toasts: &mut ToastQueue, effects: &[GameEffect]) {
+    for effect in effects {
+        match effect {
+            GameEffect::ScoreReset => toasts.push(ToastKind::Warning, "All scores were reset"),
+            GameEffect::ScoreStealApplied { context } => toasts.push(
+                ToastKind::Warning,
+                format!(
+                    "{} stole {} from {}",
+                    context.thief_name, context.amount, context.victim_name
+                ),
+            ),
+            _ => {}
+        }
+    }
+}
+
+/// Events the host can fire by hand, in menu and number-key order
+pub(crate) const MANUAL_EVENTS: [GameEvent; 4] = [
+    GameEvent::DoublePoints,
+    GameEvent::HardReset,
+    GameEvent::ReverseQuestion,
+    GameEvent::ScoreSteal,
+];
+
+/// Event a number key fires from the board: 1–4 follow the menu order.
+/// Nothing fires while another event is still active or animating.
+fn hotkey_event(key: egui::Key, event_busy: bool) -> Option<GameEvent> {
+    if event_busy {
+        return None;
+    }
+    let index = match key {
+        egui::Key::Num1 => 0,
+        egui::Key::Num2 => 1,
+        egui::Key::Num3 => 2,
+        egui::Key::Num4 => 3,
+        _ => return None,
+    };
+    Some(MANUAL_EVENTS[index].clone())
+}
+
+pub(crate) fn manual_event_label(event: &GameEvent) -> &'static str {
+    match event {
+        GameEvent::DoublePoints => "×2 Double Points",
+        GameEvent::HardReset => "Hard Reset",
+        GameEvent::ReverseQuestion => "Reverse Question",
+        GameEvent::ScoreSteal => "Score Steal",
+    }
+}
+
+/// Host-only menu for firing an event by hand. `E` opens it and 1–4 fire an
+/// event directly, unless a text field has the keyboard.
+fn draw_manual_event_menu(
+    ui: &mut egui::Ui,
+    game_engine: &mut GameEngine,
+    toasts: &mut ToastQueue,
+) {
+    let event_state = &game_engine.get_state().event_state;
+    let busy = event_state.active_event.is_some()
+        || event_state.is_animation_playing()
+        || event_state.has_queued_event();
+    let popup_id = ui.id().with("manual_event_menu");
+    let button = ui
+        .add_enabled(!busy, egui::Button::new("⚡ Events"))
+        .on_hover_text("Fire an event now (E opens this menu, 1–4 fire directly)");
+    let mut chosen: Option<GameEvent> = None;
+    if !ui.ctx().wants_keyboard_input() {
+        ui.input(|i| {
+            if i.key_pressed(egui::Key::E) && !busy {
+                ui.memory_mut(|m| m.toggle_popup(popup_id));
+            }
+            chosen = [
+                egui::Key::Num1,
+                egui::Key::Num2,
+                egui::Key::Num3,
+                egui::Key::Num4,
+            ]
+            .into_iter()
+            .filter(|key| i.key_pressed(*key))
+            .find_map(|key| hotkey_event(key, busy));
+        });
+    }
+    if button.clicked() {
+        ui.memory_mut(|m| m.toggle_popup(popup_id));
+    }
+    egui::popup_below_widget(ui, popup_id, &button, |ui| {
+        ui.set_min_width(160.0);
+        for (n, event) in MANUAL_EVENTS.iter().enumerate() {
+            let label = format!("{}  {}", n + 1, manual_event_label(event));
+            if ui.button(label).clicked() {
+                chosen = Some(event.clone());
+            }
+        }
+    });
+    if let Some(event) = chosen {
+        ui.memory_mut(|m| m.close_popup());
+        if let Some(GameActionResult::StateChanged { effects, .. }) =
+            dispatch(game_engine, GameAction::TriggerEvent { event }, toasts)
+        {
+            push_effect_toasts(toasts, &effects);
+        }
+    }
+}
+
+/// Small bar filling up toward the next event roll, hidden when events are off
+fn draw_event_countdown(ui: &mut egui::Ui, game_engine: &GameEngine) {
+    let config = game_engine.event_config();
+    if config.enabled_events.is_empty() {
+        return;
+    }
+    let interval = config.trigger_interval;
+    let Some(left) = game_engine
+        .get_state()
+        .event_state
+        .clues_until_next_event(interval)
+    else {
+        return;
+    };
+    let progress = (interval - left) as f32 / interval as f32;
+    let text = if left == 1 {
+        "Event after the next clue".to_string()
+    } else {
+        format!("Event in {} clues", left)
+    };
+    ui.add_space(12.0);
+    ui.add(
+        egui::ProgressBar::new(progress)
+            .desired_width(140.0)
+            .fill(Palette::AMBER_GLOW.linear_multiply(0.6))
+            .text(egui::RichText::new(text).size(11.0).color(Palette::TEXT)),
+    );
+}
+
+fn draw_active_event_banner(ui: &mut egui::Ui, event: &GameEvent) {
+    let color = match event {
+        GameEvent::DoublePoints => Palette::AMBER_GLOW,
+        GameEvent::ReverseQuestion => Palette::NEON_BLUE,
+        GameEvent::HardReset => Palette::MAGENTA,
+        GameEvent::ScoreSteal => Palette::CYBER_YELLOW,
+    };
+    egui::Frame::none()
+        .fill(color.linear_multiply(0.15))
+        .stroke(egui::Stroke::new(1.5, color))
+        .rounding(egui::Rounding::same(6.0))
+        .inner_margin(egui::Margin::symmetric(10.0, 4.0))
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(event.active_banner_label())
+                    .strong()
+                    .color(color),
+            );
+        });
+}
+
+/// Memory id of the steal overlay's collapsible answer
+const STEAL_ANSWER_ID: &str = "steal_host_answer";
+
+/// What the steal overlay shows for the team currently trying
+struct StealPrompt {
+    question: String,
+    points_text: String,
+    team_name: String,
     has_more_contenders: bool,
-    flash: &mut Option<(AnswerFlash, Instant)>,
-    pending_steal: &mut Option<(StealOutcome, (usize, usize), u32)>,
+    time_remaining: Option<Duration>,
+    /// Correct answer for adjudicating, when the host has opted in
+    host_answer: Option<String>,
+}
+
+/// Answer (and alternates) the host can peek at while judging a steal.
+/// Only the host overlay asks for this; the spectator view has no equivalent.
+fn steal_host_answer(clue: &Clue, enabled: bool) -> Option<String> {
+    let answer = clue.answer.trim();
+    if !enabled || answer.is_empty() {
+        return None;
+    }
+    Some(match also_accepted_label(&clue.alt_answers) {
+        Some(alts) => format!("{}\n{}", answer, alts),
+        None => answer.to_string(),
+    })
+}
+
+fn draw_steal_overlay(
+    ctx: &egui::Context,
+    prompt: &StealPrompt,
+    interaction_blocked: bool,
 ) -> Option<StealOutcome> {
     let mut outcome = None;
     let screen = ctx.screen_rect();
@@ -799,22 +2114,43 @@ fn draw_steal_overlay(
                 egui::Layout::top_down(egui::Align::Center),
                 |ui| {
                     ui.add_space(24.0);
-                    ui.heading(
-                        egui::RichText::new(format!("{} pts", points)).color(Palette::AMBER_GLOW),
-                    );
+                    ui.heading(egui::RichText::new(&prompt.points_text).color(Palette::AMBER_GLOW));
                     ui.add_space(12.0);
                     let wrap_width = rect.width() * 0.9;
-                    let label = egui::Label::new(egui::RichText::new(question).size(26.0))
+                    let label = egui::Label::new(egui::RichText::new(&prompt.question).size(26.0))
                         .wrap(true)
                         .truncate(false);
                     ui.add_sized([wrap_width, 0.0], label);
                     ui.add_space(8.0);
-                    let steal_info = if has_more_contenders {
-                        format!("Steal Attempt: {}", team_name)
+                    let steal_info = if prompt.has_more_contenders {
+                        format!("Steal Attempt: {}", prompt.team_name)
                     } else {
-                        format!("Final Attempt: {}", team_name)
+                        format!("Final Attempt: {}", prompt.team_name)
                     };
                     ui.label(egui::RichText::new(steal_info).size(20.0));
+                    if let Some(answer) = &prompt.host_answer {
+                        // Collapsed by default so a glance at the host screen doesn't give it away
+                        egui::collapsing_header::CollapsingState::load_with_default_open(
+                            ui.ctx(),
+                            egui::Id::new(STEAL_ANSWER_ID),
+                            false,
+                        )
+                        .show_header(ui, |ui| ui.label("Answer (host only)"))
+                        .body(|ui| {
+                            ui.label(
+                                egui::RichText::new(answer)
+                                    .size(20.0)
+                                    .color(Palette::CYBER_YELLOW),
+                            );
+                        });
+                    }
+                    if let Some(remaining) = prompt.time_remaining {
+                        ui.label(
+                            egui::RichText::new(format!("⏱ {}s", remaining.as_secs_f32().ceil()))
+                                .size(20.0)
+                                .color(Palette::AMBER_GLOW),
+                        );
+                    }
                 },
             );
             let bottom_h = 90.0;
@@ -828,37 +2164,236 @@ fn draw_steal_overlay(
                     |ui| {
                         ui.set_width(bottom_rect.width());
                         ui.horizontal(|ui| {
-                            // Block interactions during flash animation
-                            let interaction_blocked = flash.is_some() || pending_steal.is_some();
+                            if enhanced_modal_button(ui, "Correct", ModalButtonType::Correct)
+                                .clicked()
+                                && !interaction_blocked
+                                && accept_overlay_click(ui.ctx())
+                            {
+                                outcome = Some(StealOutcome::Correct);
+                            }
+
+                            ui.add_space(40.0);
+
+                            if enhanced_modal_button(ui, "Incorrect", ModalButtonType::Incorrect)
+                                .clicked()
+                                && !interaction_blocked
+                                && accept_overlay_click(ui.ctx())
+                            {
+                                outcome = Some(StealOutcome::Incorrect);
+                            }
+                        });
+                    },
+                );
+            });
+        });
+    outcome
+}
+
+/// Longest question peek shown when the host hovers a clue
+const HOVER_PREVIEW_CHARS: usize = 80;
+
+/// Question peek for the host hovering an unplayed clue, when previews are on.
+/// Lives in the host UI only; the spectator view never asks for it.
+fn host_hover_preview(clue: &Clue, enabled: bool) -> Option<String> {
+    let question = clue.question.trim();
+    if !enabled || clue.solved || question.is_empty() {
+        return None;
+    }
+    if question.chars().count() > HOVER_PREVIEW_CHARS {
+        let head: String = question.chars().take(HOVER_PREVIEW_CHARS).collect();
+        Some(format!("{}…", head.trim_end()))
+    } else {
+        Some(question.to_string())
+    }
+}
+
+/// "Also accepted: …" line for the resolved overlay, or None without alternates
+fn also_accepted_label(alt_answers: &[String]) -> Option<String> {
+    (!alt_answers.is_empty()).then(|| format!("Also accepted: {}", alt_answers.join(", ")))
+}
+
+/// Points, question, answer and any alternates or host note for a played clue
+fn draw_revealed_clue(
+    ui: &mut egui::Ui,
+    rect: egui::Rect,
+    state: &GameState,
+    clue: (usize, usize),
+    show_answer: bool,
+) {
+    let (question, answer, points, host_note, alt_answers) = state
+        .get_clue(clue)
+        .map(|c| {
+            (
+                c.question.clone(),
+                c.answer.clone(),
+                c.points,
+                c.host_note.clone(),
+                also_accepted_label(&c.alt_answers),
+            )
+        })
+        .unwrap_or_default();
+
+    ui.allocate_ui_with_layout(
+        rect.size(),
+        egui::Layout::top_down(egui::Align::Center),
+        |ui| {
+            ui.add_space(40.0);
+
+            // Enhanced points display with amber styling
+            ui.heading(
+                egui::RichText::new(format!("{} pts", points))
+                    .color(Palette::AMBER_GLOW)
+                    .size(32.0),
+            );
+
+            ui.add_space(25.0);
+
+            // Enhanced question text with yellow styling
+            let wrap_width = rect.width() * 0.85;
+            let q_label = egui::Label::new(
+                egui::RichText::new(question)
+                    .size(26.0)
+                    .color(Palette::CYBER_YELLOW),
+            )
+            .wrap(true)
+            .truncate(false);
+            ui.add_sized([wrap_width, 0.0], q_label);
+
+            ui.add_space(20.0);
+
+            if show_answer {
+                // Enhanced answer text with amber styling
+                let a_label = egui::Label::new(
+                    egui::RichText::new(format!("Answer: {}", answer))
+                        .color(Palette::AMBER_GLOW)
+                        .size(24.0)
+                        .strong(),
+                )
+                .wrap(true)
+                .truncate(false);
+                ui.add_sized([wrap_width, 0.0], a_label);
+
+                if let Some(alt_answers) = alt_answers {
+                    ui.add_space(8.0);
+                    ui.label(
+                        egui::RichText::new(alt_answers)
+                            .color(Palette::AMBER_GLOW)
+                            .size(20.0),
+                    );
+                }
+            }
+
+            if let Some(host_note) = host_note {
+                ui.add_space(16.0);
+                ui.label(
+                    egui::RichText::new(format!("Note: {}", host_note))
+                        .color(Palette::SUBTLE_TEAL)
+                        .size(20.0),
+                );
+            }
+        },
+    );
+}
+
+/// Read-only look back at a solved clue; true once the host closes it
+fn draw_review_overlay(ctx: &egui::Context, state: &GameState, clue: (usize, usize)) -> bool {
+    let mut closed = ctx.input(|i| i.key_pressed(egui::Key::Escape));
+    let screen = ctx.screen_rect();
+    egui::Area::new("review_full_overlay".into())
+        .order(egui::Order::Foreground)
+        .movable(false)
+        .interactable(true)
+        .fixed_pos(screen.min)
+        .show(ctx, |ui| {
+            let rect = screen;
+            paint_subtle_modal_background(&ui.painter_at(rect), rect);
+            draw_revealed_clue(ui, rect, state, clue, true);
+
+            let bottom_h = 100.0;
+            let bottom_rect = egui::Rect::from_min_size(
+                egui::pos2(rect.left(), rect.bottom() - bottom_h - 20.0),
+                egui::vec2(rect.width(), bottom_h),
+            );
+            ui.allocate_ui_at_rect(bottom_rect, |ui| {
+                ui.with_layout(egui::Layout::left_to_right(egui::Align::Center), |ui| {
+                    ui.set_width(bottom_rect.width());
+                    ui.horizontal_centered(|ui| {
+                        if enhanced_modal_button(ui, "Close", ModalButtonType::Close).clicked() {
+                            closed = true;
+                        }
+                    });
+                });
+            });
+        });
+    closed
+}
+
+/// Whether the resolved screen is still holding the answer back for the host's click
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AnswerReveal {
+    Hidden,
+    Shown,
+}
+
+impl AnswerReveal {
+    fn initial(requires_click: bool) -> Self {
+        if requires_click {
+            AnswerReveal::Hidden
+        } else {
+            AnswerReveal::Shown
+        }
+    }
+
+    fn reveal(self) -> Self {
+        AnswerReveal::Shown
+    }
+
+    fn answer_visible(self) -> bool {
+        self == AnswerReveal::Shown
+    }
 
-                            if enhanced_modal_button(ui, "Correct", ModalButtonType::Correct)
-                                .clicked()
-                                && !interaction_blocked
-                            {
-                                // Start animation first; delay state mutation until animation completes
-                                if flash.is_none() && pending_steal.is_none() {
-                                    *flash = Some((AnswerFlash::Correct, Instant::now()));
-                                    outcome = Some(StealOutcome::Correct);
-                                }
-                            }
+    /// The clue can only be closed once its answer has been seen
+    fn close_enabled(self) -> bool {
+        self.answer_visible()
+    }
+}
 
-                            ui.add_space(40.0);
+/// Memory keys for the answer flash, the answer and steal waiting on it, and the
+/// event animation: all tied to the game on screen
+fn in_flight_ids() -> [egui::Id; 4] {
+    [
+        "answer_flash",
+        "pending_answer",
+        "pending_steal",
+        "event_animation",
+    ]
+    .map(egui::Id::new)
+}
 
-                            if enhanced_modal_button(ui, "Incorrect", ModalButtonType::Incorrect)
-                                .clicked()
-                                && !interaction_blocked
-                            {
-                                if flash.is_none() && pending_steal.is_none() {
-                                    *flash = Some((AnswerFlash::Incorrect, Instant::now()));
-                                    outcome = Some(StealOutcome::Incorrect);
-                                }
-                            }
-                        });
-                    },
-                );
-            });
-        });
-    outcome
+/// Forget the flashes, pending outcomes and animations of the game that was on
+/// screen, e.g. once a saved game replaces it; other UI memory is left alone
+pub fn forget_in_flight_state(ctx: &egui::Context) {
+    let [
+        flash_id,
+        pending_answer_id,
+        pending_steal_id,
+        event_animation_id,
+    ] = in_flight_ids();
+    ctx.memory_mut(|m| {
+        m.data
+            .remove::<Option<(AnswerFlash, FlashStyle, Instant)>>(flash_id);
+        m.data
+            .remove::<Option<(AnswerFlash, (usize, usize), u32)>>(pending_answer_id);
+        m.data
+            .remove::<Option<(StealOutcome, (usize, usize), u32)>>(pending_steal_id);
+        m.data
+            .remove::<Option<EventAnimationController>>(event_animation_id);
+        m.data.remove::<ClueZoom>(egui::Id::new("clue_zoom"));
+    });
+}
+
+fn answer_reveal_id(clue: (usize, usize)) -> egui::Id {
+    egui::Id::new(("answer_reveal", clue))
 }
 
 fn draw_resolved_overlay(
@@ -867,7 +2402,8 @@ fn draw_resolved_overlay(
     clue: (usize, usize),
     next_team_id: u32,
     requested_phase: &mut Option<PlayPhase>,
-    flash: &Option<(AnswerFlash, Instant)>,
+    flash: &Option<(AnswerFlash, FlashStyle, Instant)>,
+    toasts: &mut ToastQueue,
 ) {
     let screen = ctx.screen_rect();
     egui::Area::new("resolved_full_overlay".into())
@@ -882,54 +2418,16 @@ fn draw_resolved_overlay(
             // Subtle modal background for dialogue
             paint_subtle_modal_background(&painter, rect);
 
-            let (question, answer, points) = game_engine
-                .get_state()
-                .board
-                .categories
-                .get(clue.0)
-                .and_then(|cat| cat.clues.get(clue.1))
-                .map(|c| (c.question.clone(), c.answer.clone(), c.points))
-                .unwrap_or((String::new(), String::new(), 0));
-
-            ui.allocate_ui_with_layout(
-                rect.size(),
-                egui::Layout::top_down(egui::Align::Center),
-                |ui| {
-                    ui.add_space(40.0);
-
-                    // Enhanced points display with amber styling
-                    ui.heading(
-                        egui::RichText::new(format!("{} pts", points))
-                            .color(Palette::AMBER_GLOW)
-                            .size(32.0),
-                    );
-
-                    ui.add_space(25.0);
-
-                    // Enhanced question text with yellow styling
-                    let wrap_width = rect.width() * 0.85;
-                    let q_label = egui::Label::new(
-                        egui::RichText::new(question)
-                            .size(26.0)
-                            .color(Palette::CYBER_YELLOW),
-                    )
-                    .wrap(true)
-                    .truncate(false);
-                    ui.add_sized([wrap_width, 0.0], q_label);
-
-                    ui.add_space(20.0);
-
-                    // Enhanced answer text with amber styling
-                    let a_label = egui::Label::new(
-                        egui::RichText::new(format!("Answer: {}", answer))
-                            .color(Palette::AMBER_GLOW)
-                            .size(24.0)
-                            .strong(),
-                    )
-                    .wrap(true)
-                    .truncate(false);
-                    ui.add_sized([wrap_width, 0.0], a_label);
-                },
+            let reveal_id = answer_reveal_id(clue);
+            let reveal: AnswerReveal = ui
+                .memory_mut(|m| m.data.get_temp(reveal_id))
+                .unwrap_or(AnswerReveal::Shown);
+            draw_revealed_clue(
+                ui,
+                rect,
+                game_engine.get_state(),
+                clue,
+                reveal.answer_visible(),
             );
 
             // Enhanced button area
@@ -944,13 +2442,28 @@ fn draw_resolved_overlay(
                     ui.set_width(bottom_rect.width());
                     ui.horizontal_centered(|ui| {
                         // Block interactions during flash animation (in case flash is still playing from previous phase)
-                        let interaction_blocked = flash.is_some();
+                        // and while an event rolled on resolve is still announcing itself
+                        let interaction_blocked = flash.is_some()
+                            || game_engine.get_state().event_state.is_animation_playing()
+                            || game_engine.get_state().event_state.has_queued_event();
 
+                        if !reveal.close_enabled() {
+                            if enhanced_modal_button(ui, "Reveal Answer", ModalButtonType::Correct)
+                                .clicked()
+                                && !interaction_blocked
+                                && accept_overlay_click(ui.ctx())
+                            {
+                                ui.memory_mut(|m| m.data.insert_temp(reveal_id, reveal.reveal()));
+                            }
+                            return;
+                        }
                         if enhanced_modal_button(ui, "Close", ModalButtonType::Close).clicked()
                             && !interaction_blocked
+                            && accept_overlay_click(ui.ctx())
                         {
+                            ui.memory_mut(|m| m.data.remove::<AnswerReveal>(reveal_id));
                             let action = GameAction::CloseClue { clue, next_team_id };
-                            if let Ok(result) = game_engine.handle_action(action) {
+                            if let Some(result) = dispatch(game_engine, action, toasts) {
                                 match result {
                                     GameActionResult::Success { new_phase } => {
                                         *requested_phase = Some(new_phase)
@@ -962,13 +2475,239 @@ fn draw_resolved_overlay(
                             }
                             ui.ctx().request_repaint();
                         }
+                        if game_engine.get_state().audience_claimable == Some(clue) {
+                            ui.add_space(16.0);
+                            if crate::theme::secondary_button(ui, "Audience claims it").clicked()
+                                && !interaction_blocked
+                            {
+                                dispatch(game_engine, GameAction::AudienceClaim { clue }, toasts);
+                            }
+                        }
                     });
                 });
             });
         });
 }
 
-fn draw_success_animation(painter: &egui::Painter, rect: egui::Rect, t: f32) {
+/// What a click on the board does this frame
+#[derive(Debug, Clone, PartialEq)]
+enum SelectingIntent {
+    /// Start the queued event's animation; the clue stays closed
+    PlayEvent(GameEvent),
+    Select((usize, usize)),
+    Ignore,
+}
+
+/// The one place that decides what a board click means while selecting: nothing
+/// happens while an event animation or announcement is up, and a queued event
+/// plays before any clue opens
+fn next_selecting_action(state: &GameState, clicked: Option<(usize, usize)>) -> SelectingIntent {
+    let Some(clue) = clicked else {
+        return SelectingIntent::Ignore;
+    };
+    if !clue_selection_open(state) {
+        return SelectingIntent::Ignore;
+    }
+    match &state.event_state.queued_event {
+        Some(event) => SelectingIntent::PlayEvent(event.clone()),
+        None => SelectingIntent::Select(clue),
+    }
+}
+
+/// Take the queued event and start its animation, unless one is already playing.
+/// If the engine refuses it, the reason goes up as a toast and the event stays queued.
+fn start_queued_event_animation(
+    game_engine: &mut GameEngine,
+    toasts: &mut ToastQueue,
+) -> Option<EventAnimationController> {
+    let event_state = &mut game_engine.get_state_mut().event_state;
+    if event_state.is_animation_playing() {
+        return None;
+    }
+    let queued_event = event_state.take_queued_event()?;
+    let duration = match queued_event {
+        GameEvent::DoublePoints => Duration::from_millis(3000),
+        GameEvent::HardReset => Duration::from_millis(4000),
+        GameEvent::ReverseQuestion => Duration::from_millis(2500),
+        GameEvent::ScoreSteal => Duration::from_millis(3200),
+    };
+    // Marks the animation as playing and puts the event in effect for the next cell
+    let action = GameAction::PlayEventAnimation {
+        event: queued_event.clone(),
+    };
+    if dispatch(game_engine, action, toasts).is_none() {
+        let event_state = &mut game_engine.get_state_mut().event_state;
+        event_state.queue_event(queued_event);
+        return None;
+    }
+    let mut controller = EventAnimationController::new();
+    controller.start_animation(queued_event, duration);
+    Some(controller)
+}
+
+/// Clues can't be picked while an event animation plays or its announcement
+/// is still waiting for the host
+fn clue_selection_open(state: &GameState) -> bool {
+    !state.event_state.is_animation_playing() && state.event_state.pending_announcement.is_none()
+}
+
+/// Explain the event that just played; "Understood" acknowledges it and reopens the board
+fn draw_event_announcement(
+    ctx: &egui::Context,
+    game_engine: &mut GameEngine,
+    toasts: &mut ToastQueue,
+) {
+    let Some(event) = game_engine
+        .get_state()
+        .event_state
+        .pending_announcement
+        .clone()
+    else {
+        return;
+    };
+    egui::Area::new("event_announcement".into())
+        .order(egui::Order::Foreground)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            crate::theme::window_frame().show(ui, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.heading(
+                        egui::RichText::new(event.active_banner_label())
+                            .size(28.0)
+                            .color(Palette::AMBER_GLOW),
+                    );
+                    ui.add_space(10.0);
+                    ui.label(
+                        egui::RichText::new(event.announcement_text())
+                            .size(20.0)
+                            .color(Palette::TEXT),
+                    );
+                    ui.add_space(14.0);
+                    if crate::theme::accent_button(ui, "Understood").clicked() {
+                        dispatch(game_engine, GameAction::AcknowledgeEvent, toasts);
+                    }
+                });
+            });
+        });
+}
+
+fn draw_intermission_overlay(
+    ctx: &egui::Context,
+    game_engine: &mut GameEngine,
+    privacy: ScorePrivacy,
+    requested_phase: &mut Option<PlayPhase>,
+    toasts: &mut ToastQueue,
+) {
+    let screen = ctx.screen_rect();
+    egui::Area::new("intermission_full_overlay".into())
+        .order(egui::Order::Foreground)
+        .movable(false)
+        .interactable(true)
+        .fixed_pos(screen.min)
+        .show(ctx, |ui| {
+            let rect = screen;
+            let painter = ui.painter_at(rect);
+
+            // The overlay covers the board so no clue can be picked while paused
+            paint_subtle_modal_background(&painter, rect);
+
+            let standings = intermission_standings(game_engine.get_state(), privacy);
+
+            ui.allocate_ui_with_layout(
+                rect.size(),
+                egui::Layout::top_down(egui::Align::Center),
+                |ui| {
+                    ui.add_space(40.0);
+                    ui.heading(
+                        egui::RichText::new("Intermission")
+                            .color(Palette::CYAN)
+                            .size(36.0),
+                    );
+                    ui.add_space(25.0);
+
+                    for (line, color) in &standings {
+                        ui.label(egui::RichText::new(line).color(*color).size(28.0).strong());
+                        ui.add_space(8.0);
+                    }
+
+                    ui.add_space(30.0);
+                    let state = game_engine.get_state();
+                    let round = state.upcoming_round();
+                    let mut action = None;
+                    if !state.get_available_clues().is_empty()
+                        && crate::theme::accent_button(ui, "Continue").clicked()
+                    {
+                        action = Some(GameAction::Resume);
+                    }
+                    if state.next_unlock_round().is_some()
+                        && crate::theme::secondary_button(ui, format!("Start Round {}", round))
+                            .clicked()
+                    {
+                        action = Some(GameAction::NextRound);
+                    }
+                    if let Some(action) = action {
+                        if let Some(result) = dispatch(game_engine, action, toasts) {
+                            match result {
+                                GameActionResult::Success { new_phase } => {
+                                    *requested_phase = Some(new_phase)
+                                }
+                                GameActionResult::StateChanged { new_phase, .. } => {
+                                    *requested_phase = Some(new_phase)
+                                }
+                            }
+                        }
+                        ui.ctx().request_repaint();
+                    }
+                },
+            );
+        });
+}
+
+/// Score lines for the intermission overlay, colored by team. Scores follow the
+/// privacy mode; unless every score is open the teams keep join order and go
+/// unranked, since the order alone would give the standings away.
+fn intermission_standings(
+    state: &GameState,
+    privacy: ScorePrivacy,
+) -> Vec<(String, egui::Color32)> {
+    let ranked = privacy == ScorePrivacy::Open;
+    let teams = if ranked {
+        state.standings()
+    } else {
+        state.teams.iter().collect()
+    };
+    teams
+        .into_iter()
+        .enumerate()
+        .map(|(place, team)| {
+            // Colors follow join order so a team keeps its color as standings shift
+            let index = state
+                .teams
+                .iter()
+                .position(|t| t.id == team.id)
+                .unwrap_or(0);
+            let score = privacy.displayed_score(team.score, team.id == state.active_team, false);
+            let line = if ranked {
+                format!("{}. {}  —  {}", place + 1, team.label(), score)
+            } else {
+                format!("{}  —  {}", team.label(), score)
+            };
+            (line, Palette::team_color(index))
+        })
+        .collect()
+}
+
+/// Quiet stand-in for the burst: a full-screen tint that fades out
+fn draw_fade_flash(painter: &egui::Painter, rect: egui::Rect, t: f32, kind: AnswerFlash) {
+    let alpha = ((1.0 - t) * 70.0) as u8;
+    let color = match kind {
+        AnswerFlash::Correct => egui::Color32::from_rgba_unmultiplied(0, 255, 170, alpha),
+        AnswerFlash::Incorrect => egui::Color32::from_rgba_unmultiplied(255, 40, 80, alpha),
+    };
+    painter.rect_filled(rect, 0.0, color);
+}
+
+fn draw_success_animation(painter: &egui::Painter, rect: egui::Rect, t: f32, params: &BurstParams) {
     let center = rect.center();
 
     // Easing function for smooth animation curves
@@ -990,15 +2729,15 @@ fn draw_success_animation(painter: &egui::Painter, rect: egui::Rect, t: f32) {
     let ease_out = 1.0 - (1.0 - t).powf(3.0);
 
     // Base green overlay with smooth fade
-    let alpha = ((1.0 - ease_out) * 180.0) as u8;
+    let alpha = params.alpha((1.0 - ease_out) * 180.0);
     let base_color = egui::Color32::from_rgba_unmultiplied(0, 255, 170, alpha);
     painter.rect_filled(rect, 0.0, base_color);
 
     // Multiple expanding rings with different speeds and colors
-    for i in 0..4 {
+    for i in 0..params.rings {
         let ring_t = (t * 1.5 - i as f32 * 0.15).clamp(0.0, 1.0);
         if ring_t > 0.0 {
-            let ring_alpha = ((1.0 - ring_t) * 120.0) as u8;
+            let ring_alpha = params.alpha((1.0 - ring_t) * 120.0);
             let ring_radius =
                 ease_out_bounce(ring_t) * (rect.width().min(rect.height()) * 0.7) + i as f32 * 20.0;
             let ring_color = match i {
@@ -1010,13 +2749,13 @@ fn draw_success_animation(painter: &egui::Painter, rect: egui::Rect, t: f32) {
             painter.circle_stroke(
                 center,
                 ring_radius,
-                egui::Stroke::new(8.0 - i as f32 * 1.5, ring_color),
+                egui::Stroke::new((8.0 - i as f32 * 1.5).max(1.5), ring_color),
             );
         }
     }
 
     // Radiating success lines/burst effect
-    let line_count = 12;
+    let line_count = params.lines;
     for i in 0..line_count {
         let angle = (i as f32 / line_count as f32) * 2.0 * std::f32::consts::PI;
         let line_t = (t * 2.0 - 0.3).clamp(0.0, 1.0);
@@ -1028,21 +2767,21 @@ fn draw_success_animation(painter: &egui::Painter, rect: egui::Rect, t: f32) {
             let start = center + egui::Vec2::angled(angle) * start_radius;
             let end = center + egui::Vec2::angled(angle) * end_radius;
 
-            let line_alpha = ((1.0 - line_t) * 200.0) as u8;
+            let line_alpha = params.alpha((1.0 - line_t) * 200.0);
             let line_color = egui::Color32::from_rgba_unmultiplied(0, 255, 170, line_alpha);
             painter.line_segment([start, end], egui::Stroke::new(4.0, line_color));
         }
     }
 
     // Sparkling particles
-    for i in 0..8 {
+    for i in 0..params.particles {
         let particle_t = (t * 1.8 - i as f32 * 0.1).clamp(0.0, 1.0);
         if particle_t > 0.0 {
-            let angle = (i as f32 / 8.0) * 2.0 * std::f32::consts::PI + t * 0.5;
+            let angle = (i as f32 / params.particles as f32) * 2.0 * std::f32::consts::PI + t * 0.5;
             let radius = ease_out * (rect.width().min(rect.height()) * 0.3);
             let pos = center + egui::Vec2::angled(angle) * radius;
 
-            let particle_alpha = ((1.0 - particle_t) * 255.0) as u8;
+            let particle_alpha = params.alpha((1.0 - particle_t) * 255.0);
             let particle_size = (1.0 - particle_t) * 8.0 + 2.0;
             let particle_color =
                 egui::Color32::from_rgba_unmultiplied(255, 255, 255, particle_alpha);
@@ -1051,18 +2790,18 @@ fn draw_success_animation(painter: &egui::Painter, rect: egui::Rect, t: f32) {
     }
 
     // Sound wave ripples for audio feedback visualization
-    for i in 0..3 {
+    for i in 0..params.waves {
         let wave_t = (t * 2.5 - i as f32 * 0.3).clamp(0.0, 1.0);
         if wave_t > 0.0 {
             let wave_radius = wave_t * rect.width().min(rect.height()) * 0.6;
-            let wave_alpha = ((1.0 - wave_t) * 80.0) as u8;
+            let wave_alpha = params.alpha((1.0 - wave_t) * 80.0);
             let wave_color = egui::Color32::from_rgba_unmultiplied(0, 255, 170, wave_alpha);
             painter.circle_stroke(center, wave_radius, egui::Stroke::new(2.0, wave_color));
         }
     }
 }
 
-fn draw_failure_animation(painter: &egui::Painter, rect: egui::Rect, t: f32) {
+fn draw_failure_animation(painter: &egui::Painter, rect: egui::Rect, t: f32, params: &BurstParams) {
     // Reuse success animation structure but swap to red palette
     let center = rect.center();
     let ease_out_bounce = |t: f32| -> f32 {
@@ -1080,13 +2819,13 @@ fn draw_failure_animation(painter: &egui::Painter, rect: egui::Rect, t: f32) {
         }
     };
     let ease_out = 1.0 - (1.0 - t).powf(3.0);
-    let alpha = ((1.0 - ease_out) * 180.0) as u8;
+    let alpha = params.alpha((1.0 - ease_out) * 180.0);
     let base_color = egui::Color32::from_rgba_unmultiplied(255, 40, 80, alpha);
     painter.rect_filled(rect, 0.0, base_color);
-    for i in 0..4 {
+    for i in 0..params.rings {
         let ring_t = (t * 1.5 - i as f32 * 0.15).clamp(0.0, 1.0);
         if ring_t > 0.0 {
-            let ring_alpha = ((1.0 - ring_t) * 120.0) as u8;
+            let ring_alpha = params.alpha((1.0 - ring_t) * 120.0);
             let ring_radius =
                 ease_out_bounce(ring_t) * (rect.width().min(rect.height()) * 0.7) + i as f32 * 20.0;
             let ring_color = match i {
@@ -1098,11 +2837,11 @@ fn draw_failure_animation(painter: &egui::Painter, rect: egui::Rect, t: f32) {
             painter.circle_stroke(
                 center,
                 ring_radius,
-                egui::Stroke::new(8.0 - i as f32 * 1.5, ring_color),
+                egui::Stroke::new((8.0 - i as f32 * 1.5).max(1.5), ring_color),
             );
         }
     }
-    let line_count = 12;
+    let line_count = params.lines;
     for i in 0..line_count {
         let angle = (i as f32 / line_count as f32) * 2.0 * std::f32::consts::PI;
         let line_t = (t * 2.0 - 0.3).clamp(0.0, 1.0);
@@ -1112,29 +2851,29 @@ fn draw_failure_animation(painter: &egui::Painter, rect: egui::Rect, t: f32) {
             let end_radius = length;
             let start = center + egui::Vec2::angled(angle) * start_radius;
             let end = center + egui::Vec2::angled(angle) * end_radius;
-            let line_alpha = ((1.0 - line_t) * 200.0) as u8;
+            let line_alpha = params.alpha((1.0 - line_t) * 200.0);
             let line_color = egui::Color32::from_rgba_unmultiplied(255, 40, 80, line_alpha);
             painter.line_segment([start, end], egui::Stroke::new(4.0, line_color));
         }
     }
-    for i in 0..8 {
+    for i in 0..params.particles {
         let particle_t = (t * 1.8 - i as f32 * 0.1).clamp(0.0, 1.0);
         if particle_t > 0.0 {
-            let angle = (i as f32 / 8.0) * 2.0 * std::f32::consts::PI + t * 0.5;
+            let angle = (i as f32 / params.particles as f32) * 2.0 * std::f32::consts::PI + t * 0.5;
             let radius = ease_out * (rect.width().min(rect.height()) * 0.3);
             let pos = center + egui::Vec2::angled(angle) * radius;
-            let particle_alpha = ((1.0 - particle_t) * 255.0) as u8;
+            let particle_alpha = params.alpha((1.0 - particle_t) * 255.0);
             let particle_size = (1.0 - particle_t) * 8.0 + 2.0;
             let particle_color =
                 egui::Color32::from_rgba_unmultiplied(255, 255, 255, particle_alpha);
             painter.circle_filled(pos, particle_size, particle_color);
         }
     }
-    for i in 0..3 {
+    for i in 0..params.waves {
         let wave_t = (t * 2.5 - i as f32 * 0.3).clamp(0.0, 1.0);
         if wave_t > 0.0 {
             let wave_radius = wave_t * rect.width().min(rect.height()) * 0.6;
-            let wave_alpha = ((1.0 - wave_t) * 80.0) as u8;
+            let wave_alpha = params.alpha((1.0 - wave_t) * 80.0);
             let wave_color = egui::Color32::from_rgba_unmultiplied(255, 40, 80, wave_alpha);
             painter.circle_stroke(center, wave_radius, egui::Stroke::new(2.0, wave_color));
         }
@@ -1524,3 +3263,547 @@ fn draw_reverse_question_animation(painter: &egui::Painter, rect: egui::Rect, t:
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wizard_creates_named_teams_in_order() {
+        let names = vec![" Crabs ".to_string(), String::new(), "Octopi".to_string()];
+        let actions = wizard_add_team_actions(&names, &TeamNaming::default());
+        let added: Vec<String> = actions
+            .iter()
+            .map(|action| match action {
+                GameAction::AddTeam { name } => name.clone(),
+                other => panic!("unexpected {:?}", other),
+            })
+            .collect();
+        assert_eq!(added, vec!["Crabs", "Team 2", "Octopi"]);
+
+        // Played into an engine they make the lobby roster
+        let mut engine = GameEngine::new(crate::core::Board::default_with_dimensions(2, 2));
+        let mut toasts = ToastQueue::default();
+        for action in actions {
+            assert!(dispatch(&mut engine, action, &mut toasts).is_some());
+        }
+        let roster: Vec<&str> = engine
+            .get_state()
+            .teams
+            .iter()
+            .map(|t| t.name.as_str())
+            .collect();
+        assert_eq!(roster, vec!["Crabs", "Team 2", "Octopi"]);
+        assert!(matches!(engine.get_phase(), PlayPhase::Lobby));
+    }
+
+    #[test]
+    fn test_rejected_action_shows_reason_as_toast() {
+        let mut engine = GameEngine::new(crate::core::Board::default_with_dimensions(2, 2));
+        let mut toasts = ToastQueue::default();
+
+        // Answering from the lobby is rejected by the engine
+        let action = GameAction::AnswerCorrect {
+            clue: (0, 0),
+            team_id: 1,
+        };
+        let reason = engine
+            .handle_action(action.clone())
+            .unwrap_err()
+            .to_string();
+        assert!(dispatch(&mut engine, action, &mut toasts).is_none());
+        let shown: Vec<(&str, ToastKind)> =
+            toasts.iter().map(|t| (t.text.as_str(), t.kind)).collect();
+        assert_eq!(shown, vec![(reason.as_str(), ToastKind::Error)]);
+        assert!(!reason.is_empty());
+
+        // Accepted actions stay quiet
+        let action = GameAction::AddTeam {
+            name: "Team 1".to_string(),
+        };
+        assert!(dispatch(&mut engine, action, &mut toasts).is_some());
+        assert_eq!(toasts.iter().count(), 1);
+    }
+
+    #[test]
+    fn test_clue_selection_waits_for_event_acknowledgement() {
+        let mut engine = GameEngine::new(crate::core::Board::default_with_dimensions(2, 2));
+        let _ = engine.handle_action(GameAction::AddTeam {
+            name: "Team 1".to_string(),
+        });
+        let _ = engine.handle_action(GameAction::StartGame);
+        assert!(clue_selection_open(engine.get_state()));
+
+        engine.get_state_mut().event_state.pending_announcement = Some(GameEvent::ReverseQuestion);
+        assert!(!clue_selection_open(engine.get_state()));
+
+        let _ = engine.handle_action(GameAction::AcknowledgeEvent);
+        assert!(clue_selection_open(engine.get_state()));
+    }
+
+    #[test]
+    fn test_no_flash_clue_starts_subtle_fade() {
+        let mut board = crate::core::Board::default_with_dimensions(1, 2);
+        board.categories[0].clues[1].no_flash = true;
+        let loud = &board.categories[0].clues[0];
+        let quiet = &board.categories[0].clues[1];
+        assert_eq!(flash_style(loud.no_flash), FlashStyle::Burst);
+        assert_eq!(flash_style(quiet.no_flash), FlashStyle::Fade);
+
+        let mut flash = None;
+        let mut pending = None;
+        start_answer(
+            &mut flash,
+            &mut pending,
+            AnswerFlash::Correct,
+            flash_style(quiet.no_flash),
+            (0, 1),
+            7,
+        );
+        assert!(matches!(
+            flash,
+            Some((AnswerFlash::Correct, FlashStyle::Fade, _))
+        ));
+        assert!(pending.is_some());
+    }
+
+    #[test]
+    fn test_hover_preview_is_host_only() {
+        let mut engine = GameEngine::new(Board::default_with_dimensions(1, 2));
+        engine.get_state_mut().board.categories[0].clues[0].question =
+            "Secret question text".to_string();
+        let _ = engine.handle_action(GameAction::AddTeam {
+            name: "Team 1".to_string(),
+        });
+        let _ = engine.handle_action(GameAction::StartGame);
+        let clue = &engine.get_state().board.categories[0].clues[0];
+
+        assert_eq!(
+            host_hover_preview(clue, true).as_deref(),
+            Some("Secret question text")
+        );
+        assert_eq!(host_hover_preview(clue, false), None);
+
+        // The audience board never shows the question, even with the pointer over it
+        let ctx = egui::Context::default();
+        let input = egui::RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(
+                egui::Pos2::ZERO,
+                egui::vec2(800.0, 600.0),
+            )),
+            events: vec![egui::Event::PointerMoved(egui::pos2(400.0, 300.0))],
+            ..Default::default()
+        };
+        for _ in 0..3 {
+            let output = ctx.run(input.clone(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    crate::app::spectator_ui::render_spectator(ui, &engine, ScorePrivacy::Open)
+                });
+            });
+            let leaked = output.shapes.iter().any(|clipped| match &clipped.shape {
+                egui::Shape::Text(text) => text.galley.text().contains("Secret"),
+                _ => false,
+            });
+            assert!(!leaked);
+        }
+    }
+
+    #[test]
+    fn test_steal_answer_is_host_only() {
+        let mut engine = GameEngine::new(Board::default_with_dimensions(1, 2));
+        engine.get_state_mut().board.categories[0].clues[0].answer =
+            "Secret answer text".to_string();
+        for name in ["Team 1", "Team 2"] {
+            let _ = engine.handle_action(GameAction::AddTeam {
+                name: name.to_string(),
+            });
+        }
+        let _ = engine.handle_action(GameAction::StartGame);
+        let team_id = engine.get_state().active_team;
+        let clue = (0, 0);
+        let _ = engine.handle_action(GameAction::SelectClue { clue, team_id });
+        let _ = engine.handle_action(GameAction::AnswerIncorrect { clue, team_id });
+        assert!(matches!(engine.get_phase(), PlayPhase::Steal { .. }));
+
+        let board_clue = &engine.get_state().board.categories[0].clues[0];
+        assert_eq!(steal_host_answer(board_clue, false), None);
+        let host_answer = steal_host_answer(board_clue, true);
+        assert_eq!(host_answer.as_deref(), Some("Secret answer text"));
+
+        let input = egui::RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(
+                egui::Pos2::ZERO,
+                egui::vec2(800.0, 600.0),
+            )),
+            ..Default::default()
+        };
+        let shows_secret = |output: &egui::FullOutput| {
+            output.shapes.iter().any(|clipped| match &clipped.shape {
+                egui::Shape::Text(text) => text.galley.text().contains("Secret"),
+                _ => false,
+            })
+        };
+
+        // Host overlay, with the answer section expanded
+        let ctx = egui::Context::default();
+        let mut state = egui::collapsing_header::CollapsingState::load_with_default_open(
+            &ctx,
+            egui::Id::new(STEAL_ANSWER_ID),
+            false,
+        );
+        state.set_open(true);
+        state.store(&ctx);
+        let prompt = StealPrompt {
+            question: "Question".to_string(),
+            points_text: "100".to_string(),
+            team_name: "Team 2".to_string(),
+            has_more_contenders: false,
+            time_remaining: None,
+            host_answer,
+        };
+        // A new area is invisible on its first frame while egui sizes it
+        let shown: Vec<bool> = (0..2)
+            .map(|_| {
+                let output = ctx.run(input.clone(), |ctx| {
+                    draw_steal_overlay(ctx, &prompt, false);
+                });
+                shows_secret(&output)
+            })
+            .collect();
+        assert!(shown.contains(&true));
+
+        // The audience sees the question during the steal, never the answer
+        let ctx = egui::Context::default();
+        for _ in 0..2 {
+            let output = ctx.run(input.clone(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    crate::app::spectator_ui::render_spectator(ui, &engine, ScorePrivacy::Open)
+                });
+            });
+            assert!(!shows_secret(&output));
+        }
+    }
+
+    #[test]
+    fn test_hover_preview_truncates_long_questions() {
+        let mut clue = Board::default_with_dimensions(1, 1).categories[0].clues[0].clone();
+        clue.question = "x".repeat(HOVER_PREVIEW_CHARS + 20);
+        let preview = host_hover_preview(&clue, true).unwrap();
+        assert_eq!(preview.chars().count(), HOVER_PREVIEW_CHARS + 1);
+        assert!(preview.ends_with('…'));
+
+        clue.solved = true;
+        assert_eq!(host_hover_preview(&clue, true), None);
+    }
+
+    #[test]
+    fn test_pending_answer_fires_when_configured_flash_ends() {
+        let mut settings = AppSettings::default();
+        assert_eq!(settings.flash_duration(), Duration::from_millis(1200));
+        settings.flash_duration_ms = Some(300);
+
+        let start = Instant::now();
+        let at = |ms: u64| start + Duration::from_millis(ms);
+        let duration = settings.flash_duration();
+        assert_eq!(flash_progress(start, at(0), duration), Some(0.0));
+        assert!(flash_progress(start, at(299), duration).is_some());
+        // Done at 300ms, so the pending action is applied then rather than at 1200ms
+        assert_eq!(flash_progress(start, at(300), duration), None);
+        assert!(flash_progress(start, at(300), Duration::from_millis(1200)).is_some());
+    }
+
+    #[test]
+    fn test_solved_cell_click_opens_review_only_when_enabled() {
+        assert_eq!(cell_click(true, true, true), CellClick::Review);
+        assert_eq!(cell_click(true, true, false), CellClick::Ignore);
+        // Unsolved cells select as before, whatever the review setting
+        assert_eq!(cell_click(false, true, true), CellClick::Select);
+        assert_eq!(cell_click(false, true, false), CellClick::Select);
+        assert_eq!(cell_click(false, false, true), CellClick::Ignore);
+    }
+
+    #[test]
+    fn test_also_accepted_label_lists_alternates() {
+        assert_eq!(also_accepted_label(&[]), None);
+        let alts = vec!["NYC".to_string(), "Big Apple".to_string()];
+        assert_eq!(
+            also_accepted_label(&alts).as_deref(),
+            Some("Also accepted: NYC, Big Apple")
+        );
+    }
+
+    #[test]
+    fn test_team_panel_layout_follows_position() {
+        use egui::panel::{Side, TopBottomSide};
+        assert_eq!(
+            team_panel_layout(PanelPosition::default()),
+            TeamPanelLayout::Side(Side::Left)
+        );
+        assert_eq!(
+            team_panel_layout(PanelPosition::Right),
+            TeamPanelLayout::Side(Side::Right)
+        );
+        assert_eq!(
+            team_panel_layout(PanelPosition::Top),
+            TeamPanelLayout::Strip(TopBottomSide::Top)
+        );
+        assert_eq!(
+            team_panel_layout(PanelPosition::Bottom),
+            TeamPanelLayout::Strip(TopBottomSide::Bottom)
+        );
+    }
+
+    #[test]
+    fn test_debounce_ignores_second_click_within_window() {
+        let first = Instant::now();
+        assert!(debounce_allows(None, first, OVERLAY_CLICK_DEBOUNCE));
+        assert!(!debounce_allows(
+            Some(first),
+            first + Duration::from_millis(40),
+            OVERLAY_CLICK_DEBOUNCE
+        ));
+        assert!(debounce_allows(
+            Some(first),
+            first + Duration::from_millis(150),
+            OVERLAY_CLICK_DEBOUNCE
+        ));
+        assert!(debounce_allows(
+            Some(first),
+            first + Duration::from_millis(400),
+            OVERLAY_CLICK_DEBOUNCE
+        ));
+    }
+
+    #[test]
+    fn test_parse_team_lines_skips_blanks_and_trims() {
+        let names = parse_team_lines("  Red \n\n\tBlue\n   \nGreen\n", &[], false);
+        assert_eq!(names, vec!["Red", "Blue", "Green"]);
+    }
+
+    #[test]
+    fn test_parse_team_lines_duplicates_follow_uniqueness_setting() {
+        let existing = vec!["Red".to_string()];
+        let text = "red\nBlue\n Blue \nGreen";
+
+        assert_eq!(
+            parse_team_lines(text, &existing, true),
+            vec!["Blue", "Green"]
+        );
+        assert_eq!(
+            parse_team_lines(text, &existing, false),
+            vec!["red", "Blue", "Blue", "Green"]
+        );
+    }
+
+    #[test]
+    fn test_hotkey_event_follows_menu_order() {
+        assert!(matches!(
+            hotkey_event(egui::Key::Num1, false),
+            Some(GameEvent::DoublePoints)
+        ));
+        assert!(matches!(
+            hotkey_event(egui::Key::Num2, false),
+            Some(GameEvent::HardReset)
+        ));
+        assert!(matches!(
+            hotkey_event(egui::Key::Num3, false),
+            Some(GameEvent::ReverseQuestion)
+        ));
+        assert!(matches!(
+            hotkey_event(egui::Key::Num4, false),
+            Some(GameEvent::ScoreSteal)
+        ));
+        assert!(hotkey_event(egui::Key::Num5, false).is_none());
+        assert!(hotkey_event(egui::Key::E, false).is_none());
+        // Nothing fires on top of an event that is still in effect
+        assert!(hotkey_event(egui::Key::Num1, true).is_none());
+    }
+
+    #[test]
+    fn test_team_removal_confirms_only_with_teams() {
+        assert!(!team_removal_needs_confirm(0));
+        assert!(team_removal_needs_confirm(1));
+        assert!(team_removal_needs_confirm(6));
+
+        // An empty roster never leaves a dialog waiting
+        let ctx = egui::Context::default();
+        let id = egui::Id::new(TEAM_REMOVAL_ID);
+        request_team_removal(&ctx, TeamRemoval::ClearAll, 0);
+        assert_eq!(ctx.memory_mut(|m| m.data.get_temp::<TeamRemoval>(id)), None);
+        request_team_removal(&ctx, TeamRemoval::ClearAll, 3);
+        assert_eq!(
+            ctx.memory_mut(|m| m.data.get_temp::<TeamRemoval>(id)),
+            Some(TeamRemoval::ClearAll)
+        );
+    }
+
+    #[test]
+    fn test_selecting_intent_covers_queued_and_active_events() {
+        let mut state = GameState::new(crate::core::Board::default_with_dimensions(2, 3));
+        let clue = Some((1, 2));
+
+        // Nothing clicked, nothing to do
+        assert_eq!(next_selecting_action(&state, None), SelectingIntent::Ignore);
+        // A quiet board opens the clue
+        assert_eq!(
+            next_selecting_action(&state, clue),
+            SelectingIntent::Select((1, 2))
+        );
+
+        // A queued event plays before any clue opens
+        state.event_state.queue_event(GameEvent::DoublePoints);
+        assert_eq!(
+            next_selecting_action(&state, clue),
+            SelectingIntent::PlayEvent(GameEvent::DoublePoints)
+        );
+
+        // Once its animation is running, clicks are ignored, queued or not
+        state.event_state.set_animation_playing(true);
+        assert_eq!(next_selecting_action(&state, clue), SelectingIntent::Ignore);
+        let _ = state.event_state.take_queued_event();
+        state.event_state.activate_event(GameEvent::DoublePoints);
+        assert_eq!(next_selecting_action(&state, clue), SelectingIntent::Ignore);
+
+        // Waiting on the announcement also holds the board
+        state.event_state.set_animation_playing(false);
+        state.event_state.pending_announcement = Some(GameEvent::DoublePoints);
+        assert_eq!(next_selecting_action(&state, clue), SelectingIntent::Ignore);
+
+        // With the event in effect and nothing on screen, the click selects
+        state.event_state.pending_announcement = None;
+        assert_eq!(
+            next_selecting_action(&state, clue),
+            SelectingIntent::Select((1, 2))
+        );
+    }
+
+    #[test]
+    fn test_queued_event_animation_starts_once() {
+        let mut engine = GameEngine::new(crate::core::Board::default_with_dimensions(2, 2));
+        engine
+            .get_state_mut()
+            .event_state
+            .queue_event(GameEvent::ReverseQuestion);
+        let mut toasts = ToastQueue::default();
+
+        assert!(start_queued_event_animation(&mut engine, &mut toasts).is_some());
+        let event_state = &engine.get_state().event_state;
+        assert!(event_state.is_animation_playing());
+        assert!(!event_state.has_queued_event());
+        assert_eq!(event_state.active_event, Some(GameEvent::ReverseQuestion));
+
+        // A second kickoff the same frame finds nothing left to start
+        assert!(start_queued_event_animation(&mut engine, &mut toasts).is_none());
+        assert_eq!(toasts.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_clue_cell_accessible_label() {
+        assert_eq!(
+            clue_cell_accessible_label("Science", 200, ClueCellState::Open, true),
+            "Science, 200 points, available"
+        );
+        assert_eq!(
+            clue_cell_accessible_label("Science", 200, ClueCellState::Solved, true),
+            "Science, 200 points, solved"
+        );
+        assert_eq!(
+            clue_cell_accessible_label("History", 400, ClueCellState::RevealedUnsolved, true),
+            "History, 400 points, shown, unanswered"
+        );
+        // A mystery board doesn't give the value away
+        assert_eq!(
+            clue_cell_accessible_label("History", 400, ClueCellState::Open, false),
+            "History, mystery value, available"
+        );
+    }
+
+    #[test]
+    fn test_intro_card_uses_category_intro_and_skips_without_one() {
+        let mut category = Board::default_with_dimensions(1, 1).categories[0].clone();
+        assert_eq!(intro_card_text(&category), None);
+
+        category.intro = Some("  This category is all about 90s cartoons ".to_string());
+        assert_eq!(
+            intro_card_text(&category),
+            Some("This category is all about 90s cartoons")
+        );
+
+        // A blank intro left behind by the editor doesn't get a card either
+        category.intro = Some("   ".to_string());
+        assert_eq!(intro_card_text(&category), None);
+    }
+
+    #[test]
+    fn test_answer_reveal_waits_for_the_host_when_required() {
+        let reveal = AnswerReveal::initial(true);
+        assert!(!reveal.answer_visible());
+        assert!(!reveal.close_enabled());
+
+        let reveal = reveal.reveal();
+        assert!(reveal.answer_visible());
+        assert!(reveal.close_enabled());
+        // Revealing again changes nothing
+        assert_eq!(reveal.reveal(), AnswerReveal::Shown);
+
+        // Off: the answer shows straight away and Close is ready
+        let auto = AnswerReveal::initial(false);
+        assert!(auto.answer_visible() && auto.close_enabled());
+    }
+
+    #[test]
+    fn test_intermission_standings_respect_score_privacy() {
+        let mut engine = GameEngine::new(crate::core::Board::default_with_dimensions(2, 2));
+        for name in ["Crabs", "Octopi"] {
+            let _ = engine.handle_action(GameAction::AddTeam {
+                name: name.to_string(),
+            });
+        }
+        let state = engine.get_state_mut();
+        state.teams[1].score = 500;
+        state.active_team = state.teams[0].id;
+        let lines = |privacy| -> Vec<String> {
+            intermission_standings(engine.get_state(), privacy)
+                .into_iter()
+                .map(|(line, _)| line)
+                .collect()
+        };
+
+        assert_eq!(
+            lines(ScorePrivacy::Open),
+            vec!["1. Octopi  —  500", "2. Crabs  —  0"]
+        );
+        // With any score hidden the teams come unranked, in join order
+        assert_eq!(
+            lines(ScorePrivacy::ActiveTeamOnly),
+            vec!["Crabs  —  0", "Octopi  —  —"]
+        );
+        assert_eq!(
+            lines(ScorePrivacy::Hidden),
+            vec!["Crabs  —  —", "Octopi  —  —"]
+        );
+    }
+
+    #[test]
+    fn test_loading_a_game_forgets_only_in_flight_state() {
+        let ctx = egui::Context::default();
+        let [flash_id, ..] = in_flight_ids();
+        let editor_id = egui::Id::new("bulk_team_entry");
+        ctx.memory_mut(|m| {
+            let flash = Some((AnswerFlash::Correct, FlashStyle::Burst, Instant::now()));
+            m.data.insert_temp(flash_id, flash);
+            m.data.insert_temp(editor_id, "Crabs".to_string());
+        });
+
+        forget_in_flight_state(&ctx);
+
+        ctx.memory_mut(|m| {
+            let flash = m
+                .data
+                .get_temp::<Option<(AnswerFlash, FlashStyle, Instant)>>(flash_id);
+            assert!(flash.is_none());
+            let text = m.data.get_temp::<String>(editor_id);
+            assert_eq!(text.as_deref(), Some("Crabs"));
+        });
+    }
+}
@@ -1,5 +1,9 @@
 pub mod app;
+pub mod board_capture;
 pub mod config_ui;
+pub mod diagnostics_ui;
 pub mod game_ui;
+pub mod settings;
+pub mod spectator_ui;
 
 pub use app::PartyJeopardyApp;
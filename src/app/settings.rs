@@ -0,0 +1,330 @@
+use crate::core::BoardLimits;
+use crate::game::buzzer::LineBuzzer;
+use crate::game::events::EventConfig;
+use crate::game::rules::GameRules;
+use crate::theme::ContrastMode;
+use crate::theme::animations::AnimationIntensity;
+use crate::theme::performance::{DEFAULT_LARGE_BOARD_CLUES, QualityPreset};
+use crate::ui::BoardStyle;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Answer flash length unless the host picks another
+pub const DEFAULT_FLASH_DURATION: Duration = Duration::from_millis(1200);
+
+/// Where the Teams panel sits around the board
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PanelPosition {
+    #[default]
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+/// Whose scores show while a game is in play; full standings always show once it's finished
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ScorePrivacy {
+    #[default]
+    Open,
+    /// Only the team in control sees its score
+    ActiveTeamOnly,
+    Hidden,
+}
+
+/// Placeholder for a score that privacy mode keeps hidden
+pub const HIDDEN_SCORE: &str = "—";
+
+impl ScorePrivacy {
+    /// Text shown for a team's score under this mode
+    pub fn displayed_score(self, score: i32, is_active: bool, finished: bool) -> String {
+        let visible = finished
+            || match self {
+                ScorePrivacy::Open => true,
+                ScorePrivacy::ActiveTeamOnly => is_active,
+                ScorePrivacy::Hidden => false,
+            };
+        if visible {
+            score.to_string()
+        } else {
+            HIDDEN_SCORE.to_string()
+        }
+    }
+}
+
+/// Where new teams get their default names
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TeamNaming {
+    /// A pattern with `{n}` standing for the team's number, e.g. "Squad {n}"
+    Numbered(String),
+    /// Preset names handed out in order, starting over once they run out
+    FromList(Vec<String>),
+}
+
+impl Default for TeamNaming {
+    fn default() -> Self {
+        TeamNaming::Numbered("Team {n}".to_string())
+    }
+}
+
+impl TeamNaming {
+    /// Default name for the team at `index` (0 for the first team)
+    pub fn name_for(&self, index: usize) -> String {
+        match self {
+            TeamNaming::Numbered(pattern) if pattern.contains("{n}") => {
+                pattern.replace("{n}", &(index + 1).to_string())
+            }
+            TeamNaming::Numbered(pattern) if !pattern.trim().is_empty() => {
+                format!("{} {}", pattern.trim(), index + 1)
+            }
+            TeamNaming::FromList(names) if !names.is_empty() => names[index % names.len()].clone(),
+            _ => format!("Team {}", index + 1),
+        }
+    }
+}
+
+/// How the window opens, picked on the command line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LaunchMode {
+    #[default]
+    Normal,
+    /// Fullscreen with the top bar hidden, for a TV showing only the board and scores
+    Kiosk,
+}
+
+impl LaunchMode {
+    /// `--kiosk` anywhere after the program name selects kiosk mode
+    pub fn from_args<I, S>(args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        if args
+            .into_iter()
+            .skip(1)
+            .any(|arg| arg.as_ref() == "--kiosk")
+        {
+            LaunchMode::Kiosk
+        } else {
+            LaunchMode::Normal
+        }
+    }
+}
+
+/// Where physical buzzers send their presses, picked on the command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuzzerSource {
+    /// `--buzzers=stdin`: one team id per line on standard input
+    Stdin,
+    /// `--buzzers=127.0.0.1:7777`: one team id per line from clients of this socket
+    Tcp(String),
+}
+
+impl BuzzerSource {
+    pub fn from_args<I, S>(args: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        args.into_iter()
+            .skip(1)
+            .find_map(|arg| match arg.as_ref().strip_prefix("--buzzers=")? {
+                "" => None,
+                "stdin" => Some(BuzzerSource::Stdin),
+                addr => Some(BuzzerSource::Tcp(addr.to_string())),
+            })
+    }
+
+    pub fn open(&self) -> std::io::Result<LineBuzzer> {
+        match self {
+            BuzzerSource::Stdin => Ok(LineBuzzer::stdin()),
+            BuzzerSource::Tcp(addr) => LineBuzzer::tcp(addr.as_str()),
+        }
+    }
+}
+
+/// Host preferences that shape how a game is run but aren't part of the game state.
+/// Exported and imported as JSON; fields missing from older files take their defaults.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppSettings {
+    /// Seconds the active team gets to pick a clue before control rotates (None = no limit)
+    pub selection_time_limit_secs: Option<u32>,
+    /// Seconds each stealer gets before the steal counts as missed (None = no limit)
+    pub steal_timer_secs: Option<u32>,
+    /// Type the question out character by character when a clue opens
+    pub typewriter_reveal: bool,
+    /// Grow the clicked cell out to the full screen before its question shows
+    pub clue_zoom: bool,
+    /// Lock the game board to this width/height ratio, letterboxed (None = fill the window)
+    pub board_aspect: Option<f32>,
+    /// How long the correct/incorrect flash plays before the answer is applied
+    /// (None = the standard 1.2s)
+    pub flash_duration_ms: Option<u32>,
+    /// How busy the correct/incorrect bursts are
+    pub animation_intensity: AnimationIntensity,
+    /// Glow and gradient detail on the board cells
+    pub quality: QualityPreset,
+    /// Boards with more clues than this get a hint to lower the quality at game start
+    /// (None = the standard 48)
+    pub large_board_clues: Option<usize>,
+    /// Skip bulk-entered team names that repeat an existing or earlier name
+    pub unique_team_names: bool,
+    /// Scoring rules handed to the engine while a game is running
+    pub rules: GameRules,
+    /// Event tuning handed to the engine alongside the rules
+    pub events: EventConfig,
+    /// Ask before judging clues worth at least this many points (None = never ask)
+    pub confirm_high_value_at: Option<u32>,
+    /// Explain each event after its animation and wait for the host to acknowledge it
+    pub announce_events: bool,
+    /// Edge of the window the Teams panel docks to
+    pub team_panel_position: PanelPosition,
+    /// Keep scores off the Teams panel and projector until the game is finished
+    pub score_privacy: ScorePrivacy,
+    /// Clicking a solved clue reopens it read-only, for going over answers
+    pub review_solved: bool,
+    /// Peek at a clue's question while hovering it on the host's board
+    pub host_hover_preview: bool,
+    /// Offer the host a collapsible answer in the steal overlay to help judge stealers
+    pub show_answer_to_host_on_steal: bool,
+    /// Keep the answer off the resolved screen until the host clicks Reveal Answer
+    pub reveal_answer_on_click: bool,
+    /// Caps on categories and rows while editing a board
+    pub board_limits: BoardLimits,
+    /// Kiosk mode: hide the top bar so only the board and scores show (F10 toggles)
+    pub hide_top_bar: bool,
+    /// Standard dark panels or the brighter, heavier-bordered variant
+    pub contrast: ContrastMode,
+    /// Don't prompt for team names when a game starts with no teams
+    pub skip_team_wizard: bool,
+    /// Let the host type what a team said and see how close it is to the answer
+    pub answer_assist: bool,
+    /// Default names for teams added from the lobby
+    pub team_name_template: TeamNaming,
+    /// Keep the category headers pinned and scroll the clue rows, for touchscreens
+    pub scroll_board: bool,
+    /// Gap between board cells and how rounded they are
+    pub board_style: BoardStyle,
+}
+
+impl AppSettings {
+    pub fn selection_time_limit(&self) -> Option<Duration> {
+        self.selection_time_limit_secs
+            .filter(|secs| *secs > 0)
+            .map(|secs| Duration::from_secs(secs as u64))
+    }
+
+    pub fn flash_duration(&self) -> Duration {
+        self.flash_duration_ms
+            .map(|ms| Duration::from_millis(ms as u64))
+            .unwrap_or(DEFAULT_FLASH_DURATION)
+    }
+
+    pub fn large_board_threshold(&self) -> usize {
+        self.large_board_clues.unwrap_or(DEFAULT_LARGE_BOARD_CLUES)
+    }
+
+    pub fn steal_timer(&self) -> Option<Duration> {
+        self.steal_timer_secs
+            .filter(|secs| *secs > 0)
+            .map(|secs| Duration::from_secs(secs as u64))
+    }
+
+    /// Whether judging a clue worth `points` should wait for a confirm
+    pub fn needs_outcome_confirmation(&self, points: u32) -> bool {
+        self.confirm_high_value_at
+            .is_some_and(|threshold| points >= threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_displayed_score_follows_privacy_until_finished() {
+        use ScorePrivacy::*;
+        assert_eq!(Open.displayed_score(300, false, false), "300");
+        assert_eq!(Hidden.displayed_score(300, true, false), HIDDEN_SCORE);
+        assert_eq!(ActiveTeamOnly.displayed_score(-200, true, false), "-200");
+        assert_eq!(
+            ActiveTeamOnly.displayed_score(300, false, false),
+            HIDDEN_SCORE
+        );
+        // The Finished screen reveals full standings in every mode
+        for privacy in [Open, ActiveTeamOnly, Hidden] {
+            assert_eq!(privacy.displayed_score(300, false, true), "300");
+        }
+    }
+
+    #[test]
+    fn test_launch_mode_from_args() {
+        assert_eq!(
+            LaunchMode::from_args(["rusty_krab", "--kiosk"]),
+            LaunchMode::Kiosk
+        );
+        assert_eq!(LaunchMode::from_args(["rusty_krab"]), LaunchMode::Normal);
+        assert_eq!(
+            LaunchMode::from_args(["rusty_krab", "--verbose"]),
+            LaunchMode::Normal
+        );
+        // The program name itself is never taken as a flag
+        assert_eq!(LaunchMode::from_args(["--kiosk"]), LaunchMode::Normal);
+        assert_eq!(
+            LaunchMode::from_args(Vec::<String>::new()),
+            LaunchMode::Normal
+        );
+    }
+
+    #[test]
+    fn test_buzzer_source_from_args() {
+        assert_eq!(
+            BuzzerSource::from_args(["rusty_krab", "--buzzers=stdin"]),
+            Some(BuzzerSource::Stdin)
+        );
+        assert_eq!(
+            BuzzerSource::from_args(["rusty_krab", "--kiosk", "--buzzers=127.0.0.1:7777"]),
+            Some(BuzzerSource::Tcp("127.0.0.1:7777".to_string()))
+        );
+        assert_eq!(BuzzerSource::from_args(["rusty_krab", "--buzzers="]), None);
+        assert_eq!(BuzzerSource::from_args(["rusty_krab"]), None);
+    }
+
+    #[test]
+    fn test_numbered_team_names_fill_in_the_number() {
+        let naming = TeamNaming::Numbered("Squad {n}".to_string());
+        assert_eq!(naming.name_for(0), "Squad 1");
+        assert_eq!(naming.name_for(1), "Squad 2");
+        assert_eq!(TeamNaming::default().name_for(2), "Team 3");
+        // A pattern without the placeholder still numbers its teams
+        assert_eq!(
+            TeamNaming::Numbered("Crew".to_string()).name_for(0),
+            "Crew 1"
+        );
+    }
+
+    #[test]
+    fn test_team_names_from_list_cycle_and_wrap() {
+        let naming = TeamNaming::FromList(vec![
+            "Red".to_string(),
+            "Green".to_string(),
+            "Blue".to_string(),
+        ]);
+        let names: Vec<String> = (0..5).map(|i| naming.name_for(i)).collect();
+        assert_eq!(names, vec!["Red", "Green", "Blue", "Red", "Green"]);
+        // An empty list falls back to numbered names
+        assert_eq!(TeamNaming::FromList(Vec::new()).name_for(0), "Team 1");
+    }
+
+    #[test]
+    fn test_outcome_confirmation_threshold() {
+        let mut settings = AppSettings::default();
+        assert!(!settings.needs_outcome_confirmation(1000));
+
+        settings.confirm_high_value_at = Some(800);
+        assert!(!settings.needs_outcome_confirmation(400));
+        assert!(settings.needs_outcome_confirmation(800));
+        assert!(settings.needs_outcome_confirmation(1000));
+    }
+}
@@ -0,0 +1,165 @@
+use eframe::egui;
+
+use crate::app::settings::ScorePrivacy;
+use crate::game::rules::HIDDEN_POINTS_LABEL;
+use crate::game::{GameEngine, PlayPhase};
+use crate::theme::Palette;
+use crate::ui::clue_cell_label;
+
+/// Audience-facing view for the projector: scores, the board and the clue in play.
+/// Takes the engine by shared reference so the display can never drive the game.
+pub fn render_spectator(ui: &mut egui::Ui, game_engine: &GameEngine, privacy: ScorePrivacy) {
+    crate::theme::paint_board_background(ui);
+    let state = game_engine.get_state();
+
+    // Scoreboard
+    let finished = matches!(state.phase, PlayPhase::Finished);
+    ui.horizontal_wrapped(|ui| {
+        for team in &state.teams {
+            let is_active = team.id == state.active_team
+                && !matches!(state.phase, PlayPhase::Lobby | PlayPhase::Finished);
+            let score = privacy.displayed_score(team.score, is_active, finished);
+            let color = if is_active {
+                Palette::CYAN
+            } else {
+                Palette::TEXT
+            };
+            ui.label(
+                egui::RichText::new(format!("{}  {}", team.label(), score))
+                    .size(26.0)
+                    .strong()
+                    .color(color),
+            );
+            ui.add_space(24.0);
+        }
+    });
+    if let Some(event) = &state.event_state.active_event {
+        ui.label(
+            egui::RichText::new(event.active_banner_label())
+                .size(20.0)
+                .color(Palette::AMBER_GLOW),
+        );
+    }
+    ui.separator();
+
+    match &state.phase {
+        PlayPhase::Lobby => {
+            ui.centered_and_justified(|ui| {
+                ui.heading(
+                    egui::RichText::new("Waiting for the host to start…")
+                        .size(40.0)
+                        .color(Palette::MAGENTA),
+                );
+            });
+        }
+        PlayPhase::Showing { .. } | PlayPhase::Steal { .. } => {
+            let (question, points) = game_engine
+                .current_clue()
+                .map(|c| (c.question.clone(), c.points))
+                .unwrap_or_default();
+            ui.vertical_centered(|ui| {
+                ui.add_space(40.0);
+                let points_text = if game_engine.rules().show_points_before_resolved() {
+                    format!("{} pts", points)
+                } else {
+                    HIDDEN_POINTS_LABEL.to_string()
+                };
+                ui.heading(
+                    egui::RichText::new(points_text)
+                        .size(36.0)
+                        .color(Palette::AMBER_GLOW),
+                );
+                ui.add_space(30.0);
+                ui.label(
+                    egui::RichText::new(question)
+                        .size(44.0)
+                        .color(Palette::CYBER_YELLOW),
+                );
+            });
+        }
+        _ => draw_spectator_board(ui, game_engine),
+    }
+}
+
+fn draw_spectator_board(ui: &mut egui::Ui, game_engine: &GameEngine) {
+    let board = &game_engine.get_state().board;
+    let show_values = game_engine.rules().show_cell_values;
+    let cols = board.categories.len().max(1);
+    let col_w = (ui.available_width() / cols as f32) - ui.spacing().item_spacing.x;
+    egui::Grid::new("spectator_board")
+        .spacing(egui::vec2(8.0, 8.0))
+        .min_col_width(col_w.max(60.0))
+        .show(ui, |ui| {
+            for category in &board.categories {
+                ui.vertical_centered(|ui| {
+                    ui.label(
+                        egui::RichText::new(&category.name)
+                            .size(22.0)
+                            .strong()
+                            .color(Palette::CYAN),
+                    );
+                });
+            }
+            ui.end_row();
+            let rows = board.categories.first().map(|c| c.clues.len()).unwrap_or(0);
+            for row in 0..rows {
+                for category in &board.categories {
+                    let text = match category.clues.get(row) {
+                        Some(clue) if !clue.solved => clue_cell_label(clue.points, show_values),
+                        _ => String::new(),
+                    };
+                    ui.vertical_centered(|ui| {
+                        ui.label(
+                            egui::RichText::new(text)
+                                .size(32.0)
+                                .color(Palette::AMBER_GLOW),
+                        );
+                    });
+                }
+                ui.end_row();
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Board;
+    use crate::game::GameAction;
+
+    fn render_headless(game_engine: &GameEngine) {
+        let ctx = egui::Context::default();
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                render_spectator(ui, game_engine, ScorePrivacy::Open)
+            });
+        });
+    }
+
+    #[test]
+    fn test_spectator_render_reads_engine_without_mutating_it() {
+        let mut engine = GameEngine::new(Board::default_with_dimensions(2, 2));
+        let _ = engine.handle_action(GameAction::AddTeam {
+            name: "Team 1".to_string(),
+        });
+        let _ = engine.handle_action(GameAction::StartGame);
+        let team_id = engine.get_state().teams[0].id;
+        let _ = engine.handle_action(GameAction::SelectClue {
+            clue: (0, 1),
+            team_id,
+        });
+
+        let before = serde_json::to_string(engine.get_state()).unwrap();
+        render_headless(&engine);
+        let after = serde_json::to_string(engine.get_state()).unwrap();
+        assert_eq!(before, after);
+
+        // Host-side changes are visible to the next display frame
+        let _ = engine.handle_action(GameAction::AnswerCorrect {
+            clue: (0, 1),
+            team_id,
+        });
+        render_headless(&engine);
+        assert_eq!(engine.get_team_score(team_id), Some(200));
+    }
+}
@@ -0,0 +1,231 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+
+use crate::core::Board;
+
+/// A single question the host keeps in their library
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BankEntry {
+    pub question: String,
+    pub answer: String,
+    /// Free-form labels; an entry tagged with a category's name is preferred for that category
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl BankEntry {
+    fn has_tag(&self, tag: &str) -> bool {
+        self.tags
+            .iter()
+            .any(|t| t.trim().eq_ignore_ascii_case(tag.trim()))
+    }
+}
+
+/// Pool of questions used to populate empty clues on a board
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QuestionBank {
+    pub entries: Vec<BankEntry>,
+}
+
+impl QuestionBank {
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn load_from_path(path: &Path) -> Result<Self> {
+        let data = fs::read_to_string(path)?;
+        Self::from_json(&data)
+    }
+}
+
+/// Default bank location: ./question_bank.json in the working directory
+pub fn default_bank_path() -> Result<PathBuf> {
+    Ok(std::env::current_dir()?.join("question_bank.json"))
+}
+
+/// Categories and rows of the board "Quick Demo Game" builds
+pub const DEMO_BOARD_SIZE: (usize, usize) = (3, 3);
+
+impl Board {
+    /// A fresh `categories`×`rows` board on the standard 100-point ladder with every
+    /// clue drawn from `bank`, for running a demo without writing any questions.
+    /// Fails rather than leave blanks when the bank is too small.
+    pub fn quick_demo<R: Rng + ?Sized>(
+        bank: &QuestionBank,
+        categories: usize,
+        rows: usize,
+        rng: &mut R,
+    ) -> Result<Board> {
+        let needed = categories * rows;
+        if bank.entries.len() < needed {
+            anyhow::bail!(
+                "a {}×{} demo needs {} questions but the bank has {}",
+                categories,
+                rows,
+                needed,
+                bank.entries.len()
+            );
+        }
+        let mut board = Board::default_scaled(categories, rows, 100);
+        board.fill_from_bank(bank, rng);
+        Ok(board)
+    }
+
+    /// Fill every clue with no question and no answer from random bank entries,
+    /// preferring entries tagged with the clue's category name. Filled-in clues are
+    /// never touched and no entry is used twice; once the bank runs dry the remaining
+    /// empties are left as they are. Returns how many clues were filled.
+    pub fn fill_from_bank<R: Rng + ?Sized>(&mut self, bank: &QuestionBank, rng: &mut R) -> usize {
+        let mut used: HashSet<usize> = HashSet::new();
+        let mut filled = 0;
+        for category in &mut self.categories {
+            for clue in &mut category.clues {
+                if !clue.question.trim().is_empty() || !clue.answer.trim().is_empty() {
+                    continue;
+                }
+                let unused: Vec<usize> = (0..bank.entries.len())
+                    .filter(|i| !used.contains(i))
+                    .collect();
+                let tagged: Vec<usize> = unused
+                    .iter()
+                    .copied()
+                    .filter(|&i| bank.entries[i].has_tag(&category.name))
+                    .collect();
+                let pool = if tagged.is_empty() { &unused } else { &tagged };
+                let Some(&pick) = pool.choose(rng) else {
+                    return filled;
+                };
+                used.insert(pick);
+                clue.question = bank.entries[pick].question.clone();
+                clue.answer = bank.entries[pick].answer.clone();
+                filled += 1;
+            }
+        }
+        filled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn entry(question: &str, tags: &[&str]) -> BankEntry {
+        BankEntry {
+            question: question.to_string(),
+            answer: format!("{} answer", question),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+
+    fn bank_of(count: usize) -> QuestionBank {
+        QuestionBank {
+            entries: (0..count).map(|i| entry(&format!("Q{}", i), &[])).collect(),
+        }
+    }
+
+    #[test]
+    fn test_fill_keeps_existing_clues_and_fills_empties() {
+        let mut board = Board::default_with_dimensions(2, 3);
+        board.categories[0].clues[1].question = "Host question".to_string();
+        board.categories[1].clues[2].answer = "Host answer".to_string();
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let filled = board.fill_from_bank(&bank_of(10), &mut rng);
+
+        assert_eq!(filled, 4);
+        assert_eq!(board.categories[0].clues[1].question, "Host question");
+        assert!(board.categories[0].clues[1].answer.is_empty());
+        assert_eq!(board.categories[1].clues[2].answer, "Host answer");
+        assert!(board.categories[1].clues[2].question.is_empty());
+        for (ci, ri) in [(0, 0), (0, 2), (1, 0), (1, 1)] {
+            let clue = &board.categories[ci].clues[ri];
+            assert!(clue.question.starts_with('Q'));
+            assert_eq!(clue.answer, format!("{} answer", clue.question));
+        }
+    }
+
+    #[test]
+    fn test_fill_does_not_reuse_entries() {
+        let mut board = Board::default_with_dimensions(3, 3);
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let filled = board.fill_from_bank(&bank_of(9), &mut rng);
+
+        assert_eq!(filled, 9);
+        let questions: HashSet<String> = board
+            .categories
+            .iter()
+            .flat_map(|c| c.clues.iter().map(|clue| clue.question.clone()))
+            .collect();
+        assert_eq!(questions.len(), 9);
+    }
+
+    #[test]
+    fn test_fill_stops_when_bank_runs_out() {
+        let mut board = Board::default_with_dimensions(2, 2);
+        let mut rng = StdRng::seed_from_u64(1);
+
+        assert_eq!(board.fill_from_bank(&bank_of(3), &mut rng), 3);
+        let empty = board
+            .categories
+            .iter()
+            .flat_map(|c| c.clues.iter())
+            .filter(|clue| clue.question.is_empty())
+            .count();
+        assert_eq!(empty, 1);
+    }
+
+    #[test]
+    fn test_fill_prefers_entries_tagged_with_category() {
+        let mut board = Board::default_with_dimensions(2, 1);
+        board.categories[0].name = "Science".to_string();
+        board.categories[1].name = "History".to_string();
+        let bank = QuestionBank {
+            entries: vec![
+                entry("Rome", &["history"]),
+                entry("Atoms", &["Science"]),
+                entry("Misc", &[]),
+            ],
+        };
+        let mut rng = StdRng::seed_from_u64(3);
+
+        board.fill_from_bank(&bank, &mut rng);
+
+        assert_eq!(board.categories[0].clues[0].question, "Atoms");
+        assert_eq!(board.categories[1].clues[0].question, "Rome");
+    }
+
+    #[test]
+    fn test_quick_demo_board_is_fully_filled() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let (categories, rows) = DEMO_BOARD_SIZE;
+
+        let board = Board::quick_demo(&bank_of(12), categories, rows, &mut rng).unwrap();
+
+        assert_eq!(board.categories.len(), categories);
+        for category in &board.categories {
+            assert_eq!(category.clues.len(), rows);
+            for clue in &category.clues {
+                assert!(!clue.question.is_empty() && !clue.answer.is_empty());
+            }
+        }
+        // One question short of a full board is refused outright
+        assert!(Board::quick_demo(&bank_of(8), categories, rows, &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_bank_from_json_defaults_tags() {
+        let bank =
+            QuestionBank::from_json(r#"{"entries":[{"question":"Q","answer":"A"}]}"#).unwrap();
+        assert_eq!(bank.entries.len(), 1);
+        assert!(bank.entries[0].tags.is_empty());
+    }
+}
@@ -3,17 +3,130 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Category {
     pub name: String,
+    /// "Minefield" category: a correct answer costs the clue's value and a wrong one earns it
+    #[serde(default)]
+    pub inverted: bool,
+    /// Flavor sentence the host reads when introducing the category
+    #[serde(default)]
+    pub intro: Option<String>,
     pub clues: Vec<Clue>,
 }
 
+/// Host's rough rating of how hard a clue is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Medium => "Medium",
+            Difficulty::Hard => "Hard",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Clue {
     pub id: u32,
     pub points: u32,
     pub question: String,
     pub answer: String,
+    /// Other answers the host should also accept
+    #[serde(default)]
+    pub alt_answers: Vec<String>,
     pub revealed: bool,
     pub solved: bool,
+    #[serde(default)]
+    pub difficulty: Option<Difficulty>,
+    /// Free text the host typed while the clue was open, e.g. survey answers; never scored
+    #[serde(default)]
+    pub host_note: Option<String>,
+    /// Answer with a quiet fade instead of the full burst, e.g. for a memorial question
+    #[serde(default)]
+    pub no_flash: bool,
+    /// Drawn at game start to allow a second attempt whatever the clue is worth
+    #[serde(default)]
+    pub bonus_attempt: bool,
+    /// Picture shown with the question
+    #[serde(default)]
+    pub media: Option<ClueMedia>,
+    /// Locked until the game reaches this round (None = pickable from the start)
+    #[serde(default)]
+    pub unlock_round: Option<u32>,
+}
+
+impl Clue {
+    /// Whether the clue is still gated to a later round than `round`
+    pub fn is_locked(&self, round: u32) -> bool {
+        self.unlock_round.is_some_and(|unlock| round < unlock)
+    }
+
+    /// How close `given` comes to the answer or any accepted alternate (0..=1)
+    pub fn best_answer_similarity(&self, given: &str) -> f32 {
+        std::iter::once(&self.answer)
+            .chain(&self.alt_answers)
+            .map(|expected| answer_similarity(given, expected))
+            .fold(0.0, f32::max)
+    }
+}
+
+/// Lowercase words with punctuation dropped, so "The Crab!" matches "the crab"
+fn normalize_answer(text: &str) -> Vec<char> {
+    let cleaned: String = text
+        .chars()
+        .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    cleaned
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .collect()
+}
+
+/// Levenshtein ratio between what a team said and a stored answer, ignoring case,
+/// punctuation and spacing: 1.0 is a match, 0.0 shares nothing. A hint for the host only.
+pub fn answer_similarity(given: &str, expected: &str) -> f32 {
+    let a = normalize_answer(given);
+    let b = normalize_answer(expected);
+    if a.is_empty() || b.is_empty() {
+        return if a == b { 1.0 } else { 0.0 };
+    }
+    // Single-row edit distance
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    1.0 - row[b.len()] as f32 / a.len().max(b.len()) as f32
+}
+
+/// An image file attached to a clue, with a caption that stands in if it can't be shown
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClueMedia {
+    pub path: String,
+    #[serde(default)]
+    pub caption: String,
+}
+
+impl ClueMedia {
+    /// URI the egui image loaders resolve
+    pub fn uri(&self) -> String {
+        format!("file://{}", self.path)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,6 +139,46 @@ pub struct Team {
     pub id: u32,
     pub name: String,
     pub score: i32,
+    /// Optional avatar shown before the team name
+    #[serde(default)]
+    pub emoji: Option<String>,
+}
+
+/// Avatars offered by the lobby picker
+pub const TEAM_EMOJI_CHOICES: &[&str] = &[
+    "🦀", "🐙", "🦊", "🐼", "🐸", "🦄", "🐝", "🐢", "🚀", "🔥", "⚡", "🎸", "👾", "🍕", "🌵", "💎",
+];
+
+impl Team {
+    /// Accepts a short, whitespace-free symbol (a single emoji, including ZWJ and
+    /// variation-selector sequences); plain words are rejected
+    pub fn is_valid_emoji(emoji: &str) -> bool {
+        let count = emoji.chars().count();
+        count > 0
+            && count <= 8
+            && !emoji
+                .chars()
+                .any(|c| c.is_whitespace() || c.is_ascii_alphanumeric())
+    }
+
+    /// Set or clear the avatar; returns false and leaves it unchanged if invalid
+    pub fn set_emoji(&mut self, emoji: Option<&str>) -> bool {
+        match emoji {
+            Some(emoji) if !Self::is_valid_emoji(emoji) => false,
+            emoji => {
+                self.emoji = emoji.map(str::to_string);
+                true
+            }
+        }
+    }
+
+    /// Name as displayed on screen, prefixed with the avatar when one is set
+    pub fn label(&self) -> String {
+        match &self.emoji {
+            Some(emoji) => format!("{} {}", emoji, self.name),
+            None => self.name.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -66,13 +219,18 @@ impl Default for Board {
 
 impl Board {
     pub fn default_with_dimensions(num_categories: usize, num_rows: usize) -> Self {
+        Self::default_scaled(num_categories, num_rows, 100)
+    }
+
+    /// Blank board whose rows are worth `base`, `2 * base`, `3 * base`, …
+    pub fn default_scaled(num_categories: usize, num_rows: usize, base: u32) -> Self {
         let mut categories = Vec::with_capacity(num_categories);
         let mut next_id: u32 = 1;
         for i in 0..num_categories {
             let name = format!("Category {}", i + 1);
             let mut clues = Vec::with_capacity(num_rows);
             for row in 0..num_rows {
-                let points = ((row as u32) + 1) * 100;
+                let points = ((row as u32) + 1) * base;
                 clues.push(Clue {
                     id: next_id,
                     points,
@@ -80,16 +238,490 @@ impl Board {
                     answer: String::new(),
                     revealed: false,
                     solved: false,
+                    difficulty: None,
+                    host_note: None,
+                    no_flash: false,
+                    bonus_attempt: false,
+                    unlock_round: None,
+                    media: None,
+                    alt_answers: Vec::new(),
                 });
                 next_id += 1;
             }
-            categories.push(Category { name, clues });
+            categories.push(Category {
+                name,
+                inverted: false,
+                intro: None,
+                clues,
+            });
         }
         Board { categories }
     }
+
+    /// Exchange the content of two clues, leaving each cell's id, points and play
+    /// state where they are. Returns false if either position doesn't exist.
+    pub fn swap_clue_content(&mut self, a: (usize, usize), b: (usize, usize)) -> bool {
+        let (Some(first), Some(second)) = (self.clue_at(a).cloned(), self.clue_at(b).cloned())
+        else {
+            return false;
+        };
+        if a == b {
+            return true;
+        }
+        for (pos, source) in [(a, second), (b, first)] {
+            let clue = &mut self.categories[pos.0].clues[pos.1];
+            clue.question = source.question;
+            clue.answer = source.answer;
+            clue.difficulty = source.difficulty;
+            clue.host_note = source.host_note;
+            clue.media = source.media;
+            clue.alt_answers = source.alt_answers;
+            clue.no_flash = source.no_flash;
+            clue.unlock_round = source.unlock_round;
+        }
+        true
+    }
+
+    /// Insert a copy of category `index` right after it, named "<name> (copy)" and
+    /// with fresh clue ids. Returns where the copy went, or None if there's no such category.
+    pub fn clone_category(&mut self, index: usize) -> Option<usize> {
+        let mut copy = self.categories.get(index)?.clone();
+        let next_id = self
+            .categories
+            .iter()
+            .flat_map(|c| c.clues.iter())
+            .map(|clue| clue.id)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        for (id, clue) in (next_id..).zip(&mut copy.clues) {
+            clue.id = id;
+        }
+        copy.name = format!("{} (copy)", copy.name);
+        self.categories.insert(index + 1, copy);
+        Some(index + 1)
+    }
+
+    fn clue_at(&self, pos: (usize, usize)) -> Option<&Clue> {
+        self.categories.get(pos.0)?.clues.get(pos.1)
+    }
+
+    /// Point value of each row, read from the first category (the editor's point ladder)
+    pub fn point_ladder(&self) -> Vec<u32> {
+        self.categories
+            .first()
+            .map(|c| c.clues.iter().map(|clue| clue.points).collect())
+            .unwrap_or_default()
+    }
+
+    /// Problems the editor should flag before the board is played; empty when there are none
+    pub fn validate(&self) -> Vec<BoardIssue> {
+        self.categories
+            .iter()
+            .enumerate()
+            .filter(|(_, category)| {
+                category
+                    .clues
+                    .windows(2)
+                    .any(|pair| pair[1].points <= pair[0].points)
+            })
+            .map(|(category, _)| BoardIssue::NonMonotonicPoints { category })
+            .collect()
+    }
+
+    /// Reset each category `validate` flags to an even ladder, `base`, `2 * base`, …
+    /// down the rows, where `base` is the board's smallest clue value (100 if there
+    /// isn't one). Categories whose ladder already rises are left as they are.
+    pub fn normalize_points(&mut self) {
+        let base = self
+            .categories
+            .iter()
+            .flat_map(|c| c.clues.iter())
+            .map(|clue| clue.points)
+            .filter(|points| *points > 0)
+            .min()
+            .unwrap_or(100);
+        for issue in self.validate() {
+            let BoardIssue::NonMonotonicPoints { category } = issue;
+            for (row, clue) in self.categories[category].clues.iter_mut().enumerate() {
+                clue.points = (row as u32 + 1) * base;
+            }
+        }
+    }
+
+    /// Fraction of clues with both a question and an answer (whitespace doesn't count);
+    /// 0.0 for a board without clues
+    pub fn fill_ratio(&self) -> f32 {
+        let clues = self.categories.iter().flat_map(|c| c.clues.iter());
+        let (filled, total) = clues.fold((0usize, 0usize), |(filled, total), clue| {
+            let is_filled = !clue.question.trim().is_empty() && !clue.answer.trim().is_empty();
+            (filled + is_filled as usize, total + 1)
+        });
+        if total == 0 {
+            0.0
+        } else {
+            filled as f32 / total as f32
+        }
+    }
+}
+
+/// Something `Board::validate` found wrong with a board
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardIssue {
+    /// The category's clue values don't strictly rise from the top row down
+    NonMonotonicPoints { category: usize },
+}
+
+/// Largest board the editor will grow to. The defaults keep headers readable
+/// on a projector; raise them for bigger screens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BoardLimits {
+    pub max_categories: usize,
+    pub max_rows: usize,
+}
+
+impl Default for BoardLimits {
+    fn default() -> Self {
+        Self {
+            max_categories: 10,
+            max_rows: 8,
+        }
+    }
+}
+
+impl BoardLimits {
+    pub fn can_add_category(&self, board: &Board) -> bool {
+        board.categories.len() < self.max_categories
+    }
+
+    /// Rows are counted from the first category, like the editor's point ladder
+    pub fn can_add_row(&self, board: &Board) -> bool {
+        board.point_ladder().len() < self.max_rows
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct ConfigState {
     pub board: Board,
 }
+
+#[cfg(test)]
+mod board_tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_validate_flags_scrambled_point_ladders() {
+        let mut board = Board::default_with_dimensions(3, 3);
+        assert!(board.validate().is_empty());
+
+        for (clue, points) in board.categories[1].clues.iter_mut().zip([100, 300, 200]) {
+            clue.points = points;
+        }
+        // A repeated value isn't a rising ladder either
+        board.categories[2].clues[2].points = 200;
+        assert_eq!(
+            board.validate(),
+            vec![
+                BoardIssue::NonMonotonicPoints { category: 1 },
+                BoardIssue::NonMonotonicPoints { category: 2 },
+            ]
+        );
+
+        // A custom ladder that still rises is nothing to fix
+        for (clue, points) in board.categories[0].clues.iter_mut().zip([150, 400, 900]) {
+            clue.points = points;
+        }
+
+        board.normalize_points();
+        assert!(board.validate().is_empty());
+        let ladder = |board: &Board, ci: usize| -> Vec<u32> {
+            board.categories[ci]
+                .clues
+                .iter()
+                .map(|c| c.points)
+                .collect()
+        };
+        assert_eq!(ladder(&board, 0), vec![150, 400, 900]);
+        assert_eq!(ladder(&board, 1), vec![100, 200, 300]);
+        assert_eq!(ladder(&board, 2), vec![100, 200, 300]);
+    }
+
+    #[test]
+    fn test_board_limits_guard_add_category_and_row() {
+        let mut limits = BoardLimits::default();
+        let board = Board::default_with_dimensions(11, 8);
+        assert!(!limits.can_add_category(&board));
+        assert!(!limits.can_add_row(&board));
+        assert!(limits.can_add_category(&Board::default_with_dimensions(9, 7)));
+        assert!(limits.can_add_row(&Board::default_with_dimensions(9, 7)));
+
+        // Raising the caps lets the board grow past the old literals
+        limits.max_categories = 12;
+        limits.max_rows = 10;
+        assert!(limits.can_add_category(&board));
+        assert!(limits.can_add_row(&board));
+        assert!(!limits.can_add_category(&Board::default_with_dimensions(12, 8)));
+        assert!(!limits.can_add_row(&Board::default_with_dimensions(12, 10)));
+    }
+
+    #[test]
+    fn test_point_ladder_follows_first_category() {
+        let mut board = Board::default_with_dimensions(3, 4);
+        assert_eq!(board.point_ladder(), vec![100, 200, 300, 400]);
+
+        board.categories[0].clues[1].points = 250;
+        board.categories[1].clues[1].points = 999;
+        board.categories[0].clues.pop();
+        assert_eq!(board.point_ladder(), vec![100, 250, 300]);
+    }
+
+    #[test]
+    fn test_swap_clue_content_keeps_ids_and_points() {
+        let mut board = Board::default_with_dimensions(2, 3);
+        board.categories[0].clues[0].question = "Easy Q".to_string();
+        board.categories[0].clues[0].answer = "Easy A".to_string();
+        board.categories[1].clues[2].question = "Hard Q".to_string();
+        board.categories[1].clues[2].answer = "Hard A".to_string();
+        board.categories[1].clues[2].alt_answers = vec!["Hard Alt".to_string()];
+        board.categories[1].clues[2].no_flash = true;
+        board.categories[1].clues[2].unlock_round = Some(2);
+        board.categories[1].clues[2].host_note = Some("Accept any decade".to_string());
+        board.categories[0].clues[0].media = Some(ClueMedia {
+            path: "crab.png".to_string(),
+            caption: "A crab".to_string(),
+        });
+        let (id_a, id_b) = (
+            board.categories[0].clues[0].id,
+            board.categories[1].clues[2].id,
+        );
+
+        assert!(board.swap_clue_content((0, 0), (1, 2)));
+
+        let a = &board.categories[0].clues[0];
+        let b = &board.categories[1].clues[2];
+        assert_eq!(
+            (a.question.as_str(), a.answer.as_str()),
+            ("Hard Q", "Hard A")
+        );
+        assert_eq!(
+            (b.question.as_str(), b.answer.as_str()),
+            ("Easy Q", "Easy A")
+        );
+        assert_eq!(a.host_note.as_deref(), Some("Accept any decade"));
+        assert_eq!(b.host_note, None);
+        assert_eq!(a.alt_answers, vec!["Hard Alt".to_string()]);
+        assert!(b.alt_answers.is_empty());
+        assert!(a.no_flash && !b.no_flash);
+        assert_eq!((a.unlock_round, b.unlock_round), (Some(2), None));
+        assert!(a.media.is_none());
+        assert_eq!(b.media.as_ref().map(|m| m.path.as_str()), Some("crab.png"));
+        assert_eq!((a.id, a.points), (id_a, 100));
+        assert_eq!((b.id, b.points), (id_b, 300));
+    }
+
+    #[test]
+    fn test_swap_clue_content_rejects_missing_cells() {
+        let mut board = Board::default_with_dimensions(2, 2);
+        board.categories[0].clues[0].question = "Q".to_string();
+        assert!(!board.swap_clue_content((0, 0), (5, 0)));
+        assert!(!board.swap_clue_content((0, 9), (0, 0)));
+        assert_eq!(board.categories[0].clues[0].question, "Q");
+    }
+
+    #[test]
+    fn test_alt_answers_serde_round_trip() {
+        let mut board = Board::default_with_dimensions(1, 1);
+        board.categories[0].clues[0].alt_answers = vec!["NYC".to_string(), "Big Apple".to_string()];
+        let json = serde_json::to_string(&board).unwrap();
+        let restored: Board = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            restored.categories[0].clues[0].alt_answers,
+            vec!["NYC", "Big Apple"]
+        );
+
+        // Clues saved before alternates existed load with none
+        let legacy: Clue = serde_json::from_str(
+            r#"{"id":1,"points":100,"question":"Q","answer":"A","revealed":false,"solved":false}"#,
+        )
+        .unwrap();
+        assert!(legacy.alt_answers.is_empty());
+    }
+
+    #[test]
+    fn test_category_intro_serde_round_trip() {
+        let mut board = Board::default_with_dimensions(2, 1);
+        board.categories[0].intro = Some("All about 90s cartoons".to_string());
+        let json = serde_json::to_string(&board).unwrap();
+        let restored: Board = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            restored.categories[0].intro.as_deref(),
+            Some("All about 90s cartoons")
+        );
+        assert_eq!(restored.categories[1].intro, None);
+
+        // Categories saved before intros existed load without one
+        let legacy: Category = serde_json::from_str(r#"{"name":"Old","clues":[]}"#).unwrap();
+        assert_eq!(legacy.intro, None);
+    }
+
+    #[test]
+    fn test_clone_category_inserts_copy_with_fresh_ids() {
+        let mut board = Board::default_with_dimensions(3, 2);
+        board.categories[1].name = "Rivers".to_string();
+        board.categories[1].clues[0].question = "Longest river?".to_string();
+        board.categories[1].clues[0].answer = "Nile".to_string();
+
+        assert_eq!(board.clone_category(1), Some(2));
+
+        assert_eq!(board.categories.len(), 4);
+        let (source, copy) = (&board.categories[1], &board.categories[2]);
+        assert_eq!(copy.name, "Rivers (copy)");
+        for (a, b) in source.clues.iter().zip(&copy.clues) {
+            assert_eq!(a.points, b.points);
+            assert_eq!(a.question, b.question);
+            assert_eq!(a.answer, b.answer);
+        }
+        let ids: HashSet<u32> = board
+            .categories
+            .iter()
+            .flat_map(|c| c.clues.iter().map(|clue| clue.id))
+            .collect();
+        assert_eq!(ids.len(), 8);
+        // The category that followed the source moves one to the right
+        assert_eq!(board.categories[3].name, "Category 3");
+        assert_eq!(board.clone_category(9), None);
+    }
+
+    #[test]
+    fn test_default_scaled_uses_base_per_row() {
+        let board = Board::default_scaled(2, 3, 200);
+        assert_eq!(board.categories.len(), 2);
+        for category in &board.categories {
+            let points: Vec<u32> = category.clues.iter().map(|c| c.points).collect();
+            assert_eq!(points, vec![200, 400, 600]);
+        }
+    }
+
+    #[test]
+    fn test_default_with_dimensions_keeps_hundred_ladder() {
+        let board = Board::default_with_dimensions(2, 3);
+        for category in &board.categories {
+            let points: Vec<u32> = category.clues.iter().map(|c| c.points).collect();
+            assert_eq!(points, vec![100, 200, 300]);
+        }
+    }
+
+    #[test]
+    fn test_point_ladder_empty_board() {
+        let board = Board { categories: vec![] };
+        assert!(board.point_ladder().is_empty());
+        assert_eq!(board.fill_ratio(), 0.0);
+    }
+
+    #[test]
+    fn test_fill_ratio_ignores_whitespace_only_text() {
+        let mut board = Board::default_with_dimensions(2, 2);
+        assert_eq!(board.fill_ratio(), 0.0);
+
+        for clue in &mut board.categories[0].clues {
+            clue.question = "Q".to_string();
+            clue.answer = "A".to_string();
+        }
+        // Blank-looking text and half-written clues don't count
+        board.categories[1].clues[0].question = "   ".to_string();
+        board.categories[1].clues[0].answer = "\t".to_string();
+        board.categories[1].clues[1].question = "Q only".to_string();
+
+        assert_eq!(board.fill_ratio(), 0.5);
+    }
+}
+
+#[cfg(test)]
+mod team_tests {
+    use super::*;
+
+    fn team() -> Team {
+        Team {
+            id: 1,
+            name: "Crabs".to_string(),
+            score: 0,
+            emoji: None,
+        }
+    }
+
+    #[test]
+    fn test_team_emoji_serde_round_trip() {
+        let mut original = team();
+        assert!(original.set_emoji(Some("🦀")));
+        let json = serde_json::to_string(&original).unwrap();
+        let restored: Team = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.emoji.as_deref(), Some("🦀"));
+
+        // Saves from before avatars existed still load
+        let legacy: Team = serde_json::from_str(r#"{"id":1,"name":"Crabs","score":0}"#).unwrap();
+        assert_eq!(legacy.emoji, None);
+    }
+
+    #[test]
+    fn test_team_label_includes_emoji_when_set() {
+        let mut team = team();
+        assert_eq!(team.label(), "Crabs");
+        team.set_emoji(Some("🦀"));
+        assert_eq!(team.label(), "🦀 Crabs");
+        team.set_emoji(None);
+        assert_eq!(team.label(), "Crabs");
+    }
+
+    #[test]
+    fn test_team_emoji_validation() {
+        assert!(Team::is_valid_emoji("🦀"));
+        assert!(Team::is_valid_emoji("👨‍👩‍👧"));
+        assert!(Team::is_valid_emoji("⚡️"));
+        assert!(!Team::is_valid_emoji(""));
+        assert!(!Team::is_valid_emoji("crab"));
+        assert!(!Team::is_valid_emoji("🦀 🐙"));
+        assert!(!Team::is_valid_emoji("🦀🦀🦀🦀🦀🦀🦀🦀🦀"));
+
+        let mut team = team();
+        assert!(!team.set_emoji(Some("crab")));
+        assert_eq!(team.emoji, None);
+        assert!(TEAM_EMOJI_CHOICES.iter().all(|e| Team::is_valid_emoji(e)));
+    }
+}
+
+#[cfg(test)]
+mod answer_tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_similarity_exact_ignores_case_and_punctuation() {
+        assert_eq!(answer_similarity("Mariana Trench", "Mariana Trench"), 1.0);
+        assert_eq!(answer_similarity("  the CRAB! ", "The crab"), 1.0);
+    }
+
+    #[test]
+    fn test_answer_similarity_near_miss_scores_high() {
+        let typo = answer_similarity("Marianna Trench", "Mariana Trench");
+        assert!(typo > 0.9 && typo < 1.0, "{}", typo);
+        let partial = answer_similarity("Mariana", "Mariana Trench");
+        assert!(partial > 0.4 && partial < typo, "{}", partial);
+    }
+
+    #[test]
+    fn test_answer_similarity_unrelated_scores_low() {
+        assert!(answer_similarity("Volcano", "Mariana Trench") < 0.3);
+        assert_eq!(answer_similarity("abc", "xyz"), 0.0);
+        assert_eq!(answer_similarity("", "Mariana Trench"), 0.0);
+        assert_eq!(answer_similarity("?!", ""), 1.0);
+    }
+
+    #[test]
+    fn test_best_similarity_checks_alternates() {
+        let mut clue = Board::default_with_dimensions(1, 1).categories[0].clues[0].clone();
+        clue.answer = "Deoxyribonucleic acid".to_string();
+        clue.alt_answers = vec!["DNA".to_string()];
+        assert_eq!(clue.best_answer_similarity("dna"), 1.0);
+        assert!(clue.best_answer_similarity("RNA") < 1.0);
+    }
+}
@@ -1,3 +1,4 @@
+pub mod bank;
 pub mod domain;
 pub mod persistence;
 pub mod storage;
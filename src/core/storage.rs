@@ -1,16 +1,55 @@
+use std::fmt::Write as _;
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
+use crate::app::settings::AppSettings;
 use crate::core::Board;
-use crate::game::GameState;
+use crate::game::events::EventConfig;
+use crate::game::rules::GameRules;
+use crate::game::{GameEngine, GameState};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Snapshot {
     pub board: Board,
     pub game: Option<GameState>,
+    /// Rules in force when saved; older saves load with the defaults
+    #[serde(default)]
+    pub rules: GameRules,
+    #[serde(default)]
+    pub events: EventConfig,
+}
+
+impl Snapshot {
+    /// Snapshot of a running game and its rules, with transient animation state cleared
+    pub fn of_game(game_engine: &GameEngine) -> Self {
+        let mut game = game_engine.get_state().clone();
+        game.sanitize_for_save();
+        Self {
+            board: game.board.clone(),
+            game: Some(game),
+            rules: game_engine.rules().clone(),
+            events: game_engine.event_config().clone(),
+        }
+    }
+
+    /// Rebuild the engine for a saved game, enforcing the saved rules.
+    /// None for board-only snapshots.
+    pub fn into_engine(self) -> Option<GameEngine> {
+        let mut game = self.game?;
+        // Older saves may still carry animation flags
+        game.sanitize_for_save();
+        for note in game.validate_and_repair() {
+            log::warn!("repaired loaded game: {}", note);
+        }
+        let mut game_engine = GameEngine::new(game.board.clone());
+        *game_engine.get_state_mut() = game;
+        *game_engine.rules_mut() = self.rules;
+        *game_engine.event_config_mut() = self.events;
+        Some(game_engine)
+    }
 }
 
 // Manual saves in ./saves directory
@@ -57,3 +96,400 @@ pub fn load_snapshot_from_path(path: &Path) -> Result<Snapshot> {
     let snapshot: Snapshot = serde_json::from_str(&data)?;
     Ok(snapshot)
 }
+
+/// Read a board from JSON: either a bare board or a snapshot, whose board is used
+pub fn import_board(path: &Path) -> Result<Board> {
+    let data = fs::read_to_string(path)?;
+    let board = match serde_json::from_str::<Board>(&data) {
+        Ok(board) => board,
+        Err(board_err) => match serde_json::from_str::<Snapshot>(&data) {
+            Ok(snapshot) => snapshot.board,
+            Err(_) => return Err(board_err.into()),
+        },
+    };
+    anyhow::ensure!(!board.categories.is_empty(), "board has no categories");
+    Ok(board)
+}
+
+/// Board the app opens with, if the host left one at ./startup_board.json
+pub fn startup_board_path() -> Result<PathBuf> {
+    Ok(std::env::current_dir()?.join("startup_board.json"))
+}
+
+/// The startup board at `path`: None when there's no file, an error when there
+/// is one but it can't be used
+pub fn load_startup_board(path: &Path) -> Result<Option<Board>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    import_board(path).map(Some)
+}
+
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Render the board as a standalone printable HTML page: one column per category,
+/// one row per point value, each cell holding the points, question and answer.
+pub fn board_to_html(board: &Board) -> String {
+    let rows = board
+        .categories
+        .iter()
+        .map(|c| c.clues.len())
+        .max()
+        .unwrap_or(0);
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<title>Jeopardy Board</title>\n<style>\n");
+    html.push_str("body { font-family: sans-serif; }\n");
+    html.push_str("table { border-collapse: collapse; width: 100%; }\n");
+    html.push_str("th, td { border: 1px solid #333; padding: 6px; vertical-align: top; }\n");
+    html.push_str(".points { font-weight: bold; }\n.answer { font-style: italic; }\n");
+    html.push_str("</style>\n</head>\n<body>\n<table>\n<tr>");
+    for category in &board.categories {
+        let _ = write!(html, "<th>{}</th>", escape_html(&category.name));
+    }
+    html.push_str("</tr>\n");
+
+    for row in 0..rows {
+        html.push_str("<tr>");
+        for category in &board.categories {
+            match category.clues.get(row) {
+                Some(clue) => {
+                    let _ = write!(
+                        html,
+                        "<td><div class=\"points\">{}</div><div>{}</div><div class=\"answer\">{}</div></td>",
+                        clue.points,
+                        escape_html(&clue.question),
+                        escape_html(&clue.answer)
+                    );
+                }
+                None => html.push_str("<td></td>"),
+            }
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</table>\n</body>\n</html>\n");
+    html
+}
+
+/// Write the printable HTML for `board` into ./saves next to the JSON snapshots
+pub fn save_board_html(file_stem: &str, board: &Board) -> Result<PathBuf> {
+    let dir = ensure_saves_dir()?;
+    let safe_name: String = file_stem
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+        .collect();
+    let name = if safe_name.is_empty() {
+        "board".to_string()
+    } else {
+        safe_name
+    };
+    let path = dir.join(format!("{}.html", name));
+    fs::write(&path, board_to_html(board))?;
+    Ok(path)
+}
+
+/// Where "Capture Board" writes its image: ./saves/board.png
+pub fn board_capture_path() -> Result<PathBuf> {
+    Ok(ensure_saves_dir()?.join("board.png"))
+}
+
+/// Encode `image` as a PNG at `path`
+pub fn save_png(path: &Path, image: &eframe::egui::ColorImage) -> Result<()> {
+    let [width, height] = image.size;
+    let rgba = image::RgbaImage::from_raw(width as u32, height as u32, image.as_raw().to_vec())
+        .ok_or_else(|| anyhow::anyhow!("image buffer doesn't match its size"))?;
+    rgba.save_with_format(path, image::ImageFormat::Png)?;
+    Ok(())
+}
+
+/// Where the settings window exports to and imports from: ./saves/settings.json
+pub fn settings_path() -> Result<PathBuf> {
+    Ok(ensure_saves_dir()?.join("settings.json"))
+}
+
+pub fn save_settings(path: &Path, settings: &AppSettings) -> Result<()> {
+    let json = serde_json::to_string_pretty(settings)?;
+    fs::write(path, json)?;
+    Ok(())
+}
+
+/// Read exported settings; anything the file leaves out keeps its default
+pub fn load_settings(path: &Path) -> Result<AppSettings> {
+    let data = fs::read_to_string(path)?;
+    let settings: AppSettings = serde_json::from_str(&data)?;
+    Ok(settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::GameAction;
+    use crate::game::events::GameEvent;
+
+    #[test]
+    fn test_snapshot_mid_animation_loads_settled_with_events_intact() {
+        let mut engine = GameEngine::new(Board::default_with_dimensions(2, 2));
+        let state = engine.get_state_mut();
+        state.event_state.activate_event(GameEvent::DoublePoints);
+        state.event_state.queue_event(GameEvent::ReverseQuestion);
+        state.event_state.set_animation_playing(true);
+
+        let json = serde_json::to_string(&Snapshot::of_game(&engine)).unwrap();
+        let loaded: Snapshot = serde_json::from_str(&json).unwrap();
+        let game = loaded.game.unwrap();
+
+        assert!(!game.event_state.is_animation_playing());
+        assert_eq!(game.event_state.active_event, Some(GameEvent::DoublePoints));
+        assert_eq!(
+            game.event_state.queued_event,
+            Some(GameEvent::ReverseQuestion)
+        );
+        // The live game itself is left alone
+        assert!(engine.get_state().event_state.is_animation_playing());
+    }
+
+    #[test]
+    fn test_snapshot_round_trips_custom_rules_and_enforces_them() {
+        let mut engine = GameEngine::new(Board::default_with_dimensions(2, 3));
+        for name in ["A", "B"] {
+            let _ = engine.handle_action(GameAction::AddTeam {
+                name: name.to_string(),
+            });
+        }
+        engine.rules_mut().max_teams = 2;
+        engine.rules_mut().require_ascending_selection = true;
+        engine.rules_mut().set_steal_points_factor(0.5);
+        engine.event_config_mut().no_event_weight = 40;
+
+        let json = serde_json::to_string(&Snapshot::of_game(&engine)).unwrap();
+        let snapshot: Snapshot = serde_json::from_str(&json).unwrap();
+        let mut loaded = snapshot.into_engine().unwrap();
+
+        assert_eq!(loaded.rules().max_teams, 2);
+        assert_eq!(loaded.rules().steal_points_factor, 0.5);
+        assert_eq!(loaded.event_config().no_event_weight, 40);
+        // The team cap still holds after loading
+        assert!(
+            loaded
+                .handle_action(GameAction::AddTeam {
+                    name: "C".to_string(),
+                })
+                .is_err()
+        );
+        // And so does ascending selection
+        let _ = loaded.handle_action(GameAction::StartGame);
+        let team_id = loaded.get_state().active_team;
+        assert!(
+            loaded
+                .handle_action(GameAction::SelectClue {
+                    clue: (0, 2),
+                    team_id,
+                })
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_old_snapshot_without_rules_loads_defaults() {
+        let board = serde_json::to_value(Board::default_with_dimensions(1, 1)).unwrap();
+        let json = serde_json::json!({ "board": board, "game": null }).to_string();
+
+        let snapshot: Snapshot = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(snapshot.rules.max_teams, GameRules::default().max_teams);
+        assert!(!snapshot.rules.require_ascending_selection);
+        assert_eq!(
+            snapshot.events.trigger_interval,
+            EventConfig::default().trigger_interval
+        );
+        assert!(snapshot.into_engine().is_none());
+    }
+
+    #[test]
+    fn test_board_to_html_lists_categories_and_escapes_text() {
+        let mut board = Board::default_with_dimensions(2, 2);
+        board.categories[0].name = "Science".to_string();
+        board.categories[1].name = "History".to_string();
+        board.categories[1].clues[1].question = "Is 1 < 2 & 3 > 2?".to_string();
+        board.categories[1].clues[1].answer = "Yes".to_string();
+
+        let html = board_to_html(&board);
+
+        assert!(html.contains("<th>Science</th>"));
+        assert!(html.contains("<th>History</th>"));
+        assert!(html.contains("Is 1 &lt; 2 &amp; 3 &gt; 2?"));
+        assert!(!html.contains("1 < 2"));
+        assert_eq!(html.matches("<tr>").count(), 3);
+    }
+
+    #[test]
+    fn test_snapshot_round_trips_team_metadata_and_stats_exactly() {
+        let mut engine = GameEngine::new(Board::default_with_dimensions(3, 3));
+        for name in ["Crabs", "Octopi", "Plain"] {
+            let _ = engine.handle_action(GameAction::AddTeam {
+                name: name.to_string(),
+            });
+        }
+        let state = engine.get_state_mut();
+        assert!(state.teams[0].set_emoji(Some("🦀")));
+        assert!(state.teams[1].set_emoji(Some("🐙")));
+        let ids: Vec<u32> = state.teams.iter().map(|t| t.id).collect();
+        let _ = engine.handle_action(GameAction::SetStartingScore {
+            team_id: ids[2],
+            score: 150,
+        });
+        let _ = engine.handle_action(GameAction::StartGame);
+
+        // A few clues in: one right, one stolen, and a clue left open on screen
+        for (clue, correct) in [((0, 0), true), ((1, 0), false)] {
+            let team_id = engine.get_state().active_team;
+            let _ = engine.handle_action(GameAction::SelectClue { clue, team_id });
+            let action = if correct {
+                GameAction::AnswerCorrect { clue, team_id }
+            } else {
+                GameAction::AnswerIncorrect { clue, team_id }
+            };
+            let _ = engine.handle_action(action);
+            if let crate::game::PlayPhase::Steal { current, .. } = *engine.get_phase() {
+                let _ = engine.handle_action(GameAction::StealAttempt {
+                    clue,
+                    team_id: current,
+                    correct: true,
+                });
+            }
+            if let crate::game::PlayPhase::Resolved { next_team_id, .. } = *engine.get_phase() {
+                let _ = engine.handle_action(GameAction::CloseClue { clue, next_team_id });
+            }
+        }
+        engine
+            .get_state_mut()
+            .event_state
+            .activate_event(GameEvent::DoublePoints);
+        let team_id = engine.get_state().active_team;
+        let _ = engine.handle_action(GameAction::SelectClue {
+            clue: (2, 0),
+            team_id,
+        });
+        assert!(engine.get_state().score_log.len() >= 3);
+
+        // Through a real file, the way the save and load dialogs go
+        let dir = std::env::temp_dir().join(format!("round_trip_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("mid_game.json");
+        let json = serde_json::to_string_pretty(&Snapshot::of_game(&engine)).unwrap();
+        fs::write(&path, json).unwrap();
+        let loaded = load_snapshot_from_path(&path)
+            .unwrap()
+            .into_engine()
+            .unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let as_value = |engine: &GameEngine| serde_json::to_value(engine.get_state()).unwrap();
+        assert_eq!(as_value(&loaded), as_value(&engine));
+        let labels: Vec<String> = loaded.get_state().teams.iter().map(|t| t.label()).collect();
+        assert_eq!(labels, vec!["🦀 Crabs", "🐙 Octopi", "Plain"]);
+        assert_eq!(loaded.get_team_score(ids[2]), engine.get_team_score(ids[2]));
+        assert_eq!(loaded.get_state().score_log, engine.get_state().score_log);
+    }
+
+    #[test]
+    fn test_startup_board_present_absent_or_invalid() {
+        let dir = std::env::temp_dir().join(format!("startup_board_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        // Absent: fall back to the default board
+        let missing = dir.join("missing.json");
+        assert!(load_startup_board(&missing).unwrap().is_none());
+
+        // Present: a bare board, or the board out of a saved snapshot
+        let mut board = Board::default_with_dimensions(3, 2);
+        board.categories[0].name = "Preloaded".to_string();
+        let bare = dir.join("bare.json");
+        fs::write(&bare, serde_json::to_string(&board).unwrap()).unwrap();
+        let loaded = load_startup_board(&bare).unwrap().unwrap();
+        assert_eq!(loaded.categories.len(), 3);
+        assert_eq!(loaded.categories[0].name, "Preloaded");
+
+        let saved = dir.join("saved.json");
+        let snapshot = Snapshot::of_game(&GameEngine::new(board));
+        fs::write(&saved, serde_json::to_string(&snapshot).unwrap()).unwrap();
+        let loaded = load_startup_board(&saved).unwrap().unwrap();
+        assert_eq!(loaded.categories[0].name, "Preloaded");
+
+        // Invalid: reported so the caller can fall back and tell the host
+        let garbled = dir.join("garbled.json");
+        fs::write(&garbled, "{ not json").unwrap();
+        assert!(load_startup_board(&garbled).is_err());
+        let empty = dir.join("empty.json");
+        fs::write(&empty, r#"{"categories":[]}"#).unwrap();
+        assert!(load_startup_board(&empty).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_settings_round_trip_through_file() {
+        use crate::app::settings::{PanelPosition, TeamNaming};
+        use crate::theme::ContrastMode;
+
+        let mut settings = AppSettings {
+            steal_timer_secs: Some(15),
+            typewriter_reveal: true,
+            team_panel_position: PanelPosition::Bottom,
+            contrast: ContrastMode::High,
+            team_name_template: TeamNaming::FromList(vec!["Red".into(), "Blue".into()]),
+            ..AppSettings::default()
+        };
+        settings.board_style.grid_gap = 20.0;
+        settings.rules.max_teams = 3;
+        settings.events.no_event_weight = 40;
+
+        let dir = std::env::temp_dir().join(format!("settings_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("settings.json");
+        save_settings(&path, &settings).unwrap();
+        let loaded = load_settings(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(&settings).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_partial_settings_fill_in_defaults() {
+        let json = r#"{ "typewriter_reveal": true, "rules": { "max_teams": 3 } }"#;
+        let settings: AppSettings = serde_json::from_str(json).unwrap();
+        assert!(settings.typewriter_reveal);
+        assert_eq!(settings.rules.max_teams, 3);
+
+        // Everything an older export never had comes back as the defaults
+        let defaults = AppSettings::default();
+        assert_eq!(settings.steal_timer_secs, None);
+        assert_eq!(settings.team_name_template, defaults.team_name_template);
+        assert_eq!(settings.board_style, defaults.board_style);
+        assert_eq!(
+            settings.rules.steal_points_factor,
+            defaults.rules.steal_points_factor
+        );
+        assert_eq!(
+            serde_json::to_value(&settings.events).unwrap(),
+            serde_json::to_value(&defaults.events).unwrap()
+        );
+    }
+}
@@ -1,8 +1,14 @@
 use crate::core::Team;
-use crate::game::events::{EventAnimationType, EventError, GameEvent, StealEventContext};
-use crate::game::rules::GameRules;
+use crate::game::events::{
+    EventAnimationType, EventConfig, EventError, EventTiming, GameEvent, StealDirection,
+    StealEventContext,
+};
+use crate::game::rules::{FirstTeamPolicy, GameRules};
 use crate::game::scoring::ScoringEngine;
-use crate::game::state::{GameState, PlayPhase};
+use crate::game::state::{GameState, PlayPhase, ScoreLogEntry};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
 
 /// Utility function to determine question value from clue coordinates
 fn get_question_points(state: &GameState, clue: (usize, usize)) -> u32 {
@@ -15,9 +21,9 @@ fn get_question_points(state: &GameState, clue: (usize, usize)) -> u32 {
         .unwrap_or(0)
 }
 
-/// Determine max attempts based on question value
-fn calculate_max_attempts(points: u32) -> u32 {
-    if points > 500 { 2 } else { 1 }
+/// Determine max attempts based on question value; a bonus clue always gets two
+fn calculate_max_attempts(points: u32, bonus_attempt: bool) -> u32 {
+    if bonus_attempt || points > 500 { 2 } else { 1 }
 }
 
 #[derive(Debug, Clone)]
@@ -30,6 +36,7 @@ pub enum GameAction {
         clue: (usize, usize),
         team_id: u32,
     },
+    SelectionTimeout,
     AnswerCorrect {
         clue: (usize, usize),
         team_id: u32,
@@ -43,6 +50,13 @@ pub enum GameAction {
         team_id: u32,
         correct: bool,
     },
+    /// The current stealer's clock ran out; counts as a missed steal
+    StealTimeout,
+    /// A team still waiting to steal hit its buzzer; it answers next and the
+    /// team it jumped goes back to the head of the queue
+    BuzzIn {
+        team_id: u32,
+    },
     CloseClue {
         clue: (usize, usize),
         next_team_id: u32,
@@ -63,6 +77,43 @@ pub enum GameAction {
         team_id: u32,
         new_points: i32,
     },
+    /// Break to the intermission standings screen
+    Pause,
+    /// Leave intermission and hand selection back to the active team
+    Resume,
+    /// Leave intermission into the next round, unlocking clues gated to it
+    NextRound,
+    /// Give a team a handicap before the game starts
+    SetStartingScore {
+        team_id: u32,
+        score: i32,
+    },
+    /// Give a clue every stealer missed to the audience; no team scores or loses its turn
+    AudienceClaim {
+        clue: (usize, usize),
+    },
+    /// Remove every team while still in the lobby
+    ClearTeams,
+    /// Remove the most recently added team while still in the lobby
+    RemoveLastTeam,
+    /// Randomize play order while still in the lobby
+    ShuffleTeams,
+    /// Close the open clue with a host-typed note instead of judging it; nobody scores
+    CaptureNote {
+        clue: (usize, usize),
+        note: String,
+    },
+    /// Lock in a team's final wager once the board is finished; a team with
+    /// nothing to risk is held to 0
+    SubmitWager {
+        team_id: u32,
+        wager: i32,
+    },
+    /// Judge a team's final answer: its wager is won or lost
+    ResolveWager {
+        team_id: u32,
+        correct: bool,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -125,10 +176,22 @@ pub enum GameError {
     EventError(EventError),
 }
 
+impl std::fmt::Display for GameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameError::InvalidAction { reason, .. } => write!(f, "{}", reason),
+            GameError::EventError(err) => write!(f, "{}", err),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct GameActionHandler {
     rules: GameRules,
     scoring: ScoringEngine,
+    event_config: EventConfig,
+    /// Source of randomness for game decisions; reseed for reproducible games
+    rng: StdRng,
 }
 
 impl GameActionHandler {
@@ -136,11 +199,33 @@ impl GameActionHandler {
         Self {
             rules: GameRules::new(),
             scoring: ScoringEngine::new(),
+            event_config: EventConfig::new(),
+            rng: StdRng::from_entropy(),
         }
     }
 
+    pub fn seed_rng(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    pub fn rules(&self) -> &GameRules {
+        &self.rules
+    }
+
+    pub fn rules_mut(&mut self) -> &mut GameRules {
+        &mut self.rules
+    }
+
+    pub fn event_config(&self) -> &EventConfig {
+        &self.event_config
+    }
+
+    pub fn event_config_mut(&mut self) -> &mut EventConfig {
+        &mut self.event_config
+    }
+
     pub fn handle(
-        &self,
+        &mut self,
         state: &mut crate::game::state::GameState,
         action: GameAction,
     ) -> Result<GameActionResult, GameError> {
@@ -150,6 +235,7 @@ impl GameActionHandler {
             GameAction::SelectClue { clue, team_id } => {
                 self.handle_select_clue(state, clue, team_id)
             }
+            GameAction::SelectionTimeout => self.handle_selection_timeout(state),
             GameAction::AnswerCorrect { clue, team_id } => {
                 self.handle_answer_correct(state, clue, team_id)
             }
@@ -161,6 +247,8 @@ impl GameActionHandler {
                 team_id,
                 correct,
             } => self.handle_steal_attempt(state, clue, team_id, correct),
+            GameAction::StealTimeout => self.handle_steal_timeout(state),
+            GameAction::BuzzIn { team_id } => self.handle_buzz_in(state, team_id),
             GameAction::CloseClue { clue, next_team_id } => {
                 self.handle_close_clue(state, clue, next_team_id)
             }
@@ -176,6 +264,23 @@ impl GameActionHandler {
                 team_id,
                 new_points,
             } => self.handle_manual_points_adjustment(state, team_id, new_points),
+            GameAction::Pause => self.handle_pause(state),
+            GameAction::Resume => self.handle_resume(state),
+            GameAction::NextRound => self.handle_next_round(state),
+            GameAction::AudienceClaim { clue } => self.handle_audience_claim(state, clue),
+            GameAction::ClearTeams => self.handle_clear_teams(state),
+            GameAction::RemoveLastTeam => self.handle_remove_last_team(state),
+            GameAction::ShuffleTeams => self.handle_shuffle_teams(state),
+            GameAction::SetStartingScore { team_id, score } => {
+                self.handle_set_starting_score(state, team_id, score)
+            }
+            GameAction::CaptureNote { clue, note } => self.handle_capture_note(state, clue, note),
+            GameAction::SubmitWager { team_id, wager } => {
+                self.handle_submit_wager(state, team_id, wager)
+            }
+            GameAction::ResolveWager { team_id, correct } => {
+                self.handle_resolve_wager(state, team_id, correct)
+            }
         }
     }
 
@@ -185,9 +290,14 @@ impl GameActionHandler {
         name: String,
     ) -> Result<GameActionResult, GameError> {
         if !self.rules.can_add_team(state) {
+            let reason = if matches!(state.phase, PlayPhase::Lobby) {
+                format!("Team limit of {} reached", self.rules.max_teams)
+            } else {
+                "Can only add teams in lobby phase".to_string()
+            };
             return Err(GameError::InvalidAction {
                 action: "AddTeam".to_string(),
-                reason: "Can only add teams in lobby phase".to_string(),
+                reason,
             });
         }
 
@@ -201,7 +311,7 @@ impl GameActionHandler {
     }
 
     fn handle_start_game(
-        &self,
+        &mut self,
         state: &mut crate::game::state::GameState,
     ) -> Result<GameActionResult, GameError> {
         if !self.rules.can_start_game(state) {
@@ -211,7 +321,25 @@ impl GameActionHandler {
             });
         }
 
-        let first_team_id = state.teams[0].id;
+        let first_team_id = match self.rules.first_team {
+            FirstTeamPolicy::FirstAdded => state.teams[0].id,
+            FirstTeamPolicy::Random => {
+                let index = self.rng.gen_range(0..state.teams.len());
+                state.teams[index].id
+            }
+            // Fall back to the first team if the chosen one has since been removed
+            FirstTeamPolicy::Specific(team_id) => state
+                .teams
+                .iter()
+                .find(|t| t.id == team_id)
+                .map(|t| t.id)
+                .unwrap_or(state.teams[0].id),
+        };
+        if self.rules.shuffle_categories {
+            state.board.categories.shuffle(&mut self.rng);
+        }
+        state.starting_scores = state.teams.iter().map(|t| (t.id, t.score)).collect();
+        self.draw_bonus_attempts(state);
         state.active_team = first_team_id;
         let new_phase = PlayPhase::Selecting {
             team_id: first_team_id,
@@ -221,6 +349,25 @@ impl GameActionHandler {
         Ok(GameActionResult::Success { new_phase })
     }
 
+    /// Flag `bonus_attempt_clues` random clues for a second attempt, clearing any
+    /// left over from an earlier game
+    fn draw_bonus_attempts(&mut self, state: &mut crate::game::state::GameState) {
+        let clues = state
+            .board
+            .categories
+            .iter_mut()
+            .flat_map(|c| c.clues.iter_mut());
+        let mut clues: Vec<&mut crate::core::Clue> = clues.collect();
+        for clue in clues.iter_mut() {
+            clue.bonus_attempt = false;
+        }
+        let count = self.rules.bonus_attempt_clues.min(clues.len());
+        let (picked, _) = clues.partial_shuffle(&mut self.rng, count);
+        for clue in picked {
+            clue.bonus_attempt = true;
+        }
+    }
+
     fn handle_select_clue(
         &self,
         state: &mut crate::game::state::GameState,
@@ -234,6 +381,12 @@ impl GameActionHandler {
                 reason: "Invalid clue selection or wrong team".to_string(),
             });
         }
+        if !self.rules.is_selection_in_order(state, clue) {
+            return Err(GameError::InvalidAction {
+                action: "SelectClue".to_string(),
+                reason: "A lower-value clue in this category must be picked first".to_string(),
+            });
+        }
 
         let mut effects = Vec::new();
 
@@ -251,8 +404,11 @@ impl GameActionHandler {
             }
         }
 
+        state.selection_deadline = None;
+
         let points = get_question_points(state, clue);
-        let max_attempts = calculate_max_attempts(points);
+        let bonus_attempt = state.get_clue(clue).is_some_and(|c| c.bonus_attempt);
+        let max_attempts = calculate_max_attempts(points, bonus_attempt);
 
         let new_phase = PlayPhase::Showing {
             clue,
@@ -269,9 +425,36 @@ impl GameActionHandler {
         }
     }
 
-    fn handle_answer_correct(
+    fn handle_selection_timeout(
         &self,
         state: &mut crate::game::state::GameState,
+    ) -> Result<GameActionResult, GameError> {
+        let action = GameAction::SelectionTimeout;
+        if !self.rules.is_action_valid(state, &action) {
+            return Err(GameError::InvalidAction {
+                action: "SelectionTimeout".to_string(),
+                reason: "Selection can only time out in selecting phase".to_string(),
+            });
+        }
+
+        // Hand control to the next team and let the UI arm a fresh clock for them
+        let next_team_id = self
+            .scoring
+            .rotate_active_team(&state.teams, state.active_team);
+        state.active_team = next_team_id;
+        state.selection_deadline = None;
+
+        let new_phase = PlayPhase::Selecting {
+            team_id: next_team_id,
+        };
+        state.phase = new_phase.clone();
+
+        Ok(GameActionResult::Success { new_phase })
+    }
+
+    fn handle_answer_correct(
+        &mut self,
+        state: &mut crate::game::state::GameState,
         clue: (usize, usize),
         team_id: u32,
     ) -> Result<GameActionResult, GameError> {
@@ -284,6 +467,7 @@ impl GameActionHandler {
         }
 
         let mut effects = Vec::new();
+        let sign = category_sign(state, clue);
 
         // Mark clue as revealed and solved
         if let Some(category) = state.board.categories.get_mut(clue.0) {
@@ -293,16 +477,22 @@ impl GameActionHandler {
                 effects.push(GameEffect::ClueRevealed { clue });
                 effects.push(GameEffect::ClueSolved { clue });
 
-                // Calculate points (double if Double Points event is active)
-                let points = if state.event_state.is_event_active(&GameEvent::DoublePoints) {
-                    use crate::game::events::DoublePointsEvent;
-                    DoublePointsEvent::calculate_points(c.points) as i32
-                } else {
-                    c.points as i32
-                };
+                // Calculate points (double if Double Points event is active, scaled
+                // while Reverse Question is; negative in an inverted category)
+                let points = self.rules.effective_points(
+                    c.points,
+                    state.event_state.is_event_active(&GameEvent::DoublePoints),
+                    state
+                        .event_state
+                        .is_event_active(&GameEvent::ReverseQuestion),
+                    false,
+                ) * sign;
 
                 // Award points to team
                 if self.scoring.award_points(&mut state.teams, team_id, points) {
+                    state
+                        .score_log
+                        .push(ScoreLogEntry::new(team_id, points, Some(clue)));
                     effects.push(GameEffect::ScoreChanged {
                         team_id,
                         delta: points,
@@ -330,12 +520,18 @@ impl GameActionHandler {
             effect_type: FlashType::Correct,
         });
 
-        // Always rotate the selecting team after a question resolves
-        let next_team_id = self
-            .scoring
-            .rotate_active_team(&state.teams, state.active_team);
+        // Rotate the selecting team after a question resolves, unless a correct
+        // answer lets the team keep control
+        let next_team_id = if self.rules.keep_turn_on_correct {
+            state.active_team
+        } else {
+            self.scoring
+                .rotate_active_team(&state.teams, state.active_team)
+        };
         state.active_team = next_team_id;
 
+        self.roll_on_resolve(state, &mut effects);
+
         let new_phase = PlayPhase::Resolved { clue, next_team_id };
         state.phase = new_phase.clone();
 
@@ -402,24 +598,33 @@ impl GameActionHandler {
         mut effects: Vec<GameEffect>,
     ) -> Result<GameActionResult, GameError> {
         // Deduct points from team (double penalty if Double Points event is active)
-        if let Some(category) = state.board.categories.get(clue.0) {
-            if let Some(c) = category.clues.get(clue.1) {
-                let penalty = if state.event_state.is_event_active(&GameEvent::DoublePoints) {
-                    use crate::game::events::DoublePointsEvent;
-                    DoublePointsEvent::calculate_penalty(c.points)
-                } else {
-                    c.points as i32
-                };
+        let mut owner_penalty = 0;
+        if let Some(penalty) = incorrect_answer_penalty(&self.rules, state, clue) {
+            let deducted = self
+                .scoring
+                .deduct_points(&mut state.teams, team_id, penalty);
+            if deducted {
+                owner_penalty = penalty;
+                state.log_score_change(team_id, -penalty, Some(clue));
+                effects.push(GameEffect::ScoreChanged {
+                    team_id,
+                    delta: -penalty,
+                });
+            }
+        }
 
-                if self
-                    .scoring
-                    .deduct_points(&mut state.teams, team_id, penalty)
-                {
-                    effects.push(GameEffect::ScoreChanged {
-                        team_id,
-                        delta: -penalty,
-                    });
-                }
+        // Something back for having a go; applied separately so the log shows both
+        if self.rules.consolation_points > 0 {
+            let consolation = self.rules.consolation_points as i32;
+            let awarded = self
+                .scoring
+                .award_points(&mut state.teams, team_id, consolation);
+            if awarded {
+                state.log_score_change(team_id, consolation, Some(clue));
+                effects.push(GameEffect::ScoreChanged {
+                    team_id,
+                    delta: consolation,
+                });
             }
         }
 
@@ -432,6 +637,8 @@ impl GameActionHandler {
             queue,
             current,
             owner_team_id: team_id,
+            attempted: vec![team_id],
+            owner_penalty,
         };
         state.phase = new_phase.clone();
 
@@ -439,7 +646,7 @@ impl GameActionHandler {
     }
 
     fn handle_steal_attempt(
-        &self,
+        &mut self,
         state: &mut crate::game::state::GameState,
         clue: (usize, usize),
         team_id: u32,
@@ -457,35 +664,53 @@ impl GameActionHandler {
             });
         }
 
+        // Whoever steals next gets a fresh clock
+        state.steal_deadline = None;
+
         if let PlayPhase::Steal {
             queue,
             current,
-            owner_team_id: _,
+            owner_team_id,
+            attempted,
+            owner_penalty,
             ..
         } = &mut state.phase
         {
+            if !attempted.contains(&team_id) {
+                attempted.push(team_id);
+            }
+            let owner_team_id = *owner_team_id;
+            let owner_penalty = *owner_penalty;
             let mut effects = Vec::new();
 
             if correct {
                 // Mark clue as revealed and solved
                 if let Some(category) = state.board.categories.get_mut(clue.0) {
+                    let sign = if category.inverted { -1 } else { 1 };
                     if let Some(c) = category.clues.get_mut(clue.1) {
                         c.revealed = true;
                         c.solved = true;
                         effects.push(GameEffect::ClueRevealed { clue });
                         effects.push(GameEffect::ClueSolved { clue });
 
-                        // Calculate points (double if Double Points event is active)
-                        let points = if state.event_state.is_event_active(&GameEvent::DoublePoints)
-                        {
-                            use crate::game::events::DoublePointsEvent;
-                            DoublePointsEvent::calculate_points(c.points) as i32
-                        } else {
-                            c.points as i32
-                        };
+                        // Calculate points (double if Double Points event is active and
+                        // applies to steals, scaled while Reverse Question is, then by the
+                        // steal factor; negative in an inverted category)
+                        let double_points =
+                            state.event_state.is_event_active(&GameEvent::DoublePoints);
+                        let reverse = state
+                            .event_state
+                            .is_event_active(&GameEvent::ReverseQuestion);
+                        let points =
+                            self.rules
+                                .effective_points(c.points, double_points, reverse, true)
+                                * sign;
 
                         // Award points to stealing team
                         if self.scoring.award_points(&mut state.teams, team_id, points) {
+                            state
+                                .score_log
+                                .push(ScoreLogEntry::new(team_id, points, Some(clue)));
                             effects.push(GameEffect::ScoreChanged {
                                 team_id,
                                 delta: points,
@@ -513,12 +738,18 @@ impl GameActionHandler {
                     effect_type: FlashType::Correct,
                 });
 
-                // Always rotate the selecting team after a question resolves
-                let next_team_id = self
-                    .scoring
-                    .rotate_active_team(&state.teams, state.active_team);
+                // Rotate the selecting team after a question resolves, unless the
+                // stealer takes control
+                let next_team_id = if self.rules.control_to_stealer {
+                    team_id
+                } else {
+                    self.scoring
+                        .rotate_active_team(&state.teams, state.active_team)
+                };
                 state.active_team = next_team_id;
 
+                self.roll_on_resolve(state, &mut effects);
+
                 let new_phase = PlayPhase::Resolved { clue, next_team_id };
                 state.phase = new_phase.clone();
 
@@ -528,6 +759,8 @@ impl GameActionHandler {
                     effect_type: FlashType::Incorrect,
                 });
 
+                // Never hand the clue back to a team that already tried it
+                queue.retain(|id| !attempted.contains(id));
                 if let Some(next_team) = queue.pop_front() {
                     *current = next_team;
                     Ok(GameActionResult::StateChanged {
@@ -535,6 +768,23 @@ impl GameActionHandler {
                         effects,
                     })
                 } else {
+                    // Nobody could steal it: optionally hand the owner back exactly what
+                    // was taken for the miss, whatever events came and went since
+                    let refund = Some(owner_penalty)
+                        .filter(|&p| p != 0 && self.rules.refund_owner_on_failed_steals);
+                    if let Some(refund) = refund {
+                        let refunded =
+                            self.scoring
+                                .award_points(&mut state.teams, owner_team_id, refund);
+                        if refunded {
+                            state.log_score_change(owner_team_id, refund, Some(clue));
+                            effects.push(GameEffect::ScoreChanged {
+                                team_id: owner_team_id,
+                                delta: refund,
+                            });
+                        }
+                    }
+
                     // No more teams, mark clue as solved without points
                     if let Some(category) = state.board.categories.get_mut(clue.0) {
                         if let Some(c) = category.clues.get_mut(clue.1) {
@@ -553,12 +803,18 @@ impl GameActionHandler {
                         }
                     }
 
+                    if self.rules.audience_claims {
+                        state.audience_claimable = Some(clue);
+                    }
+
                     // No successful stealers; still rotate the selecting team
                     let next_team_id = self
                         .scoring
                         .rotate_active_team(&state.teams, state.active_team);
                     state.active_team = next_team_id;
 
+                    self.roll_on_resolve(state, &mut effects);
+
                     let new_phase = PlayPhase::Resolved { clue, next_team_id };
                     state.phase = new_phase.clone();
 
@@ -573,9 +829,98 @@ impl GameActionHandler {
         }
     }
 
-    fn handle_close_clue(
+    fn handle_steal_timeout(
+        &mut self,
+        state: &mut crate::game::state::GameState,
+    ) -> Result<GameActionResult, GameError> {
+        let PlayPhase::Steal { clue, current, .. } = state.phase else {
+            return Err(GameError::InvalidAction {
+                action: "StealTimeout".to_string(),
+                reason: "Steal can only time out in steal phase".to_string(),
+            });
+        };
+        self.handle_steal_attempt(state, clue, current, false)
+    }
+
+    fn handle_buzz_in(
         &self,
         state: &mut crate::game::state::GameState,
+        team_id: u32,
+    ) -> Result<GameActionResult, GameError> {
+        if !self
+            .rules
+            .is_action_valid(state, &GameAction::BuzzIn { team_id })
+        {
+            return Err(GameError::InvalidAction {
+                action: "BuzzIn".to_string(),
+                reason: "Only a team waiting to steal can buzz in".to_string(),
+            });
+        }
+
+        if let PlayPhase::Steal { queue, current, .. } = &mut state.phase {
+            queue.retain(|id| *id != team_id);
+            queue.push_front(*current);
+            *current = team_id;
+        }
+        // The team that buzzed gets a fresh clock
+        state.steal_deadline = None;
+        Ok(GameActionResult::StateChanged {
+            new_phase: state.phase.clone(),
+            effects: Vec::new(),
+        })
+    }
+
+    /// Roll for an event as a clue resolves, when the config asks for it then. The
+    /// last clue of the board is exempt, so the final standings can't be rewritten
+    /// right before the game ends.
+    fn roll_on_resolve(
+        &mut self,
+        state: &mut crate::game::state::GameState,
+        effects: &mut Vec<GameEffect>,
+    ) {
+        if self.event_config.timing == EventTiming::OnResolve && !state.all_clues_solved() {
+            self.roll_for_event(state, effects);
+        }
+    }
+
+    /// Count a finished question toward the event interval and, when it's due,
+    /// draw and queue an event, applying Hard Reset and Score Steal right away
+    fn roll_for_event(
+        &mut self,
+        state: &mut crate::game::state::GameState,
+        effects: &mut Vec<GameEffect>,
+    ) {
+        state.event_state.increment_question_count();
+        if !state
+            .event_state
+            .should_trigger_every(self.event_config.trigger_interval)
+        {
+            return;
+        }
+        let drawn = self
+            .event_config
+            .get_random_event(&state.event_state.event_history);
+        if let Some(event) = drawn {
+            // Queue the event for animation during transition
+            state.event_state.queue_event(event.clone());
+
+            // Apply immediate effects for Hard Reset
+            if matches!(event, GameEvent::HardReset) {
+                // Reset all team scores immediately
+                apply_hard_reset(state);
+                effects.push(GameEffect::ScoreReset);
+            } else if matches!(event, GameEvent::ScoreSteal) {
+                // Apply score steal immediately and store context
+                effects.extend(apply_score_steal(state, &self.event_config, &mut self.rng));
+            }
+
+            effects.push(GameEffect::EventQueued { event });
+        }
+    }
+
+    fn handle_close_clue(
+        &mut self,
+        state: &mut crate::game::state::GameState,
         clue: (usize, usize),
         next_team_id: u32,
     ) -> Result<GameActionResult, GameError> {
@@ -587,69 +932,24 @@ impl GameActionHandler {
             });
         }
 
-        // Increment question count for event system
-        state.event_state.increment_question_count();
-
-        let mut effects = Vec::new();
+        state.audience_claimable = None;
 
-        // Check if an event should be triggered
-        if state.event_state.should_trigger_event() {
-            // Select a random event
-            use crate::game::events::EventConfig;
-            let config = EventConfig::default();
-
-            if let Some(event) = config.get_random_event() {
-                // Queue the event for animation during transition
-                state.event_state.queue_event(event.clone());
-
-                // Apply immediate effects for Hard Reset
-                if matches!(event, GameEvent::HardReset) {
-                    // Reset all team scores immediately
-                    for team in &mut state.teams {
-                        team.score = 0;
-                    }
-                    effects.push(GameEffect::ScoreReset);
-                } else if matches!(event, GameEvent::ScoreSteal) {
-                    // Apply score steal immediately and store context
-                    if let Some((thief_idx, victim_idx)) =
-                        lowest_and_highest_team_indices(&state.teams)
-                    {
-                        let (thief, victim) = {
-                            let (left, right) = state.teams.split_at_mut(victim_idx.max(thief_idx));
-                            if thief_idx < victim_idx {
-                                (&mut left[thief_idx], &mut right[0])
-                            } else {
-                                (&mut right[0], &mut left[victim_idx])
-                            }
-                        };
-                        let amount = ((victim.score as f32) * 0.20).floor() as i32;
-                        let amount = amount.max(0);
-                        victim.score = victim.score.saturating_sub(amount);
-                        thief.score = thief.score.saturating_add(amount);
-                        // Save context for UI
-                        state.event_state.last_steal = Some(StealEventContext {
-                            thief_id: thief.id,
-                            thief_name: thief.name.clone(),
-                            victim_id: victim.id,
-                            victim_name: victim.name.clone(),
-                            amount,
-                        });
-                        effects.push(GameEffect::ScoreChanged {
-                            team_id: victim.id,
-                            delta: -amount,
-                        });
-                        effects.push(GameEffect::ScoreChanged {
-                            team_id: thief.id,
-                            delta: amount,
-                        });
-                        effects.push(GameEffect::ScoreStealApplied {
-                            context: state.event_state.last_steal.clone().unwrap(),
-                        });
-                    }
-                }
+        // Nothing left to pick this round: break for the next one, or end the game
+        // once the whole board is done
+        if state.get_available_clues().is_empty() {
+            state.selection_deadline = None;
+            let new_phase = if state.all_clues_solved() {
+                PlayPhase::Finished
+            } else {
+                PlayPhase::Intermission
+            };
+            state.phase = new_phase.clone();
+            return Ok(GameActionResult::Success { new_phase });
+        }
 
-                effects.push(GameEffect::EventQueued { event });
-            }
+        let mut effects = Vec::new();
+        if self.event_config.timing == EventTiming::OnClose {
+            self.roll_for_event(state, &mut effects);
         }
 
         let new_phase = PlayPhase::Selecting {
@@ -664,6 +964,31 @@ impl GameActionHandler {
         }
     }
 
+    fn handle_audience_claim(
+        &self,
+        state: &mut crate::game::state::GameState,
+        clue: (usize, usize),
+    ) -> Result<GameActionResult, GameError> {
+        let action = GameAction::AudienceClaim { clue };
+        if !self.rules.is_action_valid(state, &action) {
+            return Err(GameError::InvalidAction {
+                action: "AudienceClaim".to_string(),
+                reason: "The audience can only claim a clue every stealer missed".to_string(),
+            });
+        }
+
+        // The last missed steal already solved the clue; the claim only banks it
+        state.audience_claimable = None;
+        if self.rules.audience_claim_scores {
+            let points = state.get_clue(clue).map_or(0, |c| c.points as i32);
+            state.audience_score = state.audience_score.saturating_add(points);
+        }
+
+        Ok(GameActionResult::Success {
+            new_phase: state.phase.clone(),
+        })
+    }
+
     fn handle_queue_event(
         &self,
         state: &mut crate::game::state::GameState,
@@ -679,9 +1004,7 @@ impl GameActionHandler {
         // Apply immediate effects for Hard Reset
         if matches!(event, GameEvent::HardReset) {
             // Reset all team scores immediately
-            for team in &mut state.teams {
-                team.score = 0;
-            }
+            apply_hard_reset(state);
             effects.push(GameEffect::ScoreReset);
         }
 
@@ -720,7 +1043,7 @@ impl GameActionHandler {
     }
 
     fn handle_trigger_event(
-        &self,
+        &mut self,
         state: &mut crate::game::state::GameState,
         event: GameEvent,
     ) -> Result<GameActionResult, GameError> {
@@ -750,9 +1073,7 @@ impl GameActionHandler {
         match event {
             GameEvent::HardReset => {
                 // Reset all team scores immediately
-                for team in &mut state.teams {
-                    team.score = 0;
-                }
+                apply_hard_reset(state);
                 effects.push(GameEffect::ScoreReset);
             }
             GameEvent::DoublePoints => {
@@ -763,39 +1084,7 @@ impl GameActionHandler {
             }
             GameEvent::ScoreSteal => {
                 // Apply immediately when triggered manually too
-                if let Some((thief_idx, victim_idx)) = lowest_and_highest_team_indices(&state.teams)
-                {
-                    let (thief, victim) = {
-                        let (left, right) = state.teams.split_at_mut(victim_idx.max(thief_idx));
-                        if thief_idx < victim_idx {
-                            (&mut left[thief_idx], &mut right[0])
-                        } else {
-                            (&mut right[0], &mut left[victim_idx])
-                        }
-                    };
-                    let amount = ((victim.score as f32) * 0.20).floor() as i32;
-                    let amount = amount.max(0);
-                    victim.score = victim.score.saturating_sub(amount);
-                    thief.score = thief.score.saturating_add(amount);
-                    state.event_state.last_steal = Some(StealEventContext {
-                        thief_id: thief.id,
-                        thief_name: thief.name.clone(),
-                        victim_id: victim.id,
-                        victim_name: victim.name.clone(),
-                        amount,
-                    });
-                    effects.push(GameEffect::ScoreChanged {
-                        team_id: victim.id,
-                        delta: -amount,
-                    });
-                    effects.push(GameEffect::ScoreChanged {
-                        team_id: thief.id,
-                        delta: amount,
-                    });
-                    effects.push(GameEffect::ScoreStealApplied {
-                        context: state.event_state.last_steal.clone().unwrap(),
-                    });
-                }
+                effects.extend(apply_score_steal(state, &self.event_config, &mut self.rng));
             }
         }
 
@@ -811,6 +1100,7 @@ impl GameActionHandler {
     ) -> Result<GameActionResult, GameError> {
         // This is called when the user acknowledges the event announcement
         // The event remains active but the animation phase is complete
+        state.event_state.pending_announcement = None;
         Ok(GameActionResult::Success {
             new_phase: state.phase.clone(),
         })
@@ -839,6 +1129,272 @@ impl GameActionHandler {
         })
     }
 
+    fn handle_capture_note(
+        &mut self,
+        state: &mut crate::game::state::GameState,
+        clue: (usize, usize),
+        note: String,
+    ) -> Result<GameActionResult, GameError> {
+        let action = GameAction::CaptureNote {
+            clue,
+            note: String::new(),
+        };
+        if !self.rules.is_action_valid(state, &action) {
+            return Err(GameError::InvalidAction {
+                action: "CaptureNote".to_string(),
+                reason: "Can only capture a note for the clue being shown".to_string(),
+            });
+        }
+
+        let mut effects = Vec::new();
+        if let Some(c) = state
+            .board
+            .categories
+            .get_mut(clue.0)
+            .and_then(|cat| cat.clues.get_mut(clue.1))
+        {
+            let note = note.trim();
+            c.host_note = (!note.is_empty()).then(|| note.to_string());
+            c.revealed = true;
+            c.solved = true;
+            effects.push(GameEffect::ClueRevealed { clue });
+            effects.push(GameEffect::ClueSolved { clue });
+
+            // Any clue-scoped event ends with the clue, scored or not
+            if state.event_state.is_event_active(&GameEvent::DoublePoints) {
+                state.event_state.deactivate_event();
+            }
+            if state
+                .event_state
+                .is_event_active(&GameEvent::ReverseQuestion)
+            {
+                use crate::game::events::ReverseQuestionEvent;
+                ReverseQuestionEvent::restore_clue(c);
+                state.event_state.deactivate_event();
+            }
+        }
+
+        let next_team_id = self
+            .scoring
+            .rotate_active_team(&state.teams, state.active_team);
+        state.active_team = next_team_id;
+
+        self.roll_on_resolve(state, &mut effects);
+
+        let new_phase = PlayPhase::Resolved { clue, next_team_id };
+        state.phase = new_phase.clone();
+
+        Ok(GameActionResult::StateChanged { new_phase, effects })
+    }
+
+    fn handle_pause(
+        &self,
+        state: &mut crate::game::state::GameState,
+    ) -> Result<GameActionResult, GameError> {
+        if !self.rules.is_action_valid(state, &GameAction::Pause) {
+            return Err(GameError::InvalidAction {
+                action: "Pause".to_string(),
+                reason: "Can only pause between clues".to_string(),
+            });
+        }
+
+        // The selection clock restarts from full once play resumes
+        state.selection_deadline = None;
+        let new_phase = PlayPhase::Intermission;
+        state.phase = new_phase.clone();
+
+        Ok(GameActionResult::Success { new_phase })
+    }
+
+    fn handle_resume(
+        &self,
+        state: &mut crate::game::state::GameState,
+    ) -> Result<GameActionResult, GameError> {
+        if !self.rules.is_action_valid(state, &GameAction::Resume) {
+            return Err(GameError::InvalidAction {
+                action: "Resume".to_string(),
+                reason: "Can only resume from intermission".to_string(),
+            });
+        }
+
+        let new_phase = PlayPhase::Selecting {
+            team_id: state.active_team,
+        };
+        state.phase = new_phase.clone();
+
+        Ok(GameActionResult::Success { new_phase })
+    }
+
+    fn handle_next_round(
+        &self,
+        state: &mut crate::game::state::GameState,
+    ) -> Result<GameActionResult, GameError> {
+        if !self.rules.is_action_valid(state, &GameAction::NextRound) {
+            return Err(GameError::InvalidAction {
+                action: "NextRound".to_string(),
+                reason: "Can only start the next round from intermission".to_string(),
+            });
+        }
+
+        state.round = state.upcoming_round();
+        self.handle_resume(state)
+    }
+
+    fn handle_clear_teams(
+        &self,
+        state: &mut crate::game::state::GameState,
+    ) -> Result<GameActionResult, GameError> {
+        if !self.rules.is_action_valid(state, &GameAction::ClearTeams) {
+            return Err(GameError::InvalidAction {
+                action: "ClearTeams".to_string(),
+                reason: "Can only clear teams in lobby phase".to_string(),
+            });
+        }
+
+        state.teams.clear();
+        state.active_team = 0;
+        Ok(GameActionResult::Success {
+            new_phase: state.phase.clone(),
+        })
+    }
+
+    fn handle_remove_last_team(
+        &self,
+        state: &mut crate::game::state::GameState,
+    ) -> Result<GameActionResult, GameError> {
+        if !self
+            .rules
+            .is_action_valid(state, &GameAction::RemoveLastTeam)
+        {
+            let reason = if matches!(state.phase, PlayPhase::Lobby) {
+                "There are no teams to remove"
+            } else {
+                "Can only remove teams in lobby phase"
+            };
+            return Err(GameError::InvalidAction {
+                action: "RemoveLastTeam".to_string(),
+                reason: reason.to_string(),
+            });
+        }
+
+        let removed = state.teams.pop().map(|t| t.id);
+        if removed == Some(state.active_team) {
+            state.active_team = state.teams.first().map(|t| t.id).unwrap_or(0);
+        }
+        Ok(GameActionResult::Success {
+            new_phase: state.phase.clone(),
+        })
+    }
+
+    fn handle_shuffle_teams(
+        &mut self,
+        state: &mut crate::game::state::GameState,
+    ) -> Result<GameActionResult, GameError> {
+        if !self.rules.is_action_valid(state, &GameAction::ShuffleTeams) {
+            return Err(GameError::InvalidAction {
+                action: "ShuffleTeams".to_string(),
+                reason: "Can only shuffle teams in lobby phase".to_string(),
+            });
+        }
+
+        state.teams.shuffle(&mut self.rng);
+        // The lobby's active team is whoever is first in line
+        if let Some(first) = state.teams.first() {
+            state.active_team = first.id;
+        }
+        Ok(GameActionResult::Success {
+            new_phase: state.phase.clone(),
+        })
+    }
+
+    fn handle_set_starting_score(
+        &self,
+        state: &mut crate::game::state::GameState,
+        team_id: u32,
+        score: i32,
+    ) -> Result<GameActionResult, GameError> {
+        let action = GameAction::SetStartingScore { team_id, score };
+        if !self.rules.validate_team_action(state, team_id, &action) {
+            return Err(GameError::InvalidAction {
+                action: "SetStartingScore".to_string(),
+                reason: "Starting scores can only be set for existing teams in the lobby"
+                    .to_string(),
+            });
+        }
+
+        if let Some(team) = state.teams.iter_mut().find(|t| t.id == team_id) {
+            let delta = score - team.score;
+            team.score = score;
+            state.log_score_change(team_id, delta, None);
+        }
+        Ok(GameActionResult::Success {
+            new_phase: state.phase.clone(),
+        })
+    }
+
+    fn handle_submit_wager(
+        &self,
+        state: &mut crate::game::state::GameState,
+        team_id: u32,
+        wager: i32,
+    ) -> Result<GameActionResult, GameError> {
+        let action = GameAction::SubmitWager { team_id, wager };
+        if state.wager_result(team_id).is_some() {
+            return Err(GameError::InvalidAction {
+                action: "SubmitWager".to_string(),
+                reason: "That team's wager has already been judged".to_string(),
+            });
+        }
+        if !self.rules.validate_team_action(state, team_id, &action) {
+            return Err(GameError::InvalidAction {
+                action: "SubmitWager".to_string(),
+                reason: "Wagers can only be placed by existing teams once the board is finished"
+                    .to_string(),
+            });
+        }
+        let score = state.get_team_by_id(team_id).map_or(0, |t| t.score);
+        let wager =
+            GameRules::check_wager(score, wager).map_err(|reason| GameError::InvalidAction {
+                action: "SubmitWager".to_string(),
+                reason,
+            })?;
+
+        state.wagers.retain(|(id, _)| *id != team_id);
+        state.wagers.push((team_id, wager));
+        Ok(GameActionResult::Success {
+            new_phase: state.phase.clone(),
+        })
+    }
+
+    fn handle_resolve_wager(
+        &self,
+        state: &mut crate::game::state::GameState,
+        team_id: u32,
+        correct: bool,
+    ) -> Result<GameActionResult, GameError> {
+        let action = GameAction::ResolveWager { team_id, correct };
+        if !self.rules.validate_team_action(state, team_id, &action) {
+            return Err(GameError::InvalidAction {
+                action: "ResolveWager".to_string(),
+                reason: "That team hasn't locked in a wager".to_string(),
+            });
+        }
+
+        let wager = state.wager_for(team_id).unwrap_or(0);
+        state.wagers.retain(|(id, _)| *id != team_id);
+        state.resolved_wagers.push((team_id, wager, correct));
+        let delta = if correct { wager } else { -wager };
+        let mut effects = Vec::new();
+        if delta != 0 && self.scoring.award_points(&mut state.teams, team_id, delta) {
+            state.log_score_change(team_id, delta, None);
+            effects.push(GameEffect::ScoreChanged { team_id, delta });
+        }
+        Ok(GameActionResult::StateChanged {
+            new_phase: state.phase.clone(),
+            effects,
+        })
+    }
+
     fn handle_manual_points_adjustment(
         &self,
         state: &mut crate::game::state::GameState,
@@ -849,6 +1405,7 @@ impl GameActionHandler {
         if let Some(team) = state.teams.iter_mut().find(|t| t.id == team_id) {
             let old_score = team.score;
             team.score = new_points;
+            state.log_score_change(team_id, new_points - old_score, None);
 
             let effects = vec![GameEffect::ManualScoreAdjustment {
                 team_id,
@@ -869,6 +1426,41 @@ impl GameActionHandler {
     }
 }
 
+/// Points the owner loses for missing a clue on their final attempt (doubled
+/// while Double Points is active, scaled while Reverse Question is, negative
+/// in an inverted category)
+fn incorrect_answer_penalty(
+    rules: &GameRules,
+    state: &GameState,
+    clue: (usize, usize),
+) -> Option<i32> {
+    let category = state.board.categories.get(clue.0)?;
+    let c = category.clues.get(clue.1)?;
+    let mut penalty = if state.event_state.is_event_active(&GameEvent::DoublePoints) {
+        use crate::game::events::DoublePointsEvent;
+        DoublePointsEvent::calculate_penalty(c.points)
+    } else {
+        c.points as i32
+    };
+    if state
+        .event_state
+        .is_event_active(&GameEvent::ReverseQuestion)
+    {
+        penalty = rules.reverse_award(penalty);
+    }
+    Some(penalty * category_sign(state, clue))
+}
+
+/// -1 for clues in an inverted ("minefield") category, where award and deduction swap
+fn category_sign(state: &GameState, clue: (usize, usize)) -> i32 {
+    let inverted = state
+        .board
+        .categories
+        .get(clue.0)
+        .is_some_and(|category| category.inverted);
+    if inverted { -1 } else { 1 }
+}
+
 /// Find indices of the lowest-scoring team (thief) and highest-scoring team (victim).
 /// Returns None if fewer than 2 teams or all scores equal.
 fn lowest_and_highest_team_indices(teams: &[Team]) -> Option<(usize, usize)> {
@@ -892,6 +1484,276 @@ fn lowest_and_highest_team_indices(teams: &[Team]) -> Option<(usize, usize)> {
     }
 }
 
+/// Indices of the (thief, victim) pair for a score steal going `direction`.
+/// Returns None with fewer than 2 teams, or when leader and trailer are tied.
+fn steal_team_indices(
+    teams: &[Team],
+    direction: StealDirection,
+    rng: &mut impl Rng,
+) -> Option<(usize, usize)> {
+    match direction {
+        StealDirection::LeaderToTrailer => lowest_and_highest_team_indices(teams),
+        StealDirection::TrailerToLeader => {
+            lowest_and_highest_team_indices(teams).map(|(low, high)| (high, low))
+        }
+        StealDirection::Random => {
+            if teams.len() < 2 {
+                return None;
+            }
+            let picked = rand::seq::index::sample(rng, teams.len(), 2);
+            Some((picked.index(0), picked.index(1)))
+        }
+    }
+}
+
+/// Borrow two distinct elements of a slice mutably at once.
+/// Returns None if `a == b` or either index is out of bounds.
+fn two_mut<T>(slice: &mut [T], a: usize, b: usize) -> Option<(&mut T, &mut T)> {
+    if a == b || a >= slice.len() || b >= slice.len() {
+        return None;
+    }
+    if a < b {
+        let (left, right) = slice.split_at_mut(b);
+        Some((&mut left[a], &mut right[0]))
+    } else {
+        let (left, right) = slice.split_at_mut(a);
+        Some((&mut right[0], &mut left[b]))
+    }
+}
+
+/// Move 20% of the victim's score to the thief, rounded per `config.steal_rounding`,
+/// recording the context for the heist animation. `config.steal_direction` picks
+/// who steals from whom; leader and trailer steals do nothing if no team is strictly ahead.
+fn apply_score_steal(
+    state: &mut GameState,
+    config: &EventConfig,
+    rng: &mut impl Rng,
+) -> Vec<GameEffect> {
+    let pair = steal_team_indices(&state.teams, config.steal_direction, rng);
+    let Some((thief_idx, victim_idx)) = pair else {
+        return Vec::new();
+    };
+    let Some((thief, victim)) = two_mut(&mut state.teams, thief_idx, victim_idx) else {
+        return Vec::new();
+    };
+    let amount = config.steal_amount(victim.score);
+    victim.score = victim.score.saturating_sub(amount);
+    thief.score = thief.score.saturating_add(amount);
+    let (victim_id, thief_id) = (victim.id, thief.id);
+    let context = StealEventContext {
+        thief_id: thief.id,
+        thief_name: thief.label(),
+        victim_id: victim.id,
+        victim_name: victim.label(),
+        amount,
+    };
+    let effects = vec![
+        GameEffect::ScoreChanged {
+            team_id: victim.id,
+            delta: -amount,
+        },
+        GameEffect::ScoreChanged {
+            team_id: thief.id,
+            delta: amount,
+        },
+        GameEffect::ScoreStealApplied {
+            context: context.clone(),
+        },
+    ];
+    // Save context for UI
+    state.event_state.last_steal = Some(context);
+    if amount > 0 {
+        state.log_score_change(victim_id, -amount, None);
+        state.log_score_change(thief_id, amount, None);
+    }
+    effects
+}
+
+/// Zero every team's score, logging what each team lost
+fn apply_hard_reset(state: &mut GameState) {
+    let losses: Vec<(u32, i32)> = state
+        .teams
+        .iter()
+        .filter(|t| t.score != 0)
+        .map(|t| (t.id, -t.score))
+        .collect();
+    for team in &mut state.teams {
+        team.score = 0;
+    }
+    for (team_id, delta) in losses {
+        state.log_score_change(team_id, delta, None);
+    }
+}
+
+#[cfg(test)]
+mod split_borrow_tests {
+    use super::*;
+
+    #[test]
+    fn test_two_mut_ascending_indices() {
+        let mut values = vec![10, 20, 30];
+        let (a, b) = two_mut(&mut values, 0, 2).unwrap();
+        assert_eq!((*a, *b), (10, 30));
+        *a += 1;
+        *b += 1;
+        assert_eq!(values, vec![11, 20, 31]);
+    }
+
+    #[test]
+    fn test_two_mut_descending_indices() {
+        let mut values = vec![10, 20, 30];
+        let (a, b) = two_mut(&mut values, 2, 1).unwrap();
+        assert_eq!((*a, *b), (30, 20));
+        *a = 0;
+        assert_eq!(values, vec![10, 20, 0]);
+    }
+
+    #[test]
+    fn test_two_mut_same_index_is_none() {
+        let mut values = vec![10, 20, 30];
+        assert!(two_mut(&mut values, 1, 1).is_none());
+    }
+
+    #[test]
+    fn test_two_mut_out_of_bounds_is_none() {
+        let mut values = vec![10, 20, 30];
+        assert!(two_mut(&mut values, 0, 3).is_none());
+        assert!(two_mut(&mut values, 5, 1).is_none());
+        assert!(two_mut::<i32>(&mut [], 0, 1).is_none());
+    }
+
+    #[test]
+    fn test_apply_score_steal_moves_points_to_trailing_team() {
+        let mut engine = crate::game::GameEngine::new(crate::core::Board::default());
+        let _ = engine.handle_action(GameAction::AddTeam {
+            name: "Leader".to_string(),
+        });
+        let _ = engine.handle_action(GameAction::AddTeam {
+            name: "Trailer".to_string(),
+        });
+        let state = engine.get_state_mut();
+        state.teams[0].score = 1000;
+        state.teams[1].score = 100;
+
+        let effects = apply_score_steal(state, &EventConfig::default(), &mut rand::thread_rng());
+
+        assert_eq!(state.teams[0].score, 800);
+        assert_eq!(state.teams[1].score, 300);
+        assert_eq!(effects.len(), 3);
+        let context = state.event_state.last_steal.as_ref().unwrap();
+        assert_eq!(context.thief_name, "Trailer");
+        assert_eq!(context.victim_name, "Leader");
+        assert_eq!(context.amount, 200);
+    }
+
+    #[test]
+    fn test_apply_score_steal_uses_configured_rounding() {
+        let mut engine = crate::game::GameEngine::new(crate::core::Board::default());
+        let _ = engine.handle_action(GameAction::AddTeam {
+            name: "Leader".to_string(),
+        });
+        let _ = engine.handle_action(GameAction::AddTeam {
+            name: "Trailer".to_string(),
+        });
+        let state = engine.get_state_mut();
+        state.teams[0].score = 1007;
+        state.teams[1].score = 0;
+        let config = EventConfig {
+            steal_rounding: crate::game::events::StealRounding::Ceil,
+            ..EventConfig::default()
+        };
+
+        apply_score_steal(state, &config, &mut rand::thread_rng());
+
+        assert_eq!(state.teams[0].score, 805);
+        assert_eq!(state.teams[1].score, 202);
+    }
+
+    /// Engine with Leader 1000, Middle 500 and Trailer 100
+    fn three_team_engine() -> crate::game::GameEngine {
+        let mut engine = crate::game::GameEngine::new(crate::core::Board::default());
+        for name in ["Leader", "Middle", "Trailer"] {
+            let _ = engine.handle_action(GameAction::AddTeam {
+                name: name.to_string(),
+            });
+        }
+        let state = engine.get_state_mut();
+        state.teams[0].score = 1000;
+        state.teams[1].score = 500;
+        state.teams[2].score = 100;
+        engine
+    }
+
+    fn steal_with(direction: StealDirection) -> Vec<i32> {
+        let mut engine = three_team_engine();
+        let config = EventConfig {
+            steal_direction: direction,
+            ..EventConfig::default()
+        };
+        apply_score_steal(engine.get_state_mut(), &config, &mut rand::thread_rng());
+        engine.get_state().teams.iter().map(|t| t.score).collect()
+    }
+
+    #[test]
+    fn test_leader_to_trailer_steal_helps_the_trailer() {
+        // Trailer takes 20% of the leader's 1000; the middle team is untouched
+        assert_eq!(
+            steal_with(StealDirection::LeaderToTrailer),
+            vec![800, 500, 300]
+        );
+    }
+
+    #[test]
+    fn test_trailer_to_leader_steal_helps_the_leader() {
+        // Leader takes 20% of the trailer's 100
+        assert_eq!(
+            steal_with(StealDirection::TrailerToLeader),
+            vec![1020, 500, 80]
+        );
+        let mut engine = three_team_engine();
+        let config = EventConfig {
+            steal_direction: StealDirection::TrailerToLeader,
+            ..EventConfig::default()
+        };
+        apply_score_steal(engine.get_state_mut(), &config, &mut rand::thread_rng());
+        let context = engine.get_state().event_state.last_steal.clone().unwrap();
+        assert_eq!(context.thief_name, "Leader");
+        assert_eq!(context.victim_name, "Trailer");
+    }
+
+    #[test]
+    fn test_random_steal_moves_points_between_two_teams() {
+        let steal_with_seed = |seed| {
+            let mut engine = three_team_engine();
+            engine.seed_rng(seed);
+            engine.event_config_mut().steal_direction = StealDirection::Random;
+            let _ = engine.handle_action(GameAction::TriggerEvent {
+                event: GameEvent::ScoreSteal,
+            });
+            let scores = engine.get_state().teams.iter().map(|t| t.score);
+            scores.collect::<Vec<i32>>()
+        };
+        let before = [1000, 500, 100];
+        let after = steal_with_seed(7);
+        let deltas: Vec<i32> = after.iter().zip(before).map(|(a, b)| a - b).collect();
+        assert_eq!(deltas.iter().filter(|d| **d > 0).count(), 1);
+        assert_eq!(deltas.iter().filter(|d| **d < 0).count(), 1);
+        assert_eq!(deltas.iter().sum::<i32>(), 0);
+        // A seeded game replays the same steal
+        assert_eq!(steal_with_seed(7), after);
+
+        // The pick always names two different teams
+        let engine = three_team_engine();
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..20 {
+            let (thief, victim) =
+                steal_team_indices(&engine.get_state().teams, StealDirection::Random, &mut rng)
+                    .unwrap();
+            assert_ne!(thief, victim);
+        }
+    }
+}
+
 #[cfg(test)]
 mod manual_points_tests {
     use super::*;
@@ -1000,6 +1862,8 @@ mod two_attempt_tests {
         let mut board = Board::default();
         board.categories = vec![Category {
             name: "Test Category".to_string(),
+            inverted: false,
+            intro: None,
             clues: vec![
                 Clue {
                     id: 1,
@@ -1008,6 +1872,13 @@ mod two_attempt_tests {
                     points: 200,
                     solved: false,
                     revealed: false,
+                    difficulty: None,
+                    host_note: None,
+                    no_flash: false,
+                    bonus_attempt: false,
+                    unlock_round: None,
+                    media: None,
+                    alt_answers: Vec::new(),
                 },
                 Clue {
                     id: 2,
@@ -1016,6 +1887,13 @@ mod two_attempt_tests {
                     points: 800,
                     solved: false,
                     revealed: false,
+                    difficulty: None,
+                    host_note: None,
+                    no_flash: false,
+                    bonus_attempt: false,
+                    unlock_round: None,
+                    media: None,
+                    alt_answers: Vec::new(),
                 },
             ],
         }];
@@ -1033,10 +1911,10 @@ mod two_attempt_tests {
         assert_eq!(get_question_points(&state, (1, 0)), 0); // Invalid clue
 
         // Test calculate_max_attempts
-        assert_eq!(calculate_max_attempts(200), 1);
-        assert_eq!(calculate_max_attempts(500), 1);
-        assert_eq!(calculate_max_attempts(501), 2);
-        assert_eq!(calculate_max_attempts(800), 2);
+        assert_eq!(calculate_max_attempts(200, false), 1);
+        assert_eq!(calculate_max_attempts(500, false), 1);
+        assert_eq!(calculate_max_attempts(501, false), 2);
+        assert_eq!(calculate_max_attempts(800, false), 2);
     }
 
     #[test]
@@ -1258,6 +2136,8 @@ mod two_attempt_tests {
         let mut board = Board::default();
         board.categories = vec![Category {
             name: "Test Category".to_string(),
+            inverted: false,
+            intro: None,
             clues: vec![Clue {
                 id: 1,
                 question: "Exactly 500 points".to_string(),
@@ -1265,6 +2145,13 @@ mod two_attempt_tests {
                 points: 500,
                 solved: false,
                 revealed: false,
+                difficulty: None,
+                host_note: None,
+                no_flash: false,
+                bonus_attempt: false,
+                unlock_round: None,
+                media: None,
+                alt_answers: Vec::new(),
             }],
         }];
 
@@ -1325,6 +2212,8 @@ mod edge_case_tests {
         let mut board = Board::default();
         board.categories = vec![Category {
             name: "Test Category".to_string(),
+            inverted: false,
+            intro: None,
             clues: vec![Clue {
                 id: 1,
                 question: "Zero points".to_string(),
@@ -1332,6 +2221,13 @@ mod edge_case_tests {
                 points: 0,
                 solved: false,
                 revealed: false,
+                difficulty: None,
+                host_note: None,
+                no_flash: false,
+                bonus_attempt: false,
+                unlock_round: None,
+                media: None,
+                alt_answers: Vec::new(),
             }],
         }];
 
@@ -1373,6 +2269,8 @@ mod edge_case_tests {
         let mut board = Board::default();
         board.categories = vec![Category {
             name: "Test Category".to_string(),
+            inverted: false,
+            intro: None,
             clues: vec![Clue {
                 id: 1,
                 question: "High value question".to_string(),
@@ -1380,6 +2278,13 @@ mod edge_case_tests {
                 points: 800,
                 solved: false,
                 revealed: false,
+                difficulty: None,
+                host_note: None,
+                no_flash: false,
+                bonus_attempt: false,
+                unlock_round: None,
+                media: None,
+                alt_answers: Vec::new(),
             }],
         }];
 
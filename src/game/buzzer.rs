@@ -0,0 +1,178 @@
+use std::io::{BufRead, BufReader};
+use std::net::{TcpListener, ToSocketAddrs};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use crate::game::{GameAction, GameEngine};
+
+/// Physical buzzers, however they reach the app. The engine never sees the
+/// transport; buzzes arrive as `GameAction::BuzzIn`.
+pub trait BuzzerInput {
+    /// Id of a team that buzzed since the last poll, oldest first; None when quiet
+    fn poll(&mut self) -> Option<u32>;
+}
+
+/// A buzz line is just the team id, e.g. "2"; anything else is ignored
+pub fn parse_buzz_line(line: &str) -> Option<u32> {
+    line.trim().parse().ok()
+}
+
+/// Buzzes read as line-delimited team ids on a background thread, from stdin
+/// or from clients of a localhost TCP socket
+pub struct LineBuzzer {
+    buzzes: Receiver<u32>,
+}
+
+impl LineBuzzer {
+    pub fn stdin() -> Self {
+        let (tx, buzzes) = mpsc::channel();
+        thread::spawn(move || forward_lines(std::io::stdin().lock(), &tx));
+        Self { buzzes }
+    }
+
+    /// Listen on `addr` (e.g. "127.0.0.1:7777"); each connection may send any number of buzzes
+    pub fn tcp(addr: impl ToSocketAddrs) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let (tx, buzzes) = mpsc::channel();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let tx = tx.clone();
+                thread::spawn(move || forward_lines(BufReader::new(stream), &tx));
+            }
+        });
+        Ok(Self { buzzes })
+    }
+}
+
+fn forward_lines(reader: impl BufRead, tx: &Sender<u32>) {
+    for line in reader.lines().map_while(Result::ok) {
+        if let Some(team_id) = parse_buzz_line(&line) {
+            if tx.send(team_id).is_err() {
+                return;
+            }
+        } else {
+            log::warn!("ignoring buzzer line {:?}", line);
+        }
+    }
+}
+
+impl BuzzerInput for LineBuzzer {
+    fn poll(&mut self) -> Option<u32> {
+        self.buzzes.try_recv().ok()
+    }
+}
+
+/// Drain pending buzzes into the engine. The first team allowed to buzz in
+/// takes over as the answerer; later buzzes from the same batch lost the race
+/// and are dropped. Returns the team that took over, if any.
+pub fn feed_buzzes(engine: &mut GameEngine, input: &mut dyn BuzzerInput) -> Option<u32> {
+    let mut answerer = None;
+    while let Some(team_id) = input.poll() {
+        if answerer.is_none() && engine.handle_action(GameAction::BuzzIn { team_id }).is_ok() {
+            answerer = Some(team_id);
+        }
+    }
+    answerer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Board;
+    use crate::game::PlayPhase;
+    use std::collections::VecDeque;
+
+    struct MockBuzzer(VecDeque<u32>);
+
+    impl BuzzerInput for MockBuzzer {
+        fn poll(&mut self) -> Option<u32> {
+            self.0.pop_front()
+        }
+    }
+
+    /// Three teams with the first one's clue missed, so the other two are waiting to steal
+    fn engine_in_steal() -> GameEngine {
+        let mut engine = GameEngine::new(Board::default_with_dimensions(2, 3));
+        for name in ["A", "B", "C"] {
+            let _ = engine.handle_action(GameAction::AddTeam {
+                name: name.to_string(),
+            });
+        }
+        let _ = engine.handle_action(GameAction::StartGame);
+        let owner = match engine.get_phase() {
+            PlayPhase::Selecting { team_id } => *team_id,
+            other => panic!("expected selecting, got {:?}", other),
+        };
+        let _ = engine.handle_action(GameAction::SelectClue {
+            clue: (0, 0),
+            team_id: owner,
+        });
+        let _ = engine.handle_action(GameAction::AnswerIncorrect {
+            clue: (0, 0),
+            team_id: owner,
+        });
+        assert!(matches!(engine.get_phase(), PlayPhase::Steal { .. }));
+        engine
+    }
+
+    #[test]
+    fn test_first_valid_buzz_becomes_the_answerer() {
+        let mut engine = engine_in_steal();
+        let PlayPhase::Steal {
+            queue,
+            current,
+            owner_team_id,
+            ..
+        } = engine.get_phase().clone()
+        else {
+            unreachable!()
+        };
+        let waiting = *queue
+            .back()
+            .expect("a team waiting behind the current stealer");
+
+        // The clue's owner can't buzz back in; the waiting team wins, the next buzz is too late
+        let mut buzzer = MockBuzzer(VecDeque::from([owner_team_id, waiting, current]));
+        assert_eq!(feed_buzzes(&mut engine, &mut buzzer), Some(waiting));
+
+        match engine.get_phase() {
+            PlayPhase::Steal {
+                current: now,
+                queue,
+                ..
+            } => {
+                assert_eq!(*now, waiting);
+                assert_eq!(queue.front(), Some(&current));
+                assert!(!queue.contains(&waiting));
+            }
+            other => panic!("expected steal, got {:?}", other),
+        }
+        // Only the team that buzzed may answer now
+        let steal = GameAction::StealAttempt {
+            clue: (0, 0),
+            team_id: current,
+            correct: true,
+        };
+        assert!(engine.handle_action(steal).is_err());
+    }
+
+    #[test]
+    fn test_buzzes_outside_steal_are_ignored() {
+        let mut engine = GameEngine::new(Board::default_with_dimensions(2, 2));
+        let _ = engine.handle_action(GameAction::AddTeam {
+            name: "A".to_string(),
+        });
+        let team_id = engine.get_state().teams[0].id;
+        let mut buzzer = MockBuzzer(VecDeque::from([team_id, 99]));
+        assert_eq!(feed_buzzes(&mut engine, &mut buzzer), None);
+        assert!(matches!(engine.get_phase(), PlayPhase::Lobby));
+    }
+
+    #[test]
+    fn test_parse_buzz_line() {
+        assert_eq!(parse_buzz_line("3\n"), Some(3));
+        assert_eq!(parse_buzz_line("  12 "), Some(12));
+        assert_eq!(parse_buzz_line("team 3"), None);
+        assert_eq!(parse_buzz_line(""), None);
+    }
+}
@@ -1,5 +1,7 @@
 use crate::core::Board;
 use crate::game::actions::{GameAction, GameActionHandler, GameActionResult, GameError};
+use crate::game::events::EventConfig;
+use crate::game::rules::{FirstTeamPolicy, GameRules};
 use crate::game::state::{GameState, PlayPhase};
 
 #[derive(Debug)]
@@ -17,7 +19,59 @@ impl GameEngine {
     }
 
     pub fn handle_action(&mut self, action: GameAction) -> Result<GameActionResult, GameError> {
-        self.action_handler.handle(&mut self.state, action)
+        log::debug!("handling {:?} in phase {:?}", action, self.state.phase);
+        let logged_action = action.clone();
+        let result = self.action_handler.handle(&mut self.state, action);
+        match &result {
+            Ok(GameActionResult::Success { new_phase }) => {
+                log::info!("{:?} -> {:?}", logged_action, new_phase);
+            }
+            Ok(GameActionResult::StateChanged { new_phase, effects }) => {
+                log::info!("{:?} -> {:?}", logged_action, new_phase);
+                for effect in effects {
+                    log::debug!("  effect {:?}", effect);
+                }
+            }
+            Err(err) => {
+                log::warn!("{:?} rejected: {:?}", logged_action, err);
+            }
+        }
+        result
+    }
+
+    pub fn rules(&self) -> &GameRules {
+        self.action_handler.rules()
+    }
+
+    pub fn rules_mut(&mut self) -> &mut GameRules {
+        self.action_handler.rules_mut()
+    }
+
+    pub fn event_config(&self) -> &EventConfig {
+        self.action_handler.event_config()
+    }
+
+    pub fn event_config_mut(&mut self) -> &mut EventConfig {
+        self.action_handler.event_config_mut()
+    }
+
+    /// Cap how many teams can join; at least one team is always allowed
+    pub fn set_max_teams(&mut self, max_teams: usize) {
+        self.rules_mut().max_teams = max_teams.max(1);
+    }
+
+    pub fn set_first_team_policy(&mut self, policy: FirstTeamPolicy) {
+        self.rules_mut().first_team = policy;
+    }
+
+    /// Reseed the engine's RNG so random decisions are reproducible
+    pub fn seed_rng(&mut self, seed: u64) {
+        self.action_handler.seed_rng(seed);
+    }
+
+    /// Replay the same board with the same teams, starting again from the lobby
+    pub fn reset_to_lobby(&mut self) {
+        self.state.reset_to_lobby();
     }
 
     pub fn get_phase(&self) -> &PlayPhase {
@@ -57,18 +111,19 @@ impl GameEngine {
     }
 
     pub fn get_available_clues(&self) -> Vec<(usize, usize)> {
-        let mut available = Vec::new();
-        for (cat_idx, category) in self.state.board.categories.iter().enumerate() {
-            for (clue_idx, clue) in category.clues.iter().enumerate() {
-                if !clue.solved {
-                    available.push((cat_idx, clue_idx));
-                }
-            }
-        }
-        available
+        self.state.get_available_clues()
     }
 
     pub fn get_clue(&self, clue: (usize, usize)) -> Option<&crate::core::Clue> {
         self.state.get_clue(clue)
     }
-}
\ No newline at end of file
+
+    pub fn clue_at(&self, category: usize, row: usize) -> Option<&crate::core::Clue> {
+        self.state.get_clue((category, row))
+    }
+
+    /// The clue in play (Showing, Steal or Resolved); None between clues
+    pub fn current_clue(&self) -> Option<&crate::core::Clue> {
+        self.state.get_clue(self.state.phase.clue()?)
+    }
+}
@@ -7,10 +7,34 @@ pub enum GameEvent {
     DoublePoints,
     HardReset,
     ReverseQuestion,
-    /// Lowest score team steals 20% of the points from the leading team
+    /// One team steals 20% of another's points; by default the lowest score takes from the leader
     ScoreSteal,
 }
 
+impl GameEvent {
+    /// Short label for the board-header banner shown while this event is in effect
+    pub fn active_banner_label(&self) -> &'static str {
+        match self {
+            GameEvent::DoublePoints => "×2 DOUBLE POINTS ACTIVE",
+            GameEvent::ReverseQuestion => "⇄ REVERSE QUESTION ACTIVE",
+            GameEvent::HardReset => "⟲ HARD RESET",
+            GameEvent::ScoreSteal => "⚡ SCORE STEAL",
+        }
+    }
+
+    /// One-line explanation for the announcement shown after the animation
+    pub fn announcement_text(&self) -> &'static str {
+        match self {
+            GameEvent::DoublePoints => "Double Points: the next clue is worth twice as much!",
+            GameEvent::ReverseQuestion => {
+                "Reverse Question: the next clue's question and answer are swapped!"
+            }
+            GameEvent::HardReset => "Hard Reset: every team's score is back to zero!",
+            GameEvent::ScoreSteal => "Score Steal: one team just took points from another!",
+        }
+    }
+}
+
 /// Tracks the state of the event system within a game
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventState {
@@ -22,6 +46,9 @@ pub struct EventState {
     /// Context for the last score steal event (for UI animation)
     #[serde(default)]
     pub last_steal: Option<StealEventContext>,
+    /// Event the host still has to acknowledge before play continues
+    #[serde(default)]
+    pub pending_announcement: Option<GameEvent>,
 }
 
 impl EventState {
@@ -33,17 +60,28 @@ impl EventState {
             event_history: Vec::new(),
             animation_playing: false,
             last_steal: None,
+            pending_announcement: None,
         }
     }
 
-    /// Check if an event should be triggered based on question count
-    pub fn should_trigger_event(&self) -> bool {
-        self.questions_answered > 0
-            && self.questions_answered % 4 == 0
+    /// Check if an event should be triggered with one every `interval` questions (0 = never)
+    pub fn should_trigger_every(&self, interval: u32) -> bool {
+        interval > 0
+            && self.questions_answered > 0
+            && self.questions_answered % interval == 0
             && self.active_event.is_none()
             && self.queued_event.is_none()
     }
 
+    /// Questions left before the next event roll with one every `interval`.
+    /// None while an event is active or waiting to play, or when events never roll.
+    pub fn clues_until_next_event(&self, interval: u32) -> Option<u32> {
+        if interval == 0 || self.active_event.is_some() || self.queued_event.is_some() {
+            return None;
+        }
+        Some(interval - self.questions_answered % interval)
+    }
+
     /// Increment the question count when a question is fully resolved
     pub fn increment_question_count(&mut self) {
         self.questions_answered += 1;
@@ -97,12 +135,120 @@ impl Default for EventState {
     }
 }
 
+/// How the score steal's 20% cut is turned into whole points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StealRounding {
+    #[default]
+    Floor,
+    Round,
+    Ceil,
+}
+
+impl StealRounding {
+    pub fn apply(self, value: f32) -> i32 {
+        match self {
+            StealRounding::Floor => value.floor() as i32,
+            StealRounding::Round => value.round() as i32,
+            StealRounding::Ceil => value.ceil() as i32,
+        }
+    }
+}
+
+/// Which way a score steal moves points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StealDirection {
+    /// The trailing team takes from the leader, a comeback mechanic
+    #[default]
+    LeaderToTrailer,
+    /// The leader takes from the trailing team, the rich get richer
+    TrailerToLeader,
+    /// Any two different teams, picked at random
+    Random,
+}
+
+/// When the event roll happens for a finished question
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EventTiming {
+    /// Once the host closes the clue and play returns to the board
+    #[default]
+    OnClose,
+    /// As soon as the clue resolves, so the animation plays before the Close button
+    OnResolve,
+}
+
+/// How likely each event is to be drawn relative to the others; only enabled
+/// events take part
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventWeights {
+    pub double_points: u32,
+    pub reverse_question: u32,
+    pub score_steal: u32,
+    pub hard_reset: u32,
+}
+
+impl Default for EventWeights {
+    fn default() -> Self {
+        Self {
+            double_points: 50,
+            reverse_question: 20,
+            score_steal: 20,
+            hard_reset: 10,
+        }
+    }
+}
+
+impl EventWeights {
+    pub fn get(&self, event: &GameEvent) -> u32 {
+        match event {
+            GameEvent::DoublePoints => self.double_points,
+            GameEvent::ReverseQuestion => self.reverse_question,
+            GameEvent::ScoreSteal => self.score_steal,
+            GameEvent::HardReset => self.hard_reset,
+        }
+    }
+
+    pub fn get_mut(&mut self, event: &GameEvent) -> &mut u32 {
+        match event {
+            GameEvent::DoublePoints => &mut self.double_points,
+            GameEvent::ReverseQuestion => &mut self.reverse_question,
+            GameEvent::ScoreSteal => &mut self.score_steal,
+            GameEvent::HardReset => &mut self.hard_reset,
+        }
+    }
+}
+
+/// Each weight as a percentage of their total. All-zero weights split evenly,
+/// the same uniform fallback the draw itself uses.
+pub fn relative_chances(weights: &[u32]) -> Vec<f32> {
+    let total: u32 = weights.iter().sum();
+    if total == 0 {
+        let even = 100.0 / weights.len().max(1) as f32;
+        return vec![even; weights.len()];
+    }
+    weights
+        .iter()
+        .map(|&w| w as f32 * 100.0 / total as f32)
+        .collect()
+}
+
 /// Configuration for the event system
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct EventConfig {
     pub trigger_interval: u32,
     pub enabled_events: Vec<GameEvent>,
     pub animation_duration: Duration,
+    pub steal_rounding: StealRounding,
+    pub steal_direction: StealDirection,
+    /// Weight of "no event this time" alongside the event weights (0 = a trigger always fires)
+    pub no_event_weight: u32,
+    /// How likely each event is to be drawn relative to the others
+    pub weights: EventWeights,
+    /// How many of the most recent events sit out the next draw (0 = no cooldown)
+    pub cooldown: usize,
+    /// Whether the event roll happens as a clue resolves or once it's closed
+    pub timing: EventTiming,
 }
 
 impl EventConfig {
@@ -116,29 +262,72 @@ impl EventConfig {
                 GameEvent::ScoreSteal,
             ],
             animation_duration: Duration::from_millis(3000),
+            steal_rounding: StealRounding::Floor,
+            steal_direction: StealDirection::LeaderToTrailer,
+            no_event_weight: 0,
+            weights: EventWeights::default(),
+            cooldown: 0,
+            timing: EventTiming::OnClose,
+        }
+    }
+
+    /// Chance, in percent, of each enabled event and then of "no event" when a
+    /// trigger fires, ignoring the cooldown
+    pub fn draw_chances(&self) -> (Vec<(GameEvent, f32)>, f32) {
+        let mut weights: Vec<u32> = self
+            .enabled_events
+            .iter()
+            .map(|e| self.weights.get(e))
+            .collect();
+        let all_zero = weights.iter().all(|&w| w == 0);
+        // Zeroed event weights draw uniformly, but never the "no event" slot
+        if !all_zero || self.no_event_weight > 0 {
+            weights.push(self.no_event_weight);
         }
+        let mut chances = relative_chances(&weights);
+        let no_event = if chances.len() > self.enabled_events.len() {
+            chances.pop().unwrap_or(0.0)
+        } else {
+            0.0
+        };
+        let events = self.enabled_events.iter().cloned().zip(chances).collect();
+        (events, no_event)
+    }
+
+    /// Points a score steal takes from a team with `victim_score`; never negative
+    pub fn steal_amount(&self, victim_score: i32) -> i32 {
+        self.steal_rounding.apply(victim_score as f32 * 0.20).max(0)
     }
 
-    /// Get a random event from the enabled events list
-    pub fn get_random_event(&self) -> Option<GameEvent> {
+    /// Get a random event from the enabled events list, or None when the
+    /// "no event" slot is drawn. Events among the last `cooldown` entries of
+    /// `recent` are left out, unless that would leave nothing to draw.
+    pub fn get_random_event(&self, recent: &[GameEvent]) -> Option<GameEvent> {
         if self.enabled_events.is_empty() {
             return None;
         }
 
-        // Weighted selection: DoublePoints (highest) > ReverseQuestion > ScoreSteal > HardReset (lowest)
-        // Only consider events that are enabled.
+        let cooled = &recent[recent.len().saturating_sub(self.cooldown)..];
+        let mut candidates: Vec<&GameEvent> = self
+            .enabled_events
+            .iter()
+            .filter(|e| !cooled.contains(e))
+            .collect();
+        if candidates.is_empty() {
+            candidates = self.enabled_events.iter().collect();
+        }
+
+        // Weighted selection over the enabled events, using the configured weights
         let mut events: Vec<GameEvent> = Vec::new();
         let mut weights: Vec<u32> = Vec::new();
 
-        for e in &self.enabled_events {
-            let w = match e {
-                GameEvent::DoublePoints => 50,
-                GameEvent::ReverseQuestion => 20,
-                GameEvent::ScoreSteal => 20,
-                GameEvent::HardReset => 10,
-            };
+        for e in candidates {
             events.push(e.clone());
-            weights.push(w);
+            weights.push(self.weights.get(e));
+        }
+        // Extra slot past the last event: drawing it yields None
+        if self.no_event_weight > 0 {
+            weights.push(self.no_event_weight);
         }
 
         // Fallback to uniform if something odd happens (e.g., zeroed weights)
@@ -414,18 +603,35 @@ mod tests {
         assert_eq!(game_state.teams[0].name, "Team 1");
     }
 
+    #[test]
+    fn test_active_banner_labels() {
+        assert_eq!(
+            GameEvent::DoublePoints.active_banner_label(),
+            "×2 DOUBLE POINTS ACTIVE"
+        );
+        assert_eq!(
+            GameEvent::ReverseQuestion.active_banner_label(),
+            "⇄ REVERSE QUESTION ACTIVE"
+        );
+        assert_eq!(GameEvent::HardReset.active_banner_label(), "⟲ HARD RESET");
+        assert_eq!(
+            GameEvent::ScoreSteal.active_banner_label(),
+            "⚡ SCORE STEAL"
+        );
+    }
+
     #[test]
     fn test_event_state_trigger_detection() {
         let mut event_state = EventState::new();
 
         // Should not trigger initially
-        assert!(!event_state.should_trigger_event());
+        assert!(!event_state.should_trigger_every(4));
 
         // Should not trigger before 4 questions
         for i in 1..4 {
             event_state.increment_question_count();
             assert!(
-                !event_state.should_trigger_event(),
+                !event_state.should_trigger_every(4),
                 "Should not trigger at {} questions",
                 i
             );
@@ -433,18 +639,18 @@ mod tests {
 
         // Should trigger at 4 questions
         event_state.increment_question_count();
-        assert!(event_state.should_trigger_event());
+        assert!(event_state.should_trigger_every(4));
 
         // Should not trigger when event is active
         event_state.activate_event(GameEvent::DoublePoints);
-        assert!(!event_state.should_trigger_event());
+        assert!(!event_state.should_trigger_every(4));
 
         // Should trigger again at 8 questions after deactivating
         event_state.deactivate_event();
         for _ in 5..=8 {
             event_state.increment_question_count();
         }
-        assert!(event_state.should_trigger_event());
+        assert!(event_state.should_trigger_every(4));
     }
 
     #[test]
@@ -470,7 +676,7 @@ mod tests {
         let config = EventConfig::new();
 
         // Should return some event from the enabled list
-        let event = config.get_random_event();
+        let event = config.get_random_event(&[]);
         assert!(event.is_some());
         assert!(config.enabled_events.contains(&event.unwrap()));
 
@@ -479,8 +685,141 @@ mod tests {
             trigger_interval: 5,
             enabled_events: vec![],
             animation_duration: Duration::from_millis(3000),
+            steal_rounding: StealRounding::Floor,
+            steal_direction: StealDirection::LeaderToTrailer,
+            no_event_weight: 0,
+            weights: EventWeights::default(),
+            cooldown: 0,
+            timing: EventTiming::OnClose,
+        };
+        assert!(empty_config.get_random_event(&[]).is_none());
+    }
+
+    #[test]
+    fn test_relative_chances_sum_to_100() {
+        let chances = relative_chances(&[50, 20, 20, 10]);
+        assert_eq!(chances, vec![50.0, 20.0, 20.0, 10.0]);
+        let chances = relative_chances(&[1, 2]);
+        assert!((chances.iter().sum::<f32>() - 100.0).abs() < 1e-3);
+        assert!((chances[1] - 66.666_67).abs() < 1e-3);
+
+        // All-zero weights split evenly instead of dividing by zero
+        assert_eq!(relative_chances(&[0, 0, 0, 0]), vec![25.0; 4]);
+        assert!(relative_chances(&[]).is_empty());
+
+        // The settings readout includes the "no event" slot only when it's weighted
+        let mut config = EventConfig {
+            no_event_weight: 100,
+            ..EventConfig::new()
+        };
+        let (events, no_event) = config.draw_chances();
+        assert_eq!(no_event, 50.0);
+        assert_eq!(events[0], (GameEvent::DoublePoints, 25.0));
+        config.weights = EventWeights {
+            double_points: 0,
+            reverse_question: 0,
+            score_steal: 0,
+            hard_reset: 0,
+        };
+        config.no_event_weight = 0;
+        let (events, no_event) = config.draw_chances();
+        assert_eq!(no_event, 0.0);
+        assert!(events.iter().all(|(_, chance)| *chance == 25.0));
+    }
+
+    #[test]
+    fn test_zero_weight_event_is_never_drawn() {
+        let mut config = EventConfig::new();
+        *config.weights.get_mut(&GameEvent::DoublePoints) = 0;
+        for _ in 0..200 {
+            assert_ne!(config.get_random_event(&[]), Some(GameEvent::DoublePoints));
+        }
+    }
+
+    #[test]
+    fn test_no_event_weight_sometimes_skips() {
+        // Default event weights sum to 100, so a no-event weight of 300 skips ~75% of triggers
+        let config = EventConfig {
+            no_event_weight: 300,
+            ..EventConfig::new()
         };
-        assert!(empty_config.get_random_event().is_none());
+        let skipped = (0..1000)
+            .filter(|_| config.get_random_event(&[]).is_none())
+            .count();
+        assert!(skipped > 500, "only {} of 1000 triggers skipped", skipped);
+        assert!(skipped < 1000, "no trigger produced an event");
+    }
+
+    #[test]
+    fn test_zero_no_event_weight_always_fires() {
+        let config = EventConfig::new();
+        assert_eq!(config.no_event_weight, 0);
+        for _ in 0..1000 {
+            assert!(config.get_random_event(&[]).is_some());
+        }
+    }
+
+    #[test]
+    fn test_cooldown_excludes_previous_event() {
+        let config = EventConfig {
+            cooldown: 1,
+            ..EventConfig::new()
+        };
+        let recent = [GameEvent::HardReset, GameEvent::DoublePoints];
+        for _ in 0..500 {
+            let event = config.get_random_event(&recent).unwrap();
+            assert_ne!(event, GameEvent::DoublePoints);
+        }
+        // Only the most recent event is cooling down
+        assert!((0..500).any(|_| config.get_random_event(&recent) == Some(GameEvent::HardReset)));
+    }
+
+    #[test]
+    fn test_cooldown_with_single_enabled_event_still_fires() {
+        let config = EventConfig {
+            cooldown: 1,
+            enabled_events: vec![GameEvent::ScoreSteal],
+            ..EventConfig::new()
+        };
+        let recent = [GameEvent::ScoreSteal];
+        assert_eq!(
+            config.get_random_event(&recent),
+            Some(GameEvent::ScoreSteal)
+        );
+    }
+
+    #[test]
+    fn test_steal_amount_rounding_modes() {
+        let amount = |rounding, score| {
+            EventConfig {
+                steal_rounding: rounding,
+                ..EventConfig::new()
+            }
+            .steal_amount(score)
+        };
+
+        // 20% of 1000 is exact, so every mode agrees
+        for rounding in [
+            StealRounding::Floor,
+            StealRounding::Round,
+            StealRounding::Ceil,
+        ] {
+            assert_eq!(amount(rounding, 1000), 200);
+        }
+
+        // 20% of 1007 is 201.4
+        assert_eq!(amount(StealRounding::Floor, 1007), 201);
+        assert_eq!(amount(StealRounding::Round, 1007), 201);
+        assert_eq!(amount(StealRounding::Ceil, 1007), 202);
+
+        // 20% of 1008 is 201.6
+        assert_eq!(amount(StealRounding::Floor, 1008), 201);
+        assert_eq!(amount(StealRounding::Round, 1008), 202);
+        assert_eq!(amount(StealRounding::Ceil, 1008), 202);
+
+        // Default keeps the old floor behaviour; negative scores give nothing
+        assert_eq!(EventConfig::default().steal_rounding, StealRounding::Floor);
+        assert_eq!(amount(StealRounding::Ceil, -500), 0);
     }
 
     #[test]
@@ -506,6 +845,39 @@ mod tests {
         assert!(!controller.is_animating());
     }
 
+    #[test]
+    fn test_clues_until_next_event_counts_down() {
+        let mut event_state = EventState::new();
+        assert_eq!(event_state.clues_until_next_event(4), Some(4));
+        event_state.increment_question_count();
+        assert_eq!(event_state.clues_until_next_event(4), Some(3));
+        for _ in 0..2 {
+            event_state.increment_question_count();
+        }
+        assert_eq!(event_state.clues_until_next_event(4), Some(1));
+
+        // The roll lands on the fourth question and the event waits to play
+        event_state.increment_question_count();
+        assert!(event_state.should_trigger_every(4));
+        event_state.queue_event(GameEvent::DoublePoints);
+        assert_eq!(event_state.clues_until_next_event(4), None);
+
+        // Mid-event there is no countdown
+        let queued = event_state.take_queued_event().unwrap();
+        event_state.activate_event(queued);
+        assert_eq!(event_state.clues_until_next_event(4), None);
+
+        // Once it ends, the bar starts over from empty
+        event_state.deactivate_event();
+        assert_eq!(event_state.clues_until_next_event(4), Some(4));
+        event_state.increment_question_count();
+        assert_eq!(event_state.clues_until_next_event(4), Some(3));
+
+        // A zero interval never counts down
+        assert_eq!(event_state.clues_until_next_event(0), None);
+        assert!(!event_state.should_trigger_every(0));
+    }
+
     #[test]
     fn test_event_trigger_timing() {
         let mut event_state = EventState::new();
@@ -515,20 +887,20 @@ mod tests {
             event_state.increment_question_count();
             if i % 4 == 0 {
                 assert!(
-                    event_state.should_trigger_event(),
+                    event_state.should_trigger_every(4),
                     "Should trigger at question {}",
                     i
                 );
                 // Simulate event activation
                 event_state.activate_event(GameEvent::DoublePoints);
                 assert!(
-                    !event_state.should_trigger_event(),
+                    !event_state.should_trigger_every(4),
                     "Should not trigger when event is active"
                 );
                 event_state.deactivate_event();
             } else {
                 assert!(
-                    !event_state.should_trigger_event(),
+                    !event_state.should_trigger_every(4),
                     "Should not trigger at question {}",
                     i
                 );
@@ -587,6 +959,8 @@ mod tests {
         let mut board = Board::default();
         board.categories = vec![Category {
             name: "Test Category".to_string(),
+            inverted: false,
+            intro: None,
             clues: vec![
                 Clue {
                     id: 1,
@@ -595,6 +969,13 @@ mod tests {
                     answer: "A1".to_string(),
                     revealed: false,
                     solved: false,
+                    difficulty: None,
+                    host_note: None,
+                    no_flash: false,
+                    bonus_attempt: false,
+                    unlock_round: None,
+                    media: None,
+                    alt_answers: Vec::new(),
                 },
                 Clue {
                     id: 2,
@@ -603,6 +984,13 @@ mod tests {
                     answer: "A2".to_string(),
                     revealed: false,
                     solved: false,
+                    difficulty: None,
+                    host_note: None,
+                    no_flash: false,
+                    bonus_attempt: false,
+                    unlock_round: None,
+                    media: None,
+                    alt_answers: Vec::new(),
                 },
                 Clue {
                     id: 3,
@@ -611,6 +999,13 @@ mod tests {
                     answer: "A3".to_string(),
                     revealed: false,
                     solved: false,
+                    difficulty: None,
+                    host_note: None,
+                    no_flash: false,
+                    bonus_attempt: false,
+                    unlock_round: None,
+                    media: None,
+                    alt_answers: Vec::new(),
                 },
                 Clue {
                     id: 4,
@@ -619,6 +1014,13 @@ mod tests {
                     answer: "A4".to_string(),
                     revealed: false,
                     solved: false,
+                    difficulty: None,
+                    host_note: None,
+                    no_flash: false,
+                    bonus_attempt: false,
+                    unlock_round: None,
+                    media: None,
+                    alt_answers: Vec::new(),
                 },
                 Clue {
                     id: 5,
@@ -627,6 +1029,13 @@ mod tests {
                     answer: "A5".to_string(),
                     revealed: false,
                     solved: false,
+                    difficulty: None,
+                    host_note: None,
+                    no_flash: false,
+                    bonus_attempt: false,
+                    unlock_round: None,
+                    media: None,
+                    alt_answers: Vec::new(),
                 },
             ],
         }];
@@ -674,6 +1083,8 @@ mod tests {
         let mut board = Board::default();
         board.categories = vec![Category {
             name: "Test Category".to_string(),
+            inverted: false,
+            intro: None,
             clues: vec![Clue {
                 id: 1,
                 points: 100,
@@ -681,6 +1092,13 @@ mod tests {
                 answer: "A1".to_string(),
                 revealed: false,
                 solved: false,
+                difficulty: None,
+                host_note: None,
+                no_flash: false,
+                bonus_attempt: false,
+                unlock_round: None,
+                media: None,
+                alt_answers: Vec::new(),
             }],
         }];
 
@@ -722,6 +1140,8 @@ mod tests {
         let mut board = Board::default();
         board.categories = vec![Category {
             name: "Test Category".to_string(),
+            inverted: false,
+            intro: None,
             clues: vec![Clue {
                 id: 1,
                 points: 100,
@@ -729,6 +1149,13 @@ mod tests {
                 answer: "A1".to_string(),
                 revealed: false,
                 solved: false,
+                difficulty: None,
+                host_note: None,
+                no_flash: false,
+                bonus_attempt: false,
+                unlock_round: None,
+                media: None,
+                alt_answers: Vec::new(),
             }],
         }];
 
@@ -760,6 +1187,8 @@ mod tests {
         let mut board = Board::default();
         board.categories = vec![Category {
             name: "Test Category".to_string(),
+            inverted: false,
+            intro: None,
             clues: vec![Clue {
                 id: 1,
                 points: 100,
@@ -767,6 +1196,13 @@ mod tests {
                 answer: "Original Answer".to_string(),
                 revealed: false,
                 solved: false,
+                difficulty: None,
+                host_note: None,
+                no_flash: false,
+                bonus_attempt: false,
+                unlock_round: None,
+                media: None,
+                alt_answers: Vec::new(),
             }],
         }];
 
@@ -814,6 +1250,8 @@ mod tests {
         let mut board = Board::default();
         board.categories = vec![Category {
             name: "Cat".to_string(),
+            inverted: false,
+            intro: None,
             clues: vec![Clue {
                 id: 1,
                 points: 100,
@@ -821,6 +1259,13 @@ mod tests {
                 answer: "A".to_string(),
                 revealed: false,
                 solved: false,
+                difficulty: None,
+                host_note: None,
+                no_flash: false,
+                bonus_attempt: false,
+                unlock_round: None,
+                media: None,
+                alt_answers: Vec::new(),
             }],
         }];
 
@@ -867,6 +1312,8 @@ mod tests {
         let mut board = Board::default();
         board.categories = vec![Category {
             name: "Cat".to_string(),
+            inverted: false,
+            intro: None,
             clues: vec![Clue {
                 id: 1,
                 points: 100,
@@ -874,6 +1321,13 @@ mod tests {
                 answer: "A".to_string(),
                 revealed: false,
                 solved: false,
+                difficulty: None,
+                host_note: None,
+                no_flash: false,
+                bonus_attempt: false,
+                unlock_round: None,
+                media: None,
+                alt_answers: Vec::new(),
             }],
         }];
 
@@ -1,4 +1,5 @@
 pub mod actions;
+pub mod buzzer;
 pub mod engine;
 pub mod events;
 pub mod rules;
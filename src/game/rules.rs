@@ -1,13 +1,130 @@
 use crate::game::actions::GameAction;
+use crate::game::events::DoublePointsEvent;
 use crate::game::state::{GameState, PlayPhase};
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
-#[derive(Debug)]
-pub struct GameRules;
+/// Which team picks the first clue when the game starts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FirstTeamPolicy {
+    /// The team that joined first (the original behavior)
+    #[default]
+    FirstAdded,
+    /// A random team, drawn from the engine's seedable RNG
+    Random,
+    /// A particular team by id
+    Specific(u32),
+}
+
+/// Saved with snapshots; fields missing from older saves take their defaults
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRules {
+    /// Fraction of a clue's value awarded for a successful steal (1.0 = full value)
+    pub steal_points_factor: f32,
+    /// Scales a clue's award and penalty while Reverse Question is active (1.0 = unchanged)
+    pub reverse_question_multiplier: f32,
+    /// Double Points also doubles a successful steal; off awards steals their usual value
+    pub double_points_on_steal: bool,
+    /// Most teams that can join a game; the board and steal UI get cramped beyond this
+    pub max_teams: usize,
+    /// Refund the owner's deduction when every stealer also misses
+    pub refund_owner_on_failed_steals: bool,
+    /// Who selects first on StartGame
+    pub first_team: FirstTeamPolicy,
+    /// Teams must take the cheapest unsolved clue in a category before pricier ones
+    pub require_ascending_selection: bool,
+    /// Print point values on board cells; off for a mystery board
+    pub show_cell_values: bool,
+    /// On a mystery board, also keep the value off the open clue until it resolves
+    pub hide_points_until_resolved: bool,
+    /// Awarded to the owner on a missed final answer, on top of the penalty (0 = none)
+    pub consolation_points: u32,
+    /// Randomize the column order on StartGame; the saved board keeps its order
+    pub shuffle_categories: bool,
+    /// A team that answers correctly picks again; control only moves on a miss
+    pub keep_turn_on_correct: bool,
+    /// A team that steals correctly picks next instead of the usual rotation
+    pub control_to_stealer: bool,
+    /// Random clues drawn at StartGame for a second attempt regardless of value (0 = off)
+    pub bonus_attempt_clues: usize,
+    /// Once every stealer has missed, the audience may claim the clue
+    pub audience_claims: bool,
+    /// An audience claim adds the clue's value to the audience's own score
+    pub audience_claim_scores: bool,
+}
 
 impl GameRules {
     pub fn new() -> Self {
-        Self
+        Self {
+            steal_points_factor: 1.0,
+            reverse_question_multiplier: 1.0,
+            double_points_on_steal: true,
+            max_teams: 8,
+            refund_owner_on_failed_steals: false,
+            first_team: FirstTeamPolicy::FirstAdded,
+            require_ascending_selection: false,
+            show_cell_values: true,
+            hide_points_until_resolved: false,
+            consolation_points: 0,
+            shuffle_categories: false,
+            keep_turn_on_correct: false,
+            control_to_stealer: false,
+            bonus_attempt_clues: 0,
+            audience_claims: false,
+            audience_claim_scores: false,
+        }
+    }
+
+    /// Set the steal award factor; negative factors are treated as zero
+    pub fn set_steal_points_factor(&mut self, factor: f32) {
+        self.steal_points_factor = factor.max(0.0);
+    }
+
+    /// Set the Reverse Question multiplier; negative multipliers are treated as zero
+    pub fn set_reverse_question_multiplier(&mut self, multiplier: f32) {
+        self.reverse_question_multiplier = multiplier.max(0.0);
+    }
+
+    /// Whether an open clue (Showing or Steal) shows its value. Only a mystery
+    /// board can hide it; with values printed on the cells there's nothing to hide.
+    pub fn show_points_before_resolved(&self) -> bool {
+        self.show_cell_values || !self.hide_points_until_resolved
+    }
+
+    /// Scale an award for a successful steal by the configured factor
+    pub fn steal_award(&self, points: i32) -> i32 {
+        (points as f32 * self.steal_points_factor).round() as i32
+    }
+
+    /// Scale an award or penalty on a reversed clue by the configured multiplier
+    pub fn reverse_award(&self, points: i32) -> i32 {
+        (points as f32 * self.reverse_question_multiplier).round() as i32
+    }
+
+    /// What a clue is actually worth right now: doubled while Double Points is
+    /// active (unless a steal is exempt), scaled by the reverse multiplier while
+    /// Reverse Question is, then by the steal factor when it's being stolen
+    pub fn effective_points(
+        &self,
+        base: u32,
+        double_points: bool,
+        reverse: bool,
+        steal: bool,
+    ) -> i32 {
+        let mut points = if double_points && (!steal || self.double_points_on_steal) {
+            DoublePointsEvent::calculate_points(base) as i32
+        } else {
+            base as i32
+        };
+        if reverse {
+            points = self.reverse_award(points);
+        }
+        if steal {
+            self.steal_award(points)
+        } else {
+            points
+        }
     }
 
     /// Check if a clue can be selected in the current game state
@@ -17,8 +134,29 @@ impl GameRules {
             return false;
         }
 
-        // Clue must exist and be available (not solved)
+        // Clue must exist, be available (not solved) and not gated to a later round
         state.is_clue_available(clue)
+            && state
+                .get_clue(clue)
+                .is_some_and(|c| !c.is_locked(state.round))
+    }
+
+    /// With ascending selection on, a clue may only be picked once every cheaper
+    /// clue in its category has been solved
+    pub fn is_selection_in_order(&self, state: &GameState, clue: (usize, usize)) -> bool {
+        if !self.require_ascending_selection {
+            return true;
+        }
+        let Some(category) = state.board.categories.get(clue.0) else {
+            return true;
+        };
+        let Some(target) = category.clues.get(clue.1) else {
+            return true;
+        };
+        !category
+            .clues
+            .iter()
+            .any(|c| !c.solved && !c.is_locked(state.round) && c.points < target.points)
     }
 
     /// Check if the game can be started
@@ -34,16 +172,21 @@ impl GameRules {
 
     /// Check if a team can be added
     pub fn can_add_team(&self, state: &GameState) -> bool {
-        // Can only add teams in lobby phase
-        matches!(state.phase, PlayPhase::Lobby)
+        // Can only add teams in lobby phase, up to the team cap
+        matches!(state.phase, PlayPhase::Lobby) && state.teams.len() < self.max_teams
     }
 
-    /// Generate the steal queue for a given clue, excluding the owner team
+    /// Generate the steal queue for a given clue, excluding the owner team and,
+    /// during a steal, every team that has already attempted the clue
     pub fn get_steal_queue(&self, state: &GameState, excluding_team: u32) -> VecDeque<u32> {
+        let attempted: &[u32] = match &state.phase {
+            PlayPhase::Steal { attempted, .. } => attempted,
+            _ => &[],
+        };
         let mut others: Vec<u32> = state
             .teams
             .iter()
-            .filter(|t| t.id != excluding_team)
+            .filter(|t| t.id != excluding_team && !attempted.contains(&t.id))
             .map(|t| t.id)
             .collect();
 
@@ -126,6 +269,15 @@ impl GameRules {
                     false
                 }
             }
+            GameAction::SelectionTimeout => {
+                // The clock can run out on whichever team is selecting
+                matches!(state.phase, PlayPhase::Selecting { .. })
+            }
+            GameAction::StealTimeout => {
+                // The clock can run out on whichever team is stealing
+                matches!(state.phase, PlayPhase::Steal { .. })
+            }
+            GameAction::BuzzIn { .. } => self.is_action_valid(state, action),
             GameAction::AnswerCorrect {
                 team_id: action_team_id,
                 ..
@@ -156,6 +308,7 @@ impl GameRules {
                 // Anyone can close a clue in resolved phase
                 matches!(state.phase, PlayPhase::Resolved { .. })
             }
+            GameAction::AudienceClaim { .. } => self.is_action_valid(state, action),
             GameAction::TriggerEvent { .. } => {
                 // Events can be triggered when no event is active
                 state.event_state.active_event.is_none()
@@ -184,6 +337,30 @@ impl GameRules {
                 // Manual points adjustment is allowed in any active game phase
                 !matches!(state.phase, PlayPhase::Lobby | PlayPhase::Finished)
             }
+            GameAction::Pause => {
+                // Intermission is only taken between clues
+                matches!(state.phase, PlayPhase::Selecting { .. })
+            }
+            GameAction::Resume => {
+                // Play picks back up only if there's something left to pick
+                matches!(state.phase, PlayPhase::Intermission)
+                    && !state.get_available_clues().is_empty()
+            }
+            GameAction::NextRound => self.is_action_valid(state, action),
+            GameAction::ClearTeams | GameAction::ShuffleTeams => {
+                matches!(state.phase, PlayPhase::Lobby)
+            }
+            GameAction::RemoveLastTeam => {
+                matches!(state.phase, PlayPhase::Lobby) && !state.teams.is_empty()
+            }
+            GameAction::SetStartingScore { .. } => {
+                // Handicaps are fixed once the game starts
+                matches!(state.phase, PlayPhase::Lobby)
+            }
+            GameAction::CaptureNote { .. } => self.is_action_valid(state, action),
+            GameAction::SubmitWager { .. } | GameAction::ResolveWager { .. } => {
+                self.is_action_valid(state, action)
+            }
         }
     }
 
@@ -202,6 +379,11 @@ impl GameRules {
                     false
                 }
             }
+            GameAction::SelectionTimeout => matches!(state.phase, PlayPhase::Selecting { .. }),
+            GameAction::StealTimeout => matches!(state.phase, PlayPhase::Steal { .. }),
+            GameAction::BuzzIn { team_id } => {
+                matches!(&state.phase, PlayPhase::Steal { queue, .. } if queue.contains(team_id))
+            }
             GameAction::AnswerCorrect { team_id, .. }
             | GameAction::AnswerIncorrect { team_id, .. } => {
                 if let PlayPhase::Showing { owner_team_id, .. } = state.phase {
@@ -220,6 +402,11 @@ impl GameRules {
             GameAction::CloseClue { .. } => {
                 matches!(state.phase, PlayPhase::Resolved { .. })
             }
+            GameAction::AudienceClaim { clue } => {
+                self.audience_claims
+                    && state.audience_claimable == Some(*clue)
+                    && matches!(state.phase, PlayPhase::Resolved { clue: resolved, .. } if resolved == *clue)
+            }
             GameAction::TriggerEvent { .. } => {
                 // Events can be triggered when no event is active
                 state.event_state.active_event.is_none()
@@ -236,6 +423,71 @@ impl GameRules {
             GameAction::PlayEventAnimation { .. } => false,
             GameAction::ReturnToConfig => true,
             GameAction::ManualPointsAdjustment { .. } => true,
+            GameAction::Pause => matches!(state.phase, PlayPhase::Selecting { .. }),
+            GameAction::Resume => {
+                matches!(state.phase, PlayPhase::Intermission)
+                    && !state.get_available_clues().is_empty()
+            }
+            GameAction::NextRound => matches!(state.phase, PlayPhase::Intermission),
+            GameAction::ClearTeams | GameAction::ShuffleTeams => {
+                matches!(state.phase, PlayPhase::Lobby)
+            }
+            GameAction::RemoveLastTeam => {
+                matches!(state.phase, PlayPhase::Lobby) && !state.teams.is_empty()
+            }
+            GameAction::SetStartingScore { team_id, .. } => {
+                matches!(state.phase, PlayPhase::Lobby)
+                    && state.teams.iter().any(|t| t.id == *team_id)
+            }
+            GameAction::CaptureNote { clue, .. } => {
+                matches!(state.phase, PlayPhase::Showing { clue: shown, .. } if shown == *clue)
+            }
+            GameAction::SubmitWager { team_id, .. } => {
+                // A team wagers once; after it's judged the wager is settled
+                matches!(state.phase, PlayPhase::Finished)
+                    && state.teams.iter().any(|t| t.id == *team_id)
+                    && state.wager_result(*team_id).is_none()
+            }
+            GameAction::ResolveWager { team_id, .. } => {
+                matches!(state.phase, PlayPhase::Finished) && state.wager_for(*team_id).is_some()
+            }
+        }
+    }
+
+    /// The wager a team with `score` may lock in: never negative and never more
+    /// than the team has. A team at or below zero has nothing to risk and is held to 0.
+    pub fn check_wager(score: i32, wager: i32) -> Result<i32, String> {
+        if wager < 0 {
+            return Err(format!("A wager can't be negative (got {})", wager));
         }
+        if score <= 0 {
+            return Ok(0);
+        }
+        if wager > score {
+            return Err(format!(
+                "A wager of {} is more than the team's {} points",
+                wager, score
+            ));
+        }
+        Ok(wager)
+    }
+}
+
+impl Default for GameRules {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Stand-in for the points label while the value is being kept secret
+pub const HIDDEN_POINTS_LABEL: &str = "??? pts";
+
+/// Points label for overlays, noting the multiplier when the effective value
+/// differs from the printed one, e.g. "400 pts (×2)"
+pub fn points_label(base: u32, effective: i32) -> String {
+    if base == 0 || effective == base as i32 {
+        return format!("{} pts", effective);
     }
+    let multiplier = ((effective as f32 / base as f32) * 100.0).round() / 100.0;
+    format!("{} pts (×{})", effective, multiplier)
 }
@@ -41,6 +41,7 @@ impl ScoringEngine {
             id: next_id,
             name,
             score: 0,
+            emoji: None,
         });
 
         next_id
@@ -66,14 +67,14 @@ impl ScoringEngine {
     }
 
     pub fn get_leaderboard(&self, teams: &[Team]) -> Vec<(u32, String, i32)> {
-        let mut leaderboard: Vec<(u32, String, i32)> = teams
-            .iter()
-            .map(|t| (t.id, t.name.clone(), t.score))
-            .collect();
+        let mut leaderboard: Vec<(u32, String, i32)> =
+            teams.iter().map(|t| (t.id, t.label(), t.score)).collect();
         leaderboard.sort_by(|a, b| b.2.cmp(&a.2));
         leaderboard
     }
 
+    /// Id of the team after `current_active`, wrapping to the first. A lone team
+    /// keeps the turn, and with no teams the current id comes back unchanged.
     pub fn rotate_active_team(&self, teams: &[Team], current_active: u32) -> u32 {
         if teams.is_empty() {
             return current_active;
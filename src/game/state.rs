@@ -1,4 +1,5 @@
 use std::collections::VecDeque;
+use std::time::{Duration, Instant, SystemTime};
 
 use serde::{Deserialize, Serialize};
 
@@ -22,6 +23,12 @@ pub enum PlayPhase {
         queue: VecDeque<u32>,
         current: u32,
         owner_team_id: u32,
+        /// Every team that has already had a go at this clue, owner first
+        #[serde(default)]
+        attempted: Vec<u32>,
+        /// Points actually taken from the owner for the miss; what a refund hands back
+        #[serde(default)]
+        owner_penalty: i32,
     },
     Resolved {
         clue: (usize, usize),
@@ -31,16 +38,102 @@ pub enum PlayPhase {
     Finished,
 }
 
+impl PlayPhase {
+    /// Board position of the clue in play: open while Showing or Steal, just judged when Resolved
+    pub fn clue(&self) -> Option<(usize, usize)> {
+        match self {
+            PlayPhase::Showing { clue, .. }
+            | PlayPhase::Steal { clue, .. }
+            | PlayPhase::Resolved { clue, .. } => Some(*clue),
+            _ => None,
+        }
+    }
+}
+
+/// Something `GameState::validate_and_repair` had to fix in a loaded game
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepairNote {
+    /// `active_team` didn't name a team and was moved to `to` (0 when there are no teams)
+    ActiveTeamReset { from: u32, to: u32 },
+    /// The saved phase couldn't be played from and was replaced
+    PhaseReset { reason: String },
+}
+
+impl std::fmt::Display for RepairNote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepairNote::ActiveTeamReset { from, to } => {
+                write!(f, "active team {} does not exist; using {}", from, to)
+            }
+            RepairNote::PhaseReset { reason } => write!(f, "phase reset: {}", reason),
+        }
+    }
+}
+
+/// One score change, in the order it happened
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreLogEntry {
+    pub team_id: u32,
+    pub delta: i32,
+    /// Clue the change came from; None for events and host adjustments
+    pub clue: Option<(usize, usize)>,
+    pub timestamp: SystemTime,
+}
+
+impl ScoreLogEntry {
+    pub fn new(team_id: u32, delta: i32, clue: Option<(usize, usize)>) -> Self {
+        Self {
+            team_id,
+            delta,
+            clue,
+            timestamp: SystemTime::now(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameState {
     pub board: Board,
     pub teams: Vec<Team>,
     pub phase: PlayPhase,
     pub active_team: u32,
+    #[serde(default)]
     pub surprise: SurpriseState,
     pub ui_map: UiMapping,
     #[serde(default)]
     pub event_state: EventState,
+    /// When the active team's selection clock runs out; only armed while selecting
+    #[serde(skip)]
+    pub selection_deadline: Option<Instant>,
+    /// When the current stealer's clock runs out; only armed during a steal
+    #[serde(skip)]
+    pub steal_deadline: Option<Instant>,
+    /// Chronological record of every score change
+    #[serde(default)]
+    pub score_log: Vec<ScoreLogEntry>,
+    /// Each team's score when the game started, so a replay starts from the same handicaps
+    #[serde(default)]
+    pub starting_scores: Vec<(u32, i32)>,
+    /// Points the audience has collected from clues nobody could steal
+    #[serde(default)]
+    pub audience_score: i32,
+    /// The resolved clue the audience may still claim, after every stealer missed
+    #[serde(default)]
+    pub audience_claimable: Option<(usize, usize)>,
+    /// Round of play, counting from 1; clues gated to a later round stay locked
+    #[serde(default = "first_round")]
+    pub round: u32,
+    /// Final wagers locked in once the board is finished, by team id; moved to
+    /// `resolved_wagers` as they're judged
+    #[serde(default)]
+    pub wagers: Vec<(u32, i32)>,
+    /// Judged final wagers: team id, the amount wagered and whether the answer was right
+    #[serde(default)]
+    pub resolved_wagers: Vec<(u32, i32, bool)>,
+}
+
+fn first_round() -> u32 {
+    1
 }
 
 impl GameState {
@@ -54,18 +147,228 @@ impl GameState {
             surprise: SurpriseState::default(),
             ui_map: UiMapping::identity(board.categories.len(), num_rows),
             event_state: EventState::default(),
+            selection_deadline: None,
+            steal_deadline: None,
+            score_log: Vec::new(),
+            starting_scores: Vec::new(),
+            audience_score: 0,
+            audience_claimable: None,
+            round: first_round(),
+            wagers: Vec::new(),
+            resolved_wagers: Vec::new(),
+        }
+    }
+
+    /// Drop presentation-only state so a saved game loads into a settled phase:
+    /// no animation marked as playing and no running selection clock. Active and
+    /// queued events are game state and are kept.
+    pub fn sanitize_for_save(&mut self) {
+        self.event_state.set_animation_playing(false);
+        self.selection_deadline = None;
+        self.steal_deadline = None;
+    }
+
+    /// Back to the lobby for a replay on the same board with the same teams: every
+    /// clue unplayed, scores at their starting values (zero if unknown), and events,
+    /// clocks and the score log cleared
+    pub fn reset_to_lobby(&mut self) {
+        for clue in self
+            .board
+            .categories
+            .iter_mut()
+            .flat_map(|c| c.clues.iter_mut())
+        {
+            clue.solved = false;
+            clue.revealed = false;
+        }
+        self.score_log.clear();
+        let starting_scores = std::mem::take(&mut self.starting_scores);
+        for team in &mut self.teams {
+            team.score = starting_scores
+                .iter()
+                .find(|(id, _)| *id == team.id)
+                .map(|(_, score)| *score)
+                .unwrap_or(0);
+        }
+        // Logged the same way SetStartingScore logs a handicap in the lobby
+        let handicaps: Vec<(u32, i32)> = self
+            .teams
+            .iter()
+            .filter(|t| t.score != 0)
+            .map(|t| (t.id, t.score))
+            .collect();
+        for (team_id, score) in handicaps {
+            self.log_score_change(team_id, score, None);
+        }
+        self.audience_score = 0;
+        self.audience_claimable = None;
+        self.wagers.clear();
+        self.resolved_wagers.clear();
+        self.round = first_round();
+        self.phase = PlayPhase::Lobby;
+        self.active_team = 0;
+        self.surprise = SurpriseState::default();
+        self.event_state = EventState::default();
+        self.selection_deadline = None;
+        self.steal_deadline = None;
+    }
+
+    /// Bring a loaded game back to something playable: `active_team` names a real
+    /// team and the phase only points at teams and open clues that exist. Anything
+    /// impossible drops back to the active team selecting. Returns what was changed.
+    pub fn validate_and_repair(&mut self) -> Vec<RepairNote> {
+        let mut notes = Vec::new();
+
+        if self.teams.is_empty() {
+            if self.active_team != 0 {
+                notes.push(RepairNote::ActiveTeamReset {
+                    from: self.active_team,
+                    to: 0,
+                });
+                self.active_team = 0;
+            }
+            if !matches!(self.phase, PlayPhase::Lobby | PlayPhase::Finished) {
+                notes.push(RepairNote::PhaseReset {
+                    reason: "no teams outside the lobby".to_string(),
+                });
+                self.phase = PlayPhase::Lobby;
+            }
+            return notes;
+        }
+
+        if self.get_team_by_id(self.active_team).is_none() {
+            let to = self.teams[0].id;
+            notes.push(RepairNote::ActiveTeamReset {
+                from: self.active_team,
+                to,
+            });
+            self.active_team = to;
         }
+
+        let open_clue = |clue: (usize, usize)| {
+            self.board
+                .categories
+                .get(clue.0)
+                .and_then(|cat| cat.clues.get(clue.1))
+                .is_some_and(|c| !c.solved)
+        };
+        let has_team = |id: u32| self.teams.iter().any(|t| t.id == id);
+        let problem = match &self.phase {
+            PlayPhase::Selecting { team_id } if !has_team(*team_id) => {
+                Some(format!("selecting team {} does not exist", team_id))
+            }
+            PlayPhase::Showing { clue, .. } | PlayPhase::Steal { clue, .. }
+                if !open_clue(*clue) =>
+            {
+                Some(format!("clue {:?} is missing or already solved", clue))
+            }
+            PlayPhase::Showing { owner_team_id, .. } if !has_team(*owner_team_id) => {
+                Some(format!("clue owner {} does not exist", owner_team_id))
+            }
+            PlayPhase::Steal { current, .. } if !has_team(*current) => {
+                Some(format!("stealing team {} does not exist", current))
+            }
+            PlayPhase::Resolved { clue, next_team_id }
+                if !has_team(*next_team_id)
+                    || self
+                        .board
+                        .categories
+                        .get(clue.0)
+                        .and_then(|cat| cat.clues.get(clue.1))
+                        .is_none() =>
+            {
+                Some(format!("resolved clue {:?} can't be closed", clue))
+            }
+            _ => None,
+        };
+        if let Some(reason) = problem {
+            notes.push(RepairNote::PhaseReset { reason });
+            self.phase = PlayPhase::Selecting {
+                team_id: self.active_team,
+            };
+        }
+
+        // Stealers queued from a save that no longer has them are skipped quietly
+        let team_ids: Vec<u32> = self.teams.iter().map(|t| t.id).collect();
+        if let PlayPhase::Steal { queue, .. } = &mut self.phase {
+            queue.retain(|id| team_ids.contains(id));
+        }
+
+        notes
+    }
+
+    pub fn log_score_change(&mut self, team_id: u32, delta: i32, clue: Option<(usize, usize)>) {
+        self.score_log
+            .push(ScoreLogEntry::new(team_id, delta, clue));
+    }
+
+    /// Most points the team won from a single clue (0 if none)
+    pub fn largest_single_clue(&self, team_id: u32) -> i32 {
+        self.score_log
+            .iter()
+            .filter(|e| e.team_id == team_id && e.clue.is_some())
+            .map(|e| e.delta)
+            .max()
+            .unwrap_or(0)
+            .max(0)
+    }
+
+    /// Highest score wins. Ties go to the team with the largest single clue,
+    /// then to whichever tied team reached its final score first.
+    pub fn determine_winner(&self) -> Option<u32> {
+        let top = self.teams.iter().map(|t| t.score).max()?;
+        let last_change = |team_id: u32| {
+            self.score_log
+                .iter()
+                .rposition(|e| e.team_id == team_id)
+                .unwrap_or(0)
+        };
+        self.teams
+            .iter()
+            .filter(|t| t.score == top)
+            .min_by_key(|t| {
+                (
+                    std::cmp::Reverse(self.largest_single_clue(t.id)),
+                    last_change(t.id),
+                )
+            })
+            .map(|t| t.id)
+    }
+
+    /// The final wager a team has locked in, if any
+    pub fn wager_for(&self, team_id: u32) -> Option<i32> {
+        self.wagers
+            .iter()
+            .find(|(id, _)| *id == team_id)
+            .map(|(_, wager)| *wager)
+    }
+
+    /// How a team's final wager was judged, if it has been: (wager, correct)
+    pub fn wager_result(&self, team_id: u32) -> Option<(i32, bool)> {
+        self.resolved_wagers
+            .iter()
+            .find(|(id, _, _)| *id == team_id)
+            .map(|(_, wager, correct)| (*wager, *correct))
     }
 
     pub fn get_team_by_id(&self, id: u32) -> Option<&Team> {
         self.teams.iter().find(|t| t.id == id)
     }
 
+    /// Teams ordered by score, highest first (ties keep join order)
+    pub fn standings(&self) -> Vec<&Team> {
+        let mut teams: Vec<&Team> = self.teams.iter().collect();
+        teams.sort_by_key(|t| std::cmp::Reverse(t.score));
+        teams
+    }
+
+    /// Clues the selecting team can pick right now: unsolved and not gated to a
+    /// later round
     pub fn get_available_clues(&self) -> Vec<(usize, usize)> {
         let mut available = Vec::new();
         for (cat_idx, category) in self.board.categories.iter().enumerate() {
             for (clue_idx, clue) in category.clues.iter().enumerate() {
-                if !clue.solved {
+                if !clue.solved && !clue.is_locked(self.round) {
                     available.push((cat_idx, clue_idx));
                 }
             }
@@ -73,6 +376,43 @@ impl GameState {
         available
     }
 
+    /// Earliest later round that unlocks an unsolved clue, if any are still gated
+    pub fn next_unlock_round(&self) -> Option<u32> {
+        self.board
+            .categories
+            .iter()
+            .flat_map(|c| c.clues.iter())
+            .filter(|clue| !clue.solved && clue.is_locked(self.round))
+            .filter_map(|clue| clue.unlock_round)
+            .min()
+    }
+
+    /// Whether every clue on the board is done, counting ones still locked
+    pub fn all_clues_solved(&self) -> bool {
+        self.board
+            .categories
+            .iter()
+            .flat_map(|c| c.clues.iter())
+            .all(|clue| clue.solved)
+    }
+
+    /// Round that "next round" moves to: the following one, or further on when
+    /// the rounds in between have nothing left to play
+    pub fn upcoming_round(&self) -> u32 {
+        let next = self.round + 1;
+        let opens_clues = self
+            .board
+            .categories
+            .iter()
+            .flat_map(|c| c.clues.iter())
+            .any(|clue| !clue.solved && !clue.is_locked(next));
+        if opens_clues {
+            next
+        } else {
+            self.next_unlock_round().unwrap_or(next)
+        }
+    }
+
     pub fn get_clue(&self, clue: (usize, usize)) -> Option<&Clue> {
         self.board.categories.get(clue.0)?.clues.get(clue.1)
     }
@@ -84,4 +424,30 @@ impl GameState {
             false
         }
     }
+
+    /// Start the selection clock for the active team if one isn't already running
+    pub fn arm_selection_clock(&mut self, limit: Duration) {
+        if matches!(self.phase, PlayPhase::Selecting { .. }) && self.selection_deadline.is_none() {
+            self.selection_deadline = Some(Instant::now() + limit);
+        }
+    }
+
+    /// Time left on the selection clock, or None when no clock is running
+    pub fn selection_time_remaining(&self) -> Option<Duration> {
+        self.selection_deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// Start the steal clock for the current stealer if one isn't already running
+    pub fn arm_steal_clock(&mut self, limit: Duration) {
+        if matches!(self.phase, PlayPhase::Steal { .. }) && self.steal_deadline.is_none() {
+            self.steal_deadline = Some(Instant::now() + limit);
+        }
+    }
+
+    /// Time left on the steal clock, or None when no clock is running
+    pub fn steal_time_remaining(&self) -> Option<Duration> {
+        self.steal_deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
 }
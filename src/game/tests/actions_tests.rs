@@ -1,5 +1,7 @@
 use super::*;
 use crate::game::actions::{FlashType, GameEffect};
+use crate::game::events::{EventTiming, GameEvent};
+use crate::game::rules::GameRules;
 use crate::game::{GameAction, GameActionResult};
 
 #[test]
@@ -145,3 +147,1262 @@ fn test_steal_attempt_correct() {
     assert!(stealing_team_score.is_some());
     assert!(stealing_team_score.unwrap() > 0);
 }
+
+#[test]
+fn test_selection_timeout_rotates_active_team() {
+    let mut engine = create_game_in_selecting_phase();
+    let first_team_id = engine.get_state().teams[0].id;
+    let second_team_id = engine.get_state().teams[1].id;
+    assert_eq!(engine.get_state().active_team, first_team_id);
+
+    engine
+        .get_state_mut()
+        .arm_selection_clock(std::time::Duration::from_secs(30));
+    assert!(engine.get_state().selection_deadline.is_some());
+
+    let result = engine.handle_action(GameAction::SelectionTimeout);
+    assert!(result.is_ok());
+
+    // Control moves on but the game stays in selecting, with the clock cleared for re-arming
+    assert_eq!(engine.get_state().active_team, second_team_id);
+    assert!(matches!(
+        engine.get_phase(),
+        PlayPhase::Selecting { team_id } if *team_id == second_team_id
+    ));
+    assert!(engine.get_state().selection_deadline.is_none());
+}
+
+#[test]
+fn test_selection_timeout_outside_selecting_fails() {
+    let mut engine = create_test_game_with_teams();
+
+    let result = engine.handle_action(GameAction::SelectionTimeout);
+    assert!(result.is_err());
+    assert!(matches!(engine.get_phase(), PlayPhase::Lobby));
+}
+
+/// Drive a fresh game on a 1x4 board to a steal on the 400-point clue and return
+/// the stealing team's score after a correct steal
+fn steal_400_point_clue(factor: f32, double_points: bool) -> i32 {
+    steal_400_point_clue_with(factor, double_points, true)
+}
+
+fn steal_400_point_clue_with(factor: f32, double_points: bool, double_on_steal: bool) -> i32 {
+    let mut engine =
+        crate::game::GameEngine::new(crate::core::Board::default_with_dimensions(1, 4));
+    let _ = engine.handle_action(GameAction::AddTeam {
+        name: "Team 1".to_string(),
+    });
+    let _ = engine.handle_action(GameAction::AddTeam {
+        name: "Team 2".to_string(),
+    });
+    let _ = engine.handle_action(GameAction::StartGame);
+    engine.rules_mut().set_steal_points_factor(factor);
+    engine.rules_mut().double_points_on_steal = double_on_steal;
+
+    let clue = (0, 3);
+    let owner_team_id = engine.get_state().teams[0].id;
+    let stealing_team_id = engine.get_state().teams[1].id;
+
+    let _ = engine.handle_action(GameAction::SelectClue {
+        clue,
+        team_id: owner_team_id,
+    });
+    let _ = engine.handle_action(GameAction::AnswerIncorrect {
+        clue,
+        team_id: owner_team_id,
+    });
+    if double_points {
+        engine
+            .get_state_mut()
+            .event_state
+            .activate_event(crate::game::events::GameEvent::DoublePoints);
+    }
+
+    let result = engine.handle_action(GameAction::StealAttempt {
+        clue,
+        team_id: stealing_team_id,
+        correct: true,
+    });
+    assert!(result.is_ok());
+    engine.get_team_score(stealing_team_id).unwrap()
+}
+
+#[test]
+fn test_steal_points_factor_defaults_to_full_value() {
+    let engine = create_test_game_engine();
+    assert_eq!(engine.rules().steal_points_factor, 1.0);
+
+    assert_eq!(steal_400_point_clue(1.0, false), 400);
+}
+
+#[test]
+fn test_steal_points_factor_half_value() {
+    assert_eq!(steal_400_point_clue(0.5, false), 200);
+}
+
+#[test]
+fn test_steal_points_factor_composes_with_double_points() {
+    assert_eq!(steal_400_point_clue(1.0, true), 800);
+    assert_eq!(steal_400_point_clue(0.5, true), 400);
+}
+
+#[test]
+fn test_double_points_on_steal_defaults_on() {
+    assert!(GameRules::new().double_points_on_steal);
+    assert_eq!(steal_400_point_clue_with(1.0, true, true), 800);
+}
+
+#[test]
+fn test_steal_during_double_points_gets_base_value_when_exempt() {
+    assert_eq!(steal_400_point_clue_with(1.0, true, false), 400);
+    // The steal factor still applies to the undoubled value
+    assert_eq!(steal_400_point_clue_with(0.5, true, false), 200);
+}
+
+/// Answer the 100-point clue correctly while Reverse Question is active and
+/// return the answering team's score
+fn answer_reversed_100_point_clue(multiplier: f32) -> i32 {
+    let mut engine =
+        crate::game::GameEngine::new(crate::core::Board::default_with_dimensions(1, 4));
+    let _ = engine.handle_action(GameAction::AddTeam {
+        name: "Team 1".to_string(),
+    });
+    let _ = engine.handle_action(GameAction::StartGame);
+    engine
+        .rules_mut()
+        .set_reverse_question_multiplier(multiplier);
+    engine
+        .get_state_mut()
+        .event_state
+        .activate_event(crate::game::events::GameEvent::ReverseQuestion);
+
+    let clue = (0, 0);
+    let team_id = engine.get_state().teams[0].id;
+    let _ = engine.handle_action(GameAction::SelectClue { clue, team_id });
+    let result = engine.handle_action(GameAction::AnswerCorrect { clue, team_id });
+    assert!(result.is_ok());
+    engine.get_team_score(team_id).unwrap()
+}
+
+#[test]
+fn test_reverse_question_multiplier_defaults_to_normal_points() {
+    let engine = create_test_game_engine();
+    assert_eq!(engine.rules().reverse_question_multiplier, 1.0);
+
+    assert_eq!(answer_reversed_100_point_clue(1.0), 100);
+}
+
+#[test]
+fn test_reverse_question_multiplier_scales_award() {
+    assert_eq!(answer_reversed_100_point_clue(1.5), 150);
+}
+
+/// Play a 3-team game where the owner misses clue (0, 1) and both stealers miss too,
+/// returning the engine and the owner's id
+fn all_steals_fail(refund: bool) -> (crate::game::GameEngine, u32) {
+    let mut engine = create_test_game_with_teams();
+    let _ = engine.handle_action(GameAction::AddTeam {
+        name: "Team 3".to_string(),
+    });
+    let _ = engine.handle_action(GameAction::StartGame);
+    engine.rules_mut().refund_owner_on_failed_steals = refund;
+
+    let clue = (0, 1);
+    let owner_team_id = engine.get_state().teams[0].id;
+    let _ = engine.handle_action(GameAction::SelectClue {
+        clue,
+        team_id: owner_team_id,
+    });
+    let _ = engine.handle_action(GameAction::AnswerIncorrect {
+        clue,
+        team_id: owner_team_id,
+    });
+    assert_eq!(engine.get_team_score(owner_team_id), Some(-200));
+
+    while let PlayPhase::Steal { current, .. } = engine.get_phase() {
+        let current = *current;
+        let result = engine.handle_action(GameAction::StealAttempt {
+            clue,
+            team_id: current,
+            correct: false,
+        });
+        assert!(result.is_ok());
+    }
+    assert!(matches!(engine.get_phase(), PlayPhase::Resolved { .. }));
+    (engine, owner_team_id)
+}
+
+#[test]
+fn test_failed_steals_refund_owner_when_enabled() {
+    let (engine, owner_team_id) = all_steals_fail(true);
+    assert_eq!(engine.get_team_score(owner_team_id), Some(0));
+    // Stealers are never charged for missing
+    assert!(engine.get_state().teams[1..].iter().all(|t| t.score == 0));
+}
+
+#[test]
+fn test_failed_steal_refund_matches_the_deduction() {
+    let mut engine = create_test_game_with_teams();
+    let _ = engine.handle_action(GameAction::StartGame);
+    engine.rules_mut().refund_owner_on_failed_steals = true;
+    let clue = (0, 1);
+    let owner_team_id = engine.get_state().active_team;
+    let _ = engine.handle_action(GameAction::SelectClue {
+        clue,
+        team_id: owner_team_id,
+    });
+    let _ = engine.handle_action(GameAction::AnswerIncorrect {
+        clue,
+        team_id: owner_team_id,
+    });
+    assert_eq!(engine.get_team_score(owner_team_id), Some(-200));
+
+    // Double Points starting mid-steal doesn't double the refund
+    engine
+        .get_state_mut()
+        .event_state
+        .activate_event(GameEvent::DoublePoints);
+    while let PlayPhase::Steal { current, .. } = engine.get_phase() {
+        let current = *current;
+        let _ = engine.handle_action(GameAction::StealAttempt {
+            clue,
+            team_id: current,
+            correct: false,
+        });
+    }
+    assert_eq!(engine.get_team_score(owner_team_id), Some(0));
+}
+
+#[test]
+fn test_failed_steals_keep_owner_penalty_by_default() {
+    let (engine, owner_team_id) = all_steals_fail(false);
+    assert_eq!(engine.get_team_score(owner_team_id), Some(-200));
+}
+
+/// Select (0, 1) and let the owner and every stealer miss, with audience claims on or off
+fn exhaust_steals_with_audience(claims: bool) -> GameEngine {
+    let mut engine = create_test_game_with_teams();
+    let _ = engine.handle_action(GameAction::StartGame);
+    engine.rules_mut().audience_claims = claims;
+    engine.rules_mut().audience_claim_scores = true;
+
+    let clue = (0, 1);
+    let owner_team_id = engine.get_state().active_team;
+    let _ = engine.handle_action(GameAction::SelectClue {
+        clue,
+        team_id: owner_team_id,
+    });
+    let _ = engine.handle_action(GameAction::AnswerIncorrect {
+        clue,
+        team_id: owner_team_id,
+    });
+    while let PlayPhase::Steal { current, .. } = engine.get_phase() {
+        let current = *current;
+        let _ = engine.handle_action(GameAction::StealAttempt {
+            clue,
+            team_id: current,
+            correct: false,
+        });
+    }
+    engine
+}
+
+#[test]
+fn test_audience_claims_an_exhausted_steal() {
+    let mut engine = exhaust_steals_with_audience(true);
+    let clue = (0, 1);
+    let scores_before: Vec<i32> = engine.get_state().teams.iter().map(|t| t.score).collect();
+    let next_team = match engine.get_phase() {
+        PlayPhase::Resolved { next_team_id, .. } => *next_team_id,
+        other => panic!("expected Resolved, got {:?}", other),
+    };
+    assert_eq!(engine.get_state().audience_claimable, Some(clue));
+    assert_eq!(engine.get_state().audience_score, 0);
+
+    assert!(
+        engine
+            .handle_action(GameAction::AudienceClaim { clue })
+            .is_ok()
+    );
+    assert_eq!(engine.get_state().audience_claimable, None);
+    assert_eq!(engine.get_state().audience_score, 200);
+    // Real teams keep their scores and the rotation is untouched
+    let scores_after: Vec<i32> = engine.get_state().teams.iter().map(|t| t.score).collect();
+    assert_eq!(scores_before, scores_after);
+    assert!(matches!(
+        engine.get_phase(),
+        PlayPhase::Resolved { next_team_id, .. } if *next_team_id == next_team
+    ));
+
+    // One claim per clue
+    assert!(
+        engine
+            .handle_action(GameAction::AudienceClaim { clue })
+            .is_err()
+    );
+}
+
+#[test]
+fn test_audience_claim_needs_the_rule() {
+    let mut engine = exhaust_steals_with_audience(false);
+    assert!(
+        engine
+            .handle_action(GameAction::AudienceClaim { clue: (0, 1) })
+            .is_err()
+    );
+    assert_eq!(engine.get_state().audience_score, 0);
+}
+
+/// Owner misses clue (0, 1) with the given consolation and clue value;
+/// returns the engine, now in the steal phase, and the owner's id
+fn missed_with_consolation(consolation: u32, points: u32) -> (GameEngine, u32) {
+    let mut engine = create_game_in_selecting_phase();
+    engine.rules_mut().consolation_points = consolation;
+    engine.get_state_mut().board.categories[0].clues[1].points = points;
+    let clue = (0, 1);
+    let owner_team_id = engine.get_state().teams[0].id;
+    let _ = engine.handle_action(GameAction::SelectClue {
+        clue,
+        team_id: owner_team_id,
+    });
+    let _ = engine.handle_action(GameAction::AnswerIncorrect {
+        clue,
+        team_id: owner_team_id,
+    });
+    assert!(matches!(engine.get_phase(), PlayPhase::Steal { .. }));
+    (engine, owner_team_id)
+}
+
+#[test]
+fn test_consolation_without_penalty_raises_score() {
+    // A zero-value clue carries no penalty, leaving only the consolation
+    let (engine, owner_team_id) = missed_with_consolation(50, 0);
+    assert_eq!(engine.get_team_score(owner_team_id), Some(50));
+}
+
+#[test]
+fn test_consolation_nets_against_penalty() {
+    let (mut engine, owner_team_id) = missed_with_consolation(50, 200);
+    assert_eq!(engine.get_team_score(owner_team_id), Some(-150));
+    let deltas: Vec<i32> = engine
+        .get_state()
+        .score_log
+        .iter()
+        .map(|e| e.delta)
+        .collect();
+    assert_eq!(deltas, vec![-200, 50]);
+
+    // Stealers missing never earn it
+    let PlayPhase::Steal { current, .. } = engine.get_phase() else {
+        panic!("expected steal phase");
+    };
+    let stealer = *current;
+    let _ = engine.handle_action(GameAction::StealAttempt {
+        clue: (0, 1),
+        team_id: stealer,
+        correct: false,
+    });
+    assert_eq!(engine.get_team_score(stealer), Some(0));
+}
+
+#[test]
+fn test_consolation_defaults_to_zero() {
+    let (engine, owner_team_id) = missed_with_consolation(GameRules::new().consolation_points, 200);
+    assert_eq!(engine.get_team_score(owner_team_id), Some(-200));
+}
+
+#[test]
+fn test_ascending_selection_requires_cheaper_clue_first() {
+    let mut engine =
+        crate::game::GameEngine::new(crate::core::Board::default_with_dimensions(2, 3));
+    let _ = engine.handle_action(GameAction::AddTeam {
+        name: "Team 1".to_string(),
+    });
+    let _ = engine.handle_action(GameAction::StartGame);
+    engine.rules_mut().require_ascending_selection = true;
+    let team_id = engine.get_state().teams[0].id;
+
+    let result = engine.handle_action(GameAction::SelectClue {
+        clue: (0, 2),
+        team_id,
+    });
+    match result {
+        Err(crate::game::actions::GameError::InvalidAction { reason, .. }) => {
+            assert!(reason.contains("lower-value clue"));
+        }
+        other => panic!("expected ordering error, got {:?}", other),
+    }
+    assert!(matches!(engine.get_phase(), PlayPhase::Selecting { .. }));
+
+    // Other categories are unaffected
+    assert!(
+        engine
+            .rules()
+            .is_selection_in_order(engine.get_state(), (1, 0))
+    );
+
+    // Once rows 0 and 1 are solved, row 2 opens up
+    for clue in [(0, 0), (0, 1)] {
+        engine.get_state_mut().board.categories[clue.0].clues[clue.1].solved = true;
+    }
+    let result = engine.handle_action(GameAction::SelectClue {
+        clue: (0, 2),
+        team_id,
+    });
+    assert!(result.is_ok());
+    assert!(matches!(engine.get_phase(), PlayPhase::Showing { .. }));
+}
+
+#[test]
+fn test_ascending_selection_allowed_after_row_zero_solved() {
+    let mut engine = create_game_in_selecting_phase();
+    engine.rules_mut().require_ascending_selection = true;
+    let team_id = engine.get_state().active_team;
+
+    assert!(
+        engine
+            .handle_action(GameAction::SelectClue {
+                clue: (0, 1),
+                team_id,
+            })
+            .is_err()
+    );
+
+    engine.get_state_mut().board.categories[0].clues[0].solved = true;
+    assert!(
+        engine
+            .handle_action(GameAction::SelectClue {
+                clue: (0, 1),
+                team_id,
+            })
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_ascending_selection_skips_locked_cheaper_clue() {
+    let mut engine = create_game_in_selecting_phase();
+    engine.rules_mut().require_ascending_selection = true;
+    engine.get_state_mut().board.categories[0].clues[0].unlock_round = Some(2);
+    let team_id = engine.get_state().active_team;
+
+    // The cheaper clue is held back for round 2, so it can't block this one
+    assert!(
+        engine
+            .handle_action(GameAction::SelectClue {
+                clue: (0, 1),
+                team_id,
+            })
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_starting_score_handicap_carries_into_scoring() {
+    let mut engine = create_test_game_with_teams();
+    let team_id = engine.get_state().teams[1].id;
+
+    let result = engine.handle_action(GameAction::SetStartingScore {
+        team_id,
+        score: 500,
+    });
+    assert!(result.is_ok());
+    assert_eq!(engine.get_team_score(team_id), Some(500));
+
+    let _ = engine.handle_action(GameAction::StartGame);
+    let owner_team_id = engine.get_state().teams[0].id;
+    let _ = engine.handle_action(GameAction::SelectClue {
+        clue: (0, 0),
+        team_id: owner_team_id,
+    });
+    let _ = engine.handle_action(GameAction::AnswerIncorrect {
+        clue: (0, 0),
+        team_id: owner_team_id,
+    });
+    let _ = engine.handle_action(GameAction::StealAttempt {
+        clue: (0, 0),
+        team_id,
+        correct: true,
+    });
+    assert_eq!(engine.get_team_score(team_id), Some(600));
+}
+
+#[test]
+fn test_starting_score_only_in_lobby() {
+    let mut engine = create_game_in_selecting_phase();
+    let team_id = engine.get_state().teams[0].id;
+
+    let result = engine.handle_action(GameAction::SetStartingScore {
+        team_id,
+        score: 500,
+    });
+    assert!(result.is_err());
+    assert_eq!(engine.get_team_score(team_id), Some(0));
+}
+
+#[test]
+fn test_pause_from_selecting_enters_intermission() {
+    let mut engine = create_game_in_selecting_phase();
+    let _ = engine.handle_action(GameAction::SelectionTimeout);
+    let active_team = engine.get_state().active_team;
+
+    let result = engine.handle_action(GameAction::Pause);
+    assert!(result.is_ok());
+    assert!(matches!(engine.get_phase(), PlayPhase::Intermission));
+    // Clues can't be picked while paused
+    assert!(
+        engine
+            .handle_action(GameAction::SelectClue {
+                clue: (0, 0),
+                team_id: active_team,
+            })
+            .is_err()
+    );
+
+    let result = engine.handle_action(GameAction::Resume);
+    assert!(result.is_ok());
+    assert!(matches!(
+        engine.get_phase(),
+        PlayPhase::Selecting { team_id } if *team_id == active_team
+    ));
+    assert_eq!(engine.get_state().active_team, active_team);
+}
+
+#[test]
+fn test_pause_and_resume_rejected_in_wrong_phase() {
+    let mut engine = create_test_game_with_teams();
+    assert!(engine.handle_action(GameAction::Pause).is_err());
+
+    let mut engine = create_game_in_selecting_phase();
+    assert!(engine.handle_action(GameAction::Resume).is_err());
+    assert!(matches!(engine.get_phase(), PlayPhase::Selecting { .. }));
+}
+
+#[test]
+fn test_clear_teams_only_in_lobby() {
+    let mut engine = create_test_game_with_teams();
+    assert!(engine.handle_action(GameAction::ClearTeams).is_ok());
+    assert_eq!(engine.team_count(), 0);
+    assert_eq!(engine.get_state().active_team, 0);
+
+    // A fresh team becomes the active one again
+    let _ = engine.handle_action(GameAction::AddTeam {
+        name: "Solo".to_string(),
+    });
+    let solo_id = engine.get_state().teams[0].id;
+    assert_eq!(engine.get_state().active_team, solo_id);
+
+    let mut engine = create_game_in_selecting_phase();
+    assert!(engine.handle_action(GameAction::ClearTeams).is_err());
+    assert_eq!(engine.team_count(), 2);
+}
+
+#[test]
+fn test_clear_teams_leaves_empty_roster_with_no_active_team() {
+    let mut engine = create_test_game_with_teams();
+    assert_ne!(engine.get_state().active_team, 0);
+
+    assert!(engine.handle_action(GameAction::ClearTeams).is_ok());
+    assert!(engine.get_state().teams.is_empty());
+    assert_eq!(engine.get_state().active_team, 0);
+    // Nothing left to remove afterwards
+    assert!(engine.handle_action(GameAction::RemoveLastTeam).is_err());
+}
+
+#[test]
+fn test_remove_last_team_hands_active_team_back() {
+    let mut engine = create_test_game_with_teams();
+    let first_id = engine.get_state().teams[0].id;
+    let last_id = engine.get_state().teams[1].id;
+    engine.get_state_mut().active_team = last_id;
+
+    assert!(engine.handle_action(GameAction::RemoveLastTeam).is_ok());
+    assert_eq!(engine.team_count(), 1);
+    assert_eq!(engine.get_state().active_team, first_id);
+
+    assert!(engine.handle_action(GameAction::RemoveLastTeam).is_ok());
+    assert_eq!(engine.get_state().active_team, 0);
+
+    let mut engine = create_game_in_selecting_phase();
+    assert!(engine.handle_action(GameAction::RemoveLastTeam).is_err());
+    assert_eq!(engine.team_count(), 2);
+}
+
+#[test]
+fn test_score_log_records_answers_and_steals_in_order() {
+    let mut engine = create_game_in_selecting_phase();
+    let first = engine.get_state().active_team;
+    let other = engine
+        .get_state()
+        .teams
+        .iter()
+        .map(|t| t.id)
+        .find(|id| *id != first)
+        .unwrap();
+
+    // First team answers (0,0) correctly
+    let _ = engine.handle_action(GameAction::SelectClue {
+        clue: (0, 0),
+        team_id: first,
+    });
+    let _ = engine.handle_action(GameAction::AnswerCorrect {
+        clue: (0, 0),
+        team_id: first,
+    });
+    let next = engine.get_state().active_team;
+    let _ = engine.handle_action(GameAction::CloseClue {
+        clue: (0, 0),
+        next_team_id: next,
+    });
+
+    // Second team misses (1,0) and the first team steals it
+    let _ = engine.handle_action(GameAction::SelectClue {
+        clue: (1, 0),
+        team_id: other,
+    });
+    let _ = engine.handle_action(GameAction::AnswerIncorrect {
+        clue: (1, 0),
+        team_id: other,
+    });
+    let _ = engine.handle_action(GameAction::StealAttempt {
+        clue: (1, 0),
+        team_id: first,
+        correct: true,
+    });
+
+    let log: Vec<_> = engine
+        .get_state()
+        .score_log
+        .iter()
+        .map(|e| (e.team_id, e.delta, e.clue))
+        .collect();
+    assert_eq!(
+        log,
+        vec![
+            (first, 100, Some((0, 0))),
+            (other, -100, Some((1, 0))),
+            (first, 100, Some((1, 0))),
+        ]
+    );
+    let log = &engine.get_state().score_log;
+    assert!(log.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+}
+
+fn inverted_game() -> (GameEngine, u32, u32) {
+    let mut board = create_test_board();
+    board.categories[0].inverted = true;
+    let mut engine = GameEngine::new(board);
+    let _ = engine.handle_action(GameAction::AddTeam {
+        name: "Team 1".to_string(),
+    });
+    let _ = engine.handle_action(GameAction::AddTeam {
+        name: "Team 2".to_string(),
+    });
+    let _ = engine.handle_action(GameAction::StartGame);
+    let first = engine.get_state().active_team;
+    let other = engine
+        .get_state()
+        .teams
+        .iter()
+        .map(|t| t.id)
+        .find(|id| *id != first)
+        .unwrap();
+    let _ = engine.handle_action(GameAction::SelectClue {
+        clue: (0, 1),
+        team_id: first,
+    });
+    (engine, first, other)
+}
+
+#[test]
+fn test_inverted_category_correct_answer_deducts() {
+    let (mut engine, first, _) = inverted_game();
+
+    let _ = engine.handle_action(GameAction::AnswerCorrect {
+        clue: (0, 1),
+        team_id: first,
+    });
+
+    assert_eq!(engine.get_team_score(first), Some(-200));
+}
+
+#[test]
+fn test_inverted_category_wrong_answer_adds() {
+    let (mut engine, first, other) = inverted_game();
+
+    let _ = engine.handle_action(GameAction::AnswerIncorrect {
+        clue: (0, 1),
+        team_id: first,
+    });
+    assert_eq!(engine.get_team_score(first), Some(200));
+
+    // A successful steal in a minefield costs the stealer too
+    let _ = engine.handle_action(GameAction::StealAttempt {
+        clue: (0, 1),
+        team_id: other,
+        correct: true,
+    });
+    assert_eq!(engine.get_team_score(other), Some(-200));
+}
+
+/// 3-team game where the owner and the first stealer have both missed clue (0, 1);
+/// returns the engine and the first stealer's id
+fn first_steal_missed() -> (GameEngine, u32) {
+    let mut engine = create_test_game_with_teams();
+    let _ = engine.handle_action(GameAction::AddTeam {
+        name: "Team 3".to_string(),
+    });
+    let _ = engine.handle_action(GameAction::StartGame);
+
+    let clue = (0, 1);
+    let owner_team_id = engine.get_state().teams[0].id;
+    let _ = engine.handle_action(GameAction::SelectClue {
+        clue,
+        team_id: owner_team_id,
+    });
+    let _ = engine.handle_action(GameAction::AnswerIncorrect {
+        clue,
+        team_id: owner_team_id,
+    });
+    let PlayPhase::Steal { current, .. } = engine.get_phase() else {
+        panic!("expected steal phase");
+    };
+    let first_stealer = *current;
+    let _ = engine.handle_action(GameAction::StealAttempt {
+        clue,
+        team_id: first_stealer,
+        correct: false,
+    });
+    (engine, first_stealer)
+}
+
+#[test]
+fn test_steal_queue_rebuild_excludes_attempted_teams() {
+    let (engine, first_stealer) = first_steal_missed();
+    let owner_team_id = engine.get_state().teams[0].id;
+    let PlayPhase::Steal {
+        attempted, current, ..
+    } = engine.get_phase()
+    else {
+        panic!("expected steal phase");
+    };
+    assert_eq!(attempted, &vec![owner_team_id, first_stealer]);
+
+    // Re-invoking the rules mid-steal never brings back a team that already tried
+    let rebuilt = GameRules::new().get_steal_queue(engine.get_state(), owner_team_id);
+    assert!(!rebuilt.contains(&first_stealer));
+    assert!(!rebuilt.contains(&owner_team_id));
+    assert_eq!(rebuilt, vec![*current]);
+}
+
+#[test]
+fn test_attempted_team_skipped_if_pushed_back_into_queue() {
+    let (mut engine, first_stealer) = first_steal_missed();
+    let PlayPhase::Steal { current, queue, .. } = &mut engine.state.phase else {
+        panic!("expected steal phase");
+    };
+    let second_stealer = *current;
+    queue.push_back(first_stealer);
+
+    let _ = engine.handle_action(GameAction::StealAttempt {
+        clue: (0, 1),
+        team_id: second_stealer,
+        correct: false,
+    });
+    assert!(matches!(engine.get_phase(), PlayPhase::Resolved { .. }));
+}
+
+#[test]
+fn test_steal_timeout_advances_to_next_stealer() {
+    let mut engine = create_test_game_with_teams();
+    let _ = engine.handle_action(GameAction::AddTeam {
+        name: "Team 3".to_string(),
+    });
+    let _ = engine.handle_action(GameAction::StartGame);
+    let clue = (0, 1);
+    let owner_team_id = engine.get_state().teams[0].id;
+    let _ = engine.handle_action(GameAction::SelectClue {
+        clue,
+        team_id: owner_team_id,
+    });
+    let _ = engine.handle_action(GameAction::AnswerIncorrect {
+        clue,
+        team_id: owner_team_id,
+    });
+    let PlayPhase::Steal { current, queue, .. } = engine.get_phase() else {
+        panic!("expected steal phase");
+    };
+    let first_stealer = *current;
+    let second_stealer = queue[0];
+    engine
+        .get_state_mut()
+        .arm_steal_clock(std::time::Duration::from_secs(10));
+    assert!(engine.get_state().steal_deadline.is_some());
+
+    let result = engine.handle_action(GameAction::StealTimeout);
+    assert!(result.is_ok());
+
+    // The stealer who ran out is spent; the next one gets a fresh clock
+    let PlayPhase::Steal {
+        current, attempted, ..
+    } = engine.get_phase()
+    else {
+        panic!("expected steal phase");
+    };
+    assert_eq!(*current, second_stealer);
+    assert!(attempted.contains(&first_stealer));
+    assert!(engine.get_state().steal_deadline.is_none());
+    assert_eq!(engine.get_team_score(first_stealer), Some(0));
+}
+
+#[test]
+fn test_steal_timeout_exhausts_to_resolved() {
+    let (mut engine, _) = first_steal_missed();
+
+    let result = engine.handle_action(GameAction::StealTimeout);
+    assert!(result.is_ok());
+    assert!(matches!(
+        engine.get_phase(),
+        PlayPhase::Resolved { clue: (0, 1), .. }
+    ));
+    assert!(engine.get_state().board.categories[0].clues[1].solved);
+
+    // Nothing left to time out once the clue is resolved
+    assert!(engine.handle_action(GameAction::StealTimeout).is_err());
+}
+
+#[test]
+fn test_capture_note_stores_text_and_solves_without_scoring() {
+    let mut engine = create_game_in_selecting_phase();
+    let team_id = engine.get_state().active_team;
+    let clue = (1, 0);
+    let _ = engine.handle_action(GameAction::SelectClue { clue, team_id });
+
+    // Only the clue on screen can take a note
+    assert!(
+        engine
+            .handle_action(GameAction::CaptureNote {
+                clue: (0, 0),
+                note: "wrong clue".to_string(),
+            })
+            .is_err()
+    );
+
+    let result = engine.handle_action(GameAction::CaptureNote {
+        clue,
+        note: "  Pizza, tacos \n".to_string(),
+    });
+    assert!(result.is_ok());
+    assert!(matches!(engine.get_phase(), PlayPhase::Resolved { .. }));
+    let captured = &engine.get_state().board.categories[1].clues[0];
+    assert!(captured.solved);
+    assert_eq!(captured.host_note.as_deref(), Some("Pizza, tacos"));
+    assert!(engine.get_state().teams.iter().all(|t| t.score == 0));
+    assert!(engine.get_state().score_log.is_empty());
+
+    // The usual close flow takes it from there
+    let PlayPhase::Resolved { next_team_id, .. } = *engine.get_phase() else {
+        panic!("expected resolved phase");
+    };
+    let _ = engine.handle_action(GameAction::CloseClue { clue, next_team_id });
+    assert!(matches!(engine.get_phase(), PlayPhase::Selecting { .. }));
+}
+
+/// Missed first steal with the next correct steal set to trigger the fourth-question event
+fn steal_about_to_trigger_event(timing: EventTiming) -> (GameEngine, u32) {
+    let (mut engine, _) = first_steal_missed();
+    let config = engine.event_config_mut();
+    config.enabled_events = vec![GameEvent::DoublePoints];
+    config.no_event_weight = 0;
+    config.timing = timing;
+    engine.get_state_mut().event_state.questions_answered = 3;
+    let PlayPhase::Steal { current, .. } = *engine.get_phase() else {
+        panic!("expected steal phase");
+    };
+    (engine, current)
+}
+
+#[test]
+fn test_event_queues_on_resolve_after_correct_steal() {
+    let (mut engine, stealer) = steal_about_to_trigger_event(EventTiming::OnResolve);
+    let result = engine
+        .handle_action(GameAction::StealAttempt {
+            clue: (0, 1),
+            team_id: stealer,
+            correct: true,
+        })
+        .unwrap();
+
+    assert!(matches!(engine.get_phase(), PlayPhase::Resolved { .. }));
+    assert!(engine.get_state().event_state.has_queued_event());
+    let GameActionResult::StateChanged { effects, .. } = result else {
+        panic!("expected state change");
+    };
+    assert!(effects.iter().any(|e| matches!(
+        e,
+        GameEffect::EventQueued {
+            event: GameEvent::DoublePoints
+        }
+    )));
+
+    // Closing doesn't roll a second time
+    let PlayPhase::Resolved { next_team_id, .. } = *engine.get_phase() else {
+        panic!("expected resolved phase");
+    };
+    let _ = engine.handle_action(GameAction::CloseClue {
+        clue: (0, 1),
+        next_team_id,
+    });
+    assert_eq!(engine.get_state().event_state.questions_answered, 4);
+}
+
+#[test]
+fn test_event_waits_for_close_by_default() {
+    let (mut engine, stealer) = steal_about_to_trigger_event(EventTiming::default());
+    let _ = engine.handle_action(GameAction::StealAttempt {
+        clue: (0, 1),
+        team_id: stealer,
+        correct: true,
+    });
+    assert!(!engine.get_state().event_state.has_queued_event());
+    assert_eq!(engine.get_state().event_state.questions_answered, 3);
+
+    let PlayPhase::Resolved { next_team_id, .. } = *engine.get_phase() else {
+        panic!("expected resolved phase");
+    };
+    let _ = engine.handle_action(GameAction::CloseClue {
+        clue: (0, 1),
+        next_team_id,
+    });
+    assert!(engine.get_state().event_state.has_queued_event());
+}
+
+#[test]
+fn test_resolving_last_clue_skips_on_resolve_event() {
+    let mut engine = create_game_in_selecting_phase();
+    let config = engine.event_config_mut();
+    config.enabled_events = vec![GameEvent::HardReset];
+    config.no_event_weight = 0;
+    config.trigger_interval = 1;
+    config.timing = EventTiming::OnResolve;
+    for (ci, ri) in [(0, 1), (1, 0), (1, 1)] {
+        engine.get_state_mut().board.categories[ci].clues[ri].solved = true;
+    }
+    engine.get_state_mut().teams[1].score = 700;
+    let team_id = engine.get_state().active_team;
+    let clue = (0, 0);
+    let points = engine.get_state().board.categories[0].clues[0].points as i32;
+
+    let _ = engine.handle_action(GameAction::SelectClue { clue, team_id });
+    let _ = engine.handle_action(GameAction::AnswerCorrect { clue, team_id });
+
+    // No Hard Reset sneaks in between the final answer and the final standings
+    assert!(!engine.get_state().event_state.has_queued_event());
+    assert_eq!(engine.get_team_score(team_id), Some(points));
+    assert_eq!(engine.get_state().teams[1].score, 700);
+    let PlayPhase::Resolved { next_team_id, .. } = *engine.get_phase() else {
+        panic!("expected resolved phase");
+    };
+    let _ = engine.handle_action(GameAction::CloseClue { clue, next_team_id });
+    assert!(matches!(engine.get_phase(), PlayPhase::Finished));
+    assert_eq!(engine.get_state().teams[1].score, 700);
+}
+
+#[test]
+fn test_last_clue_of_a_round_still_rolls_on_resolve() {
+    let mut engine = create_game_in_selecting_phase();
+    let config = engine.event_config_mut();
+    config.enabled_events = vec![GameEvent::DoublePoints];
+    config.no_event_weight = 0;
+    config.trigger_interval = 1;
+    config.timing = EventTiming::OnResolve;
+    for (ci, ri) in [(0, 1), (1, 0)] {
+        engine.get_state_mut().board.categories[ci].clues[ri].solved = true;
+    }
+    engine.get_state_mut().board.categories[1].clues[1].unlock_round = Some(2);
+    let team_id = engine.get_state().active_team;
+    let clue = (0, 0);
+
+    // Round 2 is still to come, so this isn't the end of the game
+    let _ = engine.handle_action(GameAction::SelectClue { clue, team_id });
+    let _ = engine.handle_action(GameAction::AnswerCorrect { clue, team_id });
+    assert!(engine.get_state().event_state.has_queued_event());
+}
+
+/// Active team after the selecting team answers clue (0, 0) and, for a miss,
+/// the other team's steal also misses; (before, after close)
+fn active_team_after_answer(keep_turn_on_correct: bool, correct: bool) -> (u32, u32) {
+    let mut engine = create_game_in_selecting_phase();
+    engine.rules_mut().keep_turn_on_correct = keep_turn_on_correct;
+    let clue = (0, 0);
+    let team_id = engine.get_state().active_team;
+    let _ = engine.handle_action(GameAction::SelectClue { clue, team_id });
+    if correct {
+        let _ = engine.handle_action(GameAction::AnswerCorrect { clue, team_id });
+    } else {
+        let _ = engine.handle_action(GameAction::AnswerIncorrect { clue, team_id });
+        let PlayPhase::Steal { current, .. } = *engine.get_phase() else {
+            panic!("expected steal phase");
+        };
+        let _ = engine.handle_action(GameAction::StealAttempt {
+            clue,
+            team_id: current,
+            correct: false,
+        });
+    }
+    let PlayPhase::Resolved { next_team_id, .. } = *engine.get_phase() else {
+        panic!("expected resolved phase");
+    };
+    let _ = engine.handle_action(GameAction::CloseClue { clue, next_team_id });
+    assert!(
+        matches!(engine.get_phase(), PlayPhase::Selecting { team_id } if *team_id == next_team_id)
+    );
+    (team_id, engine.get_state().active_team)
+}
+
+#[test]
+fn test_correct_answer_rotates_by_default() {
+    let (before, after) = active_team_after_answer(false, true);
+    assert_ne!(before, after);
+}
+
+#[test]
+fn test_keep_turn_on_correct_keeps_the_selecting_team() {
+    let (before, after) = active_team_after_answer(true, true);
+    assert_eq!(before, after);
+
+    // A miss still hands control on
+    let (before, after) = active_team_after_answer(true, false);
+    assert_ne!(before, after);
+}
+
+/// Three teams; the owner misses clue (0, 0) and it's stolen by whichever team
+/// isn't next in the rotation. Returns (rotation's pick, stealer, who selects next).
+fn selector_after_correct_steal(control_to_stealer: bool) -> (u32, u32, u32) {
+    let mut engine = create_test_game_with_teams();
+    let _ = engine.handle_action(GameAction::AddTeam {
+        name: "Team 3".to_string(),
+    });
+    engine.rules_mut().control_to_stealer = control_to_stealer;
+    let _ = engine.handle_action(GameAction::StartGame);
+    let clue = (0, 0);
+    let owner = engine.get_state().active_team;
+    let ids: Vec<u32> = engine.get_state().teams.iter().map(|t| t.id).collect();
+    let owner_index = ids.iter().position(|id| *id == owner).unwrap();
+    let rotated = ids[(owner_index + 1) % ids.len()];
+
+    let _ = engine.handle_action(GameAction::SelectClue {
+        clue,
+        team_id: owner,
+    });
+    let _ = engine.handle_action(GameAction::AnswerIncorrect {
+        clue,
+        team_id: owner,
+    });
+    // Steal order is shuffled; let the rotation's pick miss if it goes first
+    let stealer = loop {
+        let PlayPhase::Steal { current, .. } = *engine.get_phase() else {
+            panic!("expected steal phase");
+        };
+        let correct = current != rotated;
+        let _ = engine.handle_action(GameAction::StealAttempt {
+            clue,
+            team_id: current,
+            correct,
+        });
+        if correct {
+            break current;
+        }
+    };
+    let PlayPhase::Resolved { next_team_id, .. } = *engine.get_phase() else {
+        panic!("expected resolved phase");
+    };
+    let _ = engine.handle_action(GameAction::CloseClue { clue, next_team_id });
+    assert!(
+        matches!(engine.get_phase(), PlayPhase::Selecting { team_id } if *team_id == next_team_id)
+    );
+    (rotated, stealer, engine.get_state().active_team)
+}
+
+#[test]
+fn test_correct_steal_rotates_by_default() {
+    let (rotated, stealer, next) = selector_after_correct_steal(false);
+    assert_ne!(rotated, stealer);
+    assert_eq!(next, rotated);
+}
+
+#[test]
+fn test_control_to_stealer_hands_the_pick_to_the_stealer() {
+    let (rotated, stealer, next) = selector_after_correct_steal(true);
+    assert_ne!(rotated, stealer);
+    assert_eq!(next, stealer);
+}
+
+/// max_attempts when clue (0, 0) is worth `points` and carries `bonus_attempt`
+fn max_attempts_for(points: u32, bonus_attempt: bool) -> u32 {
+    let mut engine = create_game_in_selecting_phase();
+    let clue = &mut engine.get_state_mut().board.categories[0].clues[0];
+    clue.points = points;
+    clue.bonus_attempt = bonus_attempt;
+    let team_id = engine.get_state().active_team;
+    let _ = engine.handle_action(GameAction::SelectClue {
+        clue: (0, 0),
+        team_id,
+    });
+    let PlayPhase::Showing { max_attempts, .. } = *engine.get_phase() else {
+        panic!("expected showing phase");
+    };
+    max_attempts
+}
+
+#[test]
+fn test_bonus_attempt_clue_gets_two_attempts_whatever_its_value() {
+    assert_eq!(max_attempts_for(100, true), 2);
+    assert_eq!(max_attempts_for(100, false), 1);
+    // Unflagged clues follow the value threshold
+    assert_eq!(max_attempts_for(800, false), 2);
+    assert_eq!(max_attempts_for(500, false), 1);
+}
+
+#[test]
+fn test_bonus_attempts_are_drawn_at_start() {
+    let flagged_with_seed = |count: usize, seed: u64| {
+        let mut engine = GameEngine::new(crate::core::Board::default_with_dimensions(4, 4));
+        engine.rules_mut().bonus_attempt_clues = count;
+        let _ = engine.handle_action(GameAction::AddTeam {
+            name: "Team 1".to_string(),
+        });
+        // Left over from an earlier game; redrawn on start
+        engine.get_state_mut().board.categories[3].clues[3].bonus_attempt = true;
+        engine.seed_rng(seed);
+        let _ = engine.handle_action(GameAction::StartGame);
+        let flagged: Vec<u32> = engine
+            .get_state()
+            .board
+            .categories
+            .iter()
+            .flat_map(|c| c.clues.iter())
+            .filter(|c| c.bonus_attempt)
+            .map(|c| c.id)
+            .collect();
+        flagged
+    };
+
+    assert!(flagged_with_seed(0, 1).is_empty());
+    let flagged = flagged_with_seed(3, 1);
+    assert_eq!(flagged.len(), 3);
+    assert_eq!(flagged, flagged_with_seed(3, 1));
+    // Never more than the board holds
+    assert_eq!(flagged_with_seed(50, 1).len(), 16);
+}
+
+/// A finished two-team game with the given scores, ready for final wagers
+fn finished_game(scores: [i32; 2]) -> (crate::game::GameEngine, [u32; 2]) {
+    let mut engine = create_test_game_with_teams();
+    let _ = engine.handle_action(GameAction::StartGame);
+    let state = engine.get_state_mut();
+    state.teams[0].score = scores[0];
+    state.teams[1].score = scores[1];
+    state.phase = PlayPhase::Finished;
+    let ids = [state.teams[0].id, state.teams[1].id];
+    (engine, ids)
+}
+
+#[test]
+fn test_zero_wager_is_accepted() {
+    let (mut engine, [team_id, _]) = finished_game([500, 300]);
+    let result = engine.handle_action(GameAction::SubmitWager { team_id, wager: 0 });
+    assert!(result.is_ok());
+    assert_eq!(engine.get_state().wager_for(team_id), Some(0));
+
+    // Judging a zero wager leaves the score alone
+    let _ = engine.handle_action(GameAction::ResolveWager {
+        team_id,
+        correct: false,
+    });
+    assert_eq!(engine.get_team_score(team_id), Some(500));
+}
+
+#[test]
+fn test_over_wager_and_negative_wager_are_rejected() {
+    let (mut engine, [team_id, _]) = finished_game([500, 300]);
+    for wager in [501, -100] {
+        match engine.handle_action(GameAction::SubmitWager { team_id, wager }) {
+            Err(crate::game::actions::GameError::InvalidAction { action, reason }) => {
+                assert_eq!(action, "SubmitWager");
+                assert!(reason.contains(&wager.to_string()), "{}", reason);
+            }
+            other => panic!("expected the wager to be rejected, got {:?}", other),
+        }
+    }
+    assert_eq!(engine.get_state().wager_for(team_id), None);
+
+    // The whole score is fine, and a correct answer doubles it
+    let _ = engine.handle_action(GameAction::SubmitWager {
+        team_id,
+        wager: 500,
+    });
+    let _ = engine.handle_action(GameAction::ResolveWager {
+        team_id,
+        correct: true,
+    });
+    assert_eq!(engine.get_team_score(team_id), Some(1000));
+    assert_eq!(engine.get_state().wager_for(team_id), None);
+}
+
+#[test]
+fn test_judged_wager_cannot_be_placed_again() {
+    let (mut engine, [team_id, _]) = finished_game([500, 300]);
+    let _ = engine.handle_action(GameAction::SubmitWager {
+        team_id,
+        wager: 200,
+    });
+    let _ = engine.handle_action(GameAction::ResolveWager {
+        team_id,
+        correct: true,
+    });
+    assert_eq!(engine.get_state().wager_result(team_id), Some((200, true)));
+
+    // A second round of wager-and-judge would compound the score
+    let again = engine.handle_action(GameAction::SubmitWager {
+        team_id,
+        wager: 700,
+    });
+    assert!(matches!(
+        again,
+        Err(crate::game::actions::GameError::InvalidAction { .. })
+    ));
+    let rejudge = engine.handle_action(GameAction::ResolveWager {
+        team_id,
+        correct: true,
+    });
+    assert!(rejudge.is_err());
+    assert_eq!(engine.get_team_score(team_id), Some(700));
+}
+
+#[test]
+fn test_team_without_points_is_held_to_zero_wager() {
+    let (mut engine, [_, team_id]) = finished_game([500, -200]);
+    let result = engine.handle_action(GameAction::SubmitWager {
+        team_id,
+        wager: 300,
+    });
+    assert!(result.is_ok());
+    assert_eq!(engine.get_state().wager_for(team_id), Some(0));
+
+    let _ = engine.handle_action(GameAction::ResolveWager {
+        team_id,
+        correct: false,
+    });
+    assert_eq!(engine.get_team_score(team_id), Some(-200));
+}
+
+#[test]
+fn test_wagers_only_once_the_board_is_finished() {
+    let mut engine = create_test_game_with_teams();
+    let _ = engine.handle_action(GameAction::StartGame);
+    let team_id = engine.get_state().teams[0].id;
+    let result = engine.handle_action(GameAction::SubmitWager { team_id, wager: 0 });
+    assert!(result.is_err());
+}
@@ -1,4 +1,6 @@
 use super::*;
+use crate::game::rules::FirstTeamPolicy;
+use crate::game::state::RepairNote;
 use crate::game::{GameAction, PlayPhase};
 
 #[test]
@@ -90,3 +92,445 @@ fn test_game_engine_query_methods() {
     assert!(engine.get_clue((0, 0)).is_some());
     assert!(engine.get_clue((10, 10)).is_none());
 }
+
+#[test]
+fn test_current_clue_follows_the_phase() {
+    let mut engine = create_test_game_with_teams();
+    assert!(engine.current_clue().is_none());
+    let _ = engine.handle_action(GameAction::StartGame);
+    assert!(matches!(engine.get_phase(), PlayPhase::Selecting { .. }));
+    assert!(engine.current_clue().is_none());
+
+    let clue = (1, 1);
+    let expected_id = engine.clue_at(1, 1).unwrap().id;
+    assert_eq!(
+        engine.clue_at(1, 1).map(|c| c.id),
+        engine.get_clue(clue).map(|c| c.id)
+    );
+    assert!(engine.clue_at(10, 0).is_none());
+
+    let owner = engine.get_state().teams[0].id;
+    let stealer = engine.get_state().teams[1].id;
+    let _ = engine.handle_action(GameAction::SelectClue {
+        clue,
+        team_id: owner,
+    });
+    assert!(matches!(engine.get_phase(), PlayPhase::Showing { .. }));
+    assert_eq!(engine.current_clue().map(|c| c.id), Some(expected_id));
+
+    let _ = engine.handle_action(GameAction::AnswerIncorrect {
+        clue,
+        team_id: owner,
+    });
+    assert!(matches!(engine.get_phase(), PlayPhase::Steal { .. }));
+    assert_eq!(engine.current_clue().map(|c| c.id), Some(expected_id));
+
+    let _ = engine.handle_action(GameAction::StealAttempt {
+        clue,
+        team_id: stealer,
+        correct: true,
+    });
+    assert!(matches!(engine.get_phase(), PlayPhase::Resolved { .. }));
+    assert_eq!(engine.current_clue().map(|c| c.id), Some(expected_id));
+}
+
+/// Minimal logger that keeps formatted records in memory so tests can inspect them
+struct CaptureLogger {
+    records: std::sync::Mutex<Vec<String>>,
+}
+
+impl log::Log for CaptureLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        self.records
+            .lock()
+            .unwrap()
+            .push(format!("{} {}", record.level(), record.args()));
+    }
+
+    fn flush(&self) {}
+}
+
+static CAPTURE_LOGGER: CaptureLogger = CaptureLogger {
+    records: std::sync::Mutex::new(Vec::new()),
+};
+
+#[test]
+fn test_handle_action_logs_answer_correct() {
+    // Other tests may have installed the logger already; that's fine, it's the same one
+    let _ = log::set_logger(&CAPTURE_LOGGER);
+    log::set_max_level(log::LevelFilter::Debug);
+
+    let mut engine = create_game_in_selecting_phase();
+    let clue = (0, 0);
+    let team_id = engine.get_state().teams[0].id;
+    let _ = engine.handle_action(GameAction::SelectClue { clue, team_id });
+    let _ = engine.handle_action(GameAction::AnswerCorrect { clue, team_id });
+
+    let records = CAPTURE_LOGGER.records.lock().unwrap();
+    assert!(
+        records.iter().any(|r| r.starts_with("INFO")
+            && r.contains("AnswerCorrect")
+            && r.contains("Resolved")),
+        "Expected an info entry for AnswerCorrect with its resulting phase"
+    );
+}
+
+#[test]
+fn test_first_team_policy_first_added_is_default() {
+    let mut engine = create_test_game_with_teams();
+    assert_eq!(engine.rules().first_team, FirstTeamPolicy::FirstAdded);
+    let first_team_id = engine.get_state().teams[0].id;
+
+    let _ = engine.handle_action(GameAction::StartGame);
+
+    assert_eq!(engine.get_state().active_team, first_team_id);
+    assert!(matches!(
+        engine.get_phase(),
+        PlayPhase::Selecting { team_id } if *team_id == first_team_id
+    ));
+}
+
+#[test]
+fn test_first_team_policy_specific() {
+    let mut engine = create_test_game_with_teams();
+    let chosen = engine.get_state().teams[1].id;
+    engine.set_first_team_policy(FirstTeamPolicy::Specific(chosen));
+
+    let _ = engine.handle_action(GameAction::StartGame);
+
+    assert_eq!(engine.get_state().active_team, chosen);
+    assert!(matches!(
+        engine.get_phase(),
+        PlayPhase::Selecting { team_id } if *team_id == chosen
+    ));
+}
+
+#[test]
+fn test_first_team_policy_random_is_deterministic_with_seed() {
+    let start_with_seed = |seed: u64| {
+        let mut engine = create_test_game_with_teams();
+        for i in 3..=6 {
+            let _ = engine.handle_action(GameAction::AddTeam {
+                name: format!("Team {}", i),
+            });
+        }
+        engine.set_first_team_policy(FirstTeamPolicy::Random);
+        engine.seed_rng(seed);
+        let _ = engine.handle_action(GameAction::StartGame);
+        engine.get_state().active_team
+    };
+
+    let first = start_with_seed(1234);
+    assert_eq!(first, start_with_seed(1234));
+    assert!((1..=6).contains(&first));
+    // Different seeds should not all land on the same team
+    let picks: std::collections::HashSet<u32> = (0..20).map(start_with_seed).collect();
+    assert!(picks.len() > 1);
+}
+
+#[test]
+fn test_shuffle_teams_is_deterministic_and_keeps_every_team() {
+    let shuffle_with_seed = |seed: u64| {
+        let mut engine = create_test_game_engine();
+        for i in 1..=6 {
+            let _ = engine.handle_action(GameAction::AddTeam {
+                name: format!("Team {}", i),
+            });
+        }
+        engine.seed_rng(seed);
+        assert!(engine.handle_action(GameAction::ShuffleTeams).is_ok());
+        let order: Vec<u32> = engine.get_state().teams.iter().map(|t| t.id).collect();
+        assert_eq!(engine.get_state().active_team, order[0]);
+        order
+    };
+
+    let order = shuffle_with_seed(99);
+    assert_eq!(order, shuffle_with_seed(99));
+    let mut ids = order.clone();
+    ids.sort();
+    assert_eq!(ids, vec![1, 2, 3, 4, 5, 6]);
+    // Some seed must actually change the order
+    assert!((0..20).any(|seed| shuffle_with_seed(seed) != vec![1, 2, 3, 4, 5, 6]));
+}
+
+#[test]
+fn test_shuffle_teams_rejected_outside_lobby() {
+    let mut engine = create_test_game_engine();
+    for name in ["A", "B"] {
+        let _ = engine.handle_action(GameAction::AddTeam {
+            name: name.to_string(),
+        });
+    }
+    let _ = engine.handle_action(GameAction::StartGame);
+    assert!(engine.handle_action(GameAction::ShuffleTeams).is_err());
+}
+
+#[test]
+fn test_repair_out_of_range_showing_clue_returns_to_selecting() {
+    let mut engine = create_game_in_selecting_phase();
+    let active = engine.get_state().active_team;
+    let state = engine.get_state_mut();
+    state.phase = PlayPhase::Showing {
+        clue: (7, 9),
+        owner_team_id: active,
+        attempt_count: 1,
+        max_attempts: 1,
+    };
+
+    let notes = state.validate_and_repair();
+
+    assert_eq!(notes.len(), 1);
+    assert!(matches!(notes[0], RepairNote::PhaseReset { .. }));
+    assert!(matches!(
+        engine.get_phase(),
+        PlayPhase::Selecting { team_id } if *team_id == active
+    ));
+}
+
+#[test]
+fn test_repair_solved_showing_clue_and_missing_active_team() {
+    let mut engine = create_game_in_selecting_phase();
+    let first_team = engine.get_state().teams[0].id;
+    let state = engine.get_state_mut();
+    state.board.categories[0].clues[1].solved = true;
+    state.active_team = 999;
+    state.phase = PlayPhase::Showing {
+        clue: (0, 1),
+        owner_team_id: 999,
+        attempt_count: 1,
+        max_attempts: 1,
+    };
+
+    let notes = state.validate_and_repair();
+
+    assert_eq!(
+        notes[0],
+        RepairNote::ActiveTeamReset {
+            from: 999,
+            to: first_team,
+        }
+    );
+    assert!(matches!(notes[1], RepairNote::PhaseReset { .. }));
+    assert!(matches!(
+        engine.get_phase(),
+        PlayPhase::Selecting { team_id } if *team_id == first_team
+    ));
+}
+
+#[test]
+fn test_repair_leaves_consistent_game_alone() {
+    let mut engine = create_game_in_selecting_phase();
+    let team_id = engine.get_state().active_team;
+    let _ = engine.handle_action(GameAction::SelectClue {
+        clue: (1, 1),
+        team_id,
+    });
+
+    assert!(engine.get_state_mut().validate_and_repair().is_empty());
+    assert!(matches!(engine.get_phase(), PlayPhase::Showing { .. }));
+}
+
+#[test]
+fn test_full_playthrough_square_board() {
+    assert_eq!(
+        simulate_full_playthrough(Board::default_with_dimensions(4, 5)),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_full_playthrough_jagged_board() {
+    let mut board = Board::default_with_dimensions(3, 4);
+    board.categories[0].clues.truncate(1);
+    board.categories[2].clues.truncate(3);
+    assert_eq!(simulate_full_playthrough(board), Ok(()));
+}
+
+#[test]
+fn test_closing_last_clue_finishes_game() {
+    let mut engine = GameEngine::new(Board::default_with_dimensions(1, 1));
+    let _ = engine.handle_action(GameAction::AddTeam {
+        name: "Team 1".to_string(),
+    });
+    let _ = engine.handle_action(GameAction::StartGame);
+    let team_id = engine.get_state().active_team;
+    let clue = (0, 0);
+    let _ = engine.handle_action(GameAction::SelectClue { clue, team_id });
+    let _ = engine.handle_action(GameAction::AnswerCorrect { clue, team_id });
+
+    let result = engine.handle_action(GameAction::CloseClue {
+        clue,
+        next_team_id: team_id,
+    });
+    assert!(result.is_ok());
+    assert!(matches!(engine.get_phase(), PlayPhase::Finished));
+}
+
+#[test]
+fn test_single_team_game_keeps_the_turn() {
+    let mut engine = create_test_game_engine();
+    let _ = engine.handle_action(GameAction::AddTeam {
+        name: "Solo".to_string(),
+    });
+    let _ = engine.handle_action(GameAction::StartGame);
+    let team_id = engine.get_state().teams[0].id;
+
+    for clue in [(0, 0), (1, 0)] {
+        let _ = engine.handle_action(GameAction::SelectClue { clue, team_id });
+        let _ = engine.handle_action(GameAction::AnswerCorrect { clue, team_id });
+        let PlayPhase::Resolved { next_team_id, .. } = *engine.get_phase() else {
+            panic!("expected resolved phase");
+        };
+        assert_eq!(next_team_id, team_id);
+        let _ = engine.handle_action(GameAction::CloseClue { clue, next_team_id });
+        assert_eq!(engine.get_state().active_team, team_id);
+    }
+}
+
+#[test]
+fn test_shuffle_categories_is_deterministic_and_keeps_every_category() {
+    let mut board = crate::core::Board::default_with_dimensions(6, 3);
+    for (i, category) in board.categories.iter_mut().enumerate() {
+        category.name = format!("Category {}", i);
+    }
+    let start_with_seed = |seed: u64| {
+        let mut engine = GameEngine::new(board.clone());
+        engine.rules_mut().shuffle_categories = true;
+        let _ = engine.handle_action(GameAction::AddTeam {
+            name: "Team 1".to_string(),
+        });
+        engine.seed_rng(seed);
+        assert!(engine.handle_action(GameAction::StartGame).is_ok());
+        engine.get_state().board.categories.clone()
+    };
+
+    let shuffled = start_with_seed(7);
+    let names = |cats: &[crate::core::Category]| -> Vec<String> {
+        cats.iter().map(|c| c.name.clone()).collect()
+    };
+    assert_eq!(names(&shuffled), names(&start_with_seed(7)));
+
+    // Same categories, each with its clues untouched
+    let mut sorted = shuffled.clone();
+    sorted.sort_by(|a, b| a.name.cmp(&b.name));
+    for (original, moved) in board.categories.iter().zip(&sorted) {
+        assert_eq!(original.name, moved.name);
+        let ids = |c: &crate::core::Category| -> Vec<(u32, u32)> {
+            c.clues.iter().map(|clue| (clue.id, clue.points)).collect()
+        };
+        assert_eq!(ids(original), ids(moved));
+    }
+    assert!((0..20).any(|seed| names(&start_with_seed(seed)) != names(&board.categories)));
+}
+
+#[test]
+fn test_categories_keep_config_order_by_default() {
+    let mut engine = create_test_game_with_teams();
+    let before: Vec<String> = engine
+        .get_state()
+        .board
+        .categories
+        .iter()
+        .map(|c| c.name.clone())
+        .collect();
+    let _ = engine.handle_action(GameAction::StartGame);
+    let after: Vec<String> = engine
+        .get_state()
+        .board
+        .categories
+        .iter()
+        .map(|c| c.name.clone())
+        .collect();
+    assert_eq!(before, after);
+}
+
+#[test]
+fn test_reset_to_lobby_replays_same_board_and_teams() {
+    let mut engine = create_test_game_with_teams();
+    let ids: Vec<u32> = engine.get_state().teams.iter().map(|t| t.id).collect();
+    let _ = engine.handle_action(GameAction::SetStartingScore {
+        team_id: ids[1],
+        score: 300,
+    });
+    let board_order: Vec<u32> = engine
+        .get_state()
+        .board
+        .categories
+        .iter()
+        .flat_map(|c| c.clues.iter().map(|clue| clue.id))
+        .collect();
+    assert!(simulate_play_to_finish(&mut engine));
+    engine
+        .get_state_mut()
+        .event_state
+        .activate_event(crate::game::events::GameEvent::DoublePoints);
+
+    engine.reset_to_lobby();
+
+    let state = engine.get_state();
+    assert!(matches!(state.phase, PlayPhase::Lobby));
+    assert!(
+        state
+            .board
+            .categories
+            .iter()
+            .flat_map(|c| c.clues.iter())
+            .all(|clue| !clue.solved && !clue.revealed)
+    );
+    let replay_order: Vec<u32> = state
+        .board
+        .categories
+        .iter()
+        .flat_map(|c| c.clues.iter().map(|clue| clue.id))
+        .collect();
+    assert_eq!(replay_order, board_order);
+    let roster: Vec<(u32, i32)> = state.teams.iter().map(|t| (t.id, t.score)).collect();
+    assert_eq!(roster, vec![(ids[0], 0), (ids[1], 300)]);
+    assert!(state.event_state.active_event.is_none());
+    assert_eq!(state.event_state.questions_answered, 0);
+    assert_eq!(state.score_log.len(), 1);
+
+    // And the replay plays through again
+    assert!(engine.handle_action(GameAction::StartGame).is_ok());
+    assert!(simulate_play_to_finish(&mut engine));
+}
+
+#[test]
+fn test_only_locked_clues_left_breaks_for_next_round() {
+    let mut engine = create_game_in_selecting_phase();
+    for clue in &mut engine.get_state_mut().board.categories[1].clues {
+        clue.unlock_round = Some(2);
+    }
+
+    // Playing out round 1 leaves only round-2 clues: a break, not a dead board
+    assert!(!simulate_play_to_finish(&mut engine));
+    assert!(matches!(engine.get_phase(), PlayPhase::Intermission));
+    assert!(engine.get_available_clues().is_empty());
+    assert!(engine.handle_action(GameAction::Resume).is_err());
+
+    assert!(engine.handle_action(GameAction::NextRound).is_ok());
+    assert_eq!(engine.get_state().round, 2);
+    assert_eq!(engine.get_available_clues(), vec![(1, 0), (1, 1)]);
+    assert!(simulate_play_to_finish(&mut engine));
+}
+
+/// Answer every clue correctly with the active team; true once the game is Finished
+fn simulate_play_to_finish(engine: &mut GameEngine) -> bool {
+    if matches!(engine.get_phase(), PlayPhase::Lobby) {
+        let _ = engine.handle_action(GameAction::StartGame);
+    }
+    while let Some(clue) = engine.get_available_clues().first().copied() {
+        let team_id = engine.get_state().active_team;
+        let _ = engine.handle_action(GameAction::SelectClue { clue, team_id });
+        let _ = engine.handle_action(GameAction::AnswerCorrect { clue, team_id });
+        let PlayPhase::Resolved { next_team_id, .. } = *engine.get_phase() else {
+            return false;
+        };
+        let _ = engine.handle_action(GameAction::CloseClue { clue, next_team_id });
+    }
+    matches!(engine.get_phase(), PlayPhase::Finished)
+}
@@ -32,6 +32,67 @@ pub fn create_game_in_selecting_phase() -> GameEngine {
     engine
 }
 
+/// Play a fresh game on `board` through every clue in category-then-row order:
+/// pick it with the active team, answer it correctly and close it. Errors name the
+/// first clue the engine refused, or say why the game didn't end Finished with
+/// every clue solved.
+pub fn simulate_full_playthrough(board: Board) -> Result<(), String> {
+    use crate::game::GameAction;
+
+    let mut engine = GameEngine::new(board);
+    engine.seed_rng(7);
+    for name in ["Team 1", "Team 2"] {
+        engine
+            .handle_action(GameAction::AddTeam {
+                name: name.to_string(),
+            })
+            .map_err(|e| format!("adding {}: {:?}", name, e))?;
+    }
+    engine
+        .handle_action(GameAction::StartGame)
+        .map_err(|e| format!("starting: {:?}", e))?;
+
+    let clues: Vec<(usize, usize)> = engine
+        .get_state()
+        .board
+        .categories
+        .iter()
+        .enumerate()
+        .flat_map(|(ci, cat)| (0..cat.clues.len()).map(move |ri| (ci, ri)))
+        .collect();
+    for clue in clues {
+        let team_id = engine.get_state().active_team;
+        engine
+            .handle_action(GameAction::SelectClue { clue, team_id })
+            .map_err(|e| format!("selecting {:?}: {:?}", clue, e))?;
+        engine
+            .handle_action(GameAction::AnswerCorrect { clue, team_id })
+            .map_err(|e| format!("answering {:?}: {:?}", clue, e))?;
+        let PlayPhase::Resolved { next_team_id, .. } = *engine.get_phase() else {
+            return Err(format!(
+                "{:?} left the game in {:?}",
+                clue,
+                engine.get_phase()
+            ));
+        };
+        engine
+            .handle_action(GameAction::CloseClue { clue, next_team_id })
+            .map_err(|e| format!("closing {:?}: {:?}", clue, e))?;
+    }
+
+    if !matches!(engine.get_phase(), PlayPhase::Finished) {
+        return Err(format!(
+            "board played out but the game is in {:?}",
+            engine.get_phase()
+        ));
+    }
+    let unsolved = engine.get_available_clues();
+    if !unsolved.is_empty() {
+        return Err(format!("clues left unsolved: {:?}", unsolved));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod engine_tests;
 
@@ -1,5 +1,6 @@
 use super::*;
 use crate::game::GameAction;
+use crate::game::actions::GameError;
 use crate::game::rules::GameRules;
 
 #[test]
@@ -15,6 +16,39 @@ fn test_can_add_team_rules() {
     assert!(!rules.can_add_team(engine.get_state()));
 }
 
+#[test]
+fn test_can_add_team_respects_max_teams() {
+    let mut engine = create_test_game_with_teams();
+    engine.set_max_teams(2);
+
+    assert!(!engine.rules().can_add_team(engine.get_state()));
+    engine.set_max_teams(3);
+    assert!(engine.rules().can_add_team(engine.get_state()));
+}
+
+#[test]
+fn test_add_team_beyond_max_is_rejected() {
+    let mut engine = create_test_game_engine();
+    engine.set_max_teams(3);
+    for i in 1..=3 {
+        let result = engine.handle_action(GameAction::AddTeam {
+            name: format!("Team {}", i),
+        });
+        assert!(result.is_ok());
+    }
+
+    let result = engine.handle_action(GameAction::AddTeam {
+        name: "Team 4".to_string(),
+    });
+    match result {
+        Err(GameError::InvalidAction { reason, .. }) => {
+            assert_eq!(reason, "Team limit of 3 reached");
+        }
+        other => panic!("expected team limit error, got {:?}", other),
+    }
+    assert_eq!(engine.team_count(), 3);
+}
+
 #[test]
 fn test_can_start_game_rules() {
     let rules = GameRules::new();
@@ -124,3 +158,74 @@ fn test_get_available_actions() {
             .any(|a| matches!(a, GameAction::SelectClue { .. }))
     );
 }
+
+#[test]
+fn test_effective_points_with_events_and_multipliers() {
+    let mut rules = GameRules::new();
+    assert_eq!(rules.effective_points(200, false, false, false), 200);
+    assert_eq!(rules.effective_points(200, true, false, false), 400);
+    // A multiplier of 1.0 leaves reversed clues at their usual value
+    assert_eq!(rules.effective_points(200, false, true, false), 200);
+    // The steal factor only applies when the clue is being stolen
+    rules.set_steal_points_factor(0.5);
+    assert_eq!(rules.effective_points(200, false, false, false), 200);
+    assert_eq!(rules.effective_points(200, false, false, true), 100);
+    assert_eq!(rules.effective_points(200, true, false, true), 200);
+    // The reverse multiplier only applies while Reverse Question is active
+    rules.set_reverse_question_multiplier(1.5);
+    assert_eq!(rules.effective_points(200, false, false, false), 200);
+    assert_eq!(rules.effective_points(200, true, true, false), 600);
+    assert_eq!(rules.effective_points(200, false, true, true), 150);
+}
+
+#[test]
+fn test_points_label_shows_multiplier_only_when_changed() {
+    use crate::game::rules::points_label;
+
+    assert_eq!(points_label(200, 200), "200 pts");
+    assert_eq!(points_label(200, 400), "400 pts (×2)");
+    assert_eq!(points_label(400, 200), "200 pts (×0.5)");
+    assert_eq!(points_label(300, 450), "450 pts (×1.5)");
+    assert_eq!(points_label(0, 0), "0 pts");
+}
+
+#[test]
+fn test_show_points_before_resolved() {
+    let mut rules = GameRules::new();
+    assert!(rules.show_points_before_resolved());
+
+    // The setting alone does nothing while values are printed on the board
+    rules.hide_points_until_resolved = true;
+    assert!(rules.show_points_before_resolved());
+
+    // Mystery board: shown unless the host also hides them on the open clue
+    rules.show_cell_values = false;
+    assert!(!rules.show_points_before_resolved());
+    rules.hide_points_until_resolved = false;
+    assert!(rules.show_points_before_resolved());
+}
+
+#[test]
+fn test_clue_locked_until_its_round() {
+    let mut engine = create_game_in_selecting_phase();
+    engine.get_state_mut().board.categories[0].clues[0].unlock_round = Some(2);
+    let team_id = engine.get_state().active_team;
+    let select = GameAction::SelectClue {
+        clue: (0, 0),
+        team_id,
+    };
+
+    // Round 1: the gated clue can't be picked, but its neighbours can
+    assert_eq!(engine.get_state().round, 1);
+    assert!(!engine.rules().can_select_clue(engine.get_state(), (0, 0)));
+    assert!(engine.rules().can_select_clue(engine.get_state(), (0, 1)));
+    assert!(engine.handle_action(select.clone()).is_err());
+
+    // Moving on from the intermission opens round 2
+    assert!(engine.handle_action(GameAction::Pause).is_ok());
+    assert!(engine.handle_action(GameAction::NextRound).is_ok());
+    assert_eq!(engine.get_state().round, 2);
+    assert!(matches!(engine.get_phase(), PlayPhase::Selecting { .. }));
+    assert!(engine.handle_action(select).is_ok());
+    assert!(matches!(engine.get_phase(), PlayPhase::Showing { .. }));
+}
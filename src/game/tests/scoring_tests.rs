@@ -1,5 +1,6 @@
-use crate::core::Team;
+use crate::core::{Board, Team};
 use crate::game::scoring::ScoringEngine;
+use crate::game::state::GameState;
 
 #[test]
 fn test_award_points() {
@@ -9,11 +10,13 @@ fn test_award_points() {
             id: 1,
             name: "Team 1".to_string(),
             score: 0,
+            emoji: None,
         },
         Team {
             id: 2,
             name: "Team 2".to_string(),
             score: 0,
+            emoji: None,
         },
     ];
 
@@ -34,6 +37,7 @@ fn test_deduct_points() {
         id: 1,
         name: "Team 1".to_string(),
         score: 200,
+        emoji: None,
     }];
 
     // Deduct points
@@ -54,11 +58,13 @@ fn test_get_team_score() {
             id: 1,
             name: "Team 1".to_string(),
             score: 150,
+            emoji: None,
         },
         Team {
             id: 2,
             name: "Team 2".to_string(),
             score: 75,
+            emoji: None,
         },
     ];
 
@@ -75,16 +81,19 @@ fn test_get_leaderboard() {
             id: 1,
             name: "Team A".to_string(),
             score: 100,
+            emoji: None,
         },
         Team {
             id: 2,
             name: "Team B".to_string(),
             score: 200,
+            emoji: None,
         },
         Team {
             id: 3,
             name: "Team C".to_string(),
             score: 150,
+            emoji: None,
         },
     ];
 
@@ -104,6 +113,7 @@ fn test_add_team() {
         id: 1,
         name: "Team 1".to_string(),
         score: 0,
+        emoji: None,
     }];
 
     let new_team_id = scoring.add_team(&mut teams, "Team 2".to_string());
@@ -122,16 +132,19 @@ fn test_rotate_active_team() {
             id: 1,
             name: "Team 1".to_string(),
             score: 0,
+            emoji: None,
         },
         Team {
             id: 2,
             name: "Team 2".to_string(),
             score: 0,
+            emoji: None,
         },
         Team {
             id: 3,
             name: "Team 3".to_string(),
             score: 0,
+            emoji: None,
         },
     ];
 
@@ -148,6 +161,25 @@ fn test_rotate_active_team() {
     assert_eq!(next_team, 1); // Should default to first team
 }
 
+#[test]
+fn test_rotate_active_team_single_and_empty() {
+    let scoring = ScoringEngine::new();
+    let teams = vec![Team {
+        id: 7,
+        name: "Solo".to_string(),
+        score: 0,
+        emoji: None,
+    }];
+
+    // A single-team practice game keeps the same team up
+    assert_eq!(scoring.rotate_active_team(&teams, 7), 7);
+    assert_eq!(scoring.rotate_active_team(&teams, 999), 7);
+
+    // No teams at all leaves the current id alone instead of panicking
+    assert_eq!(scoring.rotate_active_team(&[], 7), 7);
+    assert_eq!(scoring.rotate_active_team(&[], 0), 0);
+}
+
 #[test]
 fn test_team_exists() {
     let scoring = ScoringEngine::new();
@@ -156,11 +188,13 @@ fn test_team_exists() {
             id: 1,
             name: "Team 1".to_string(),
             score: 0,
+            emoji: None,
         },
         Team {
             id: 2,
             name: "Team 2".to_string(),
             score: 0,
+            emoji: None,
         },
     ];
 
@@ -177,16 +211,19 @@ fn test_get_team_stats() {
             id: 1,
             name: "Team 1".to_string(),
             score: 100,
+            emoji: None,
         },
         Team {
             id: 2,
             name: "Team 2".to_string(),
             score: 200,
+            emoji: None,
         },
         Team {
             id: 3,
             name: "Team 3".to_string(),
             score: 50,
+            emoji: None,
         },
     ];
 
@@ -212,3 +249,62 @@ fn test_empty_teams_stats() {
     assert_eq!(stats.total_points, 0);
     assert_eq!(stats.average_score, 0.0);
 }
+
+#[test]
+fn test_determine_winner_breaks_ties_by_largest_single_clue() {
+    let mut state = GameState::new(Board::default_with_dimensions(2, 5));
+    for (id, name) in [(1, "Steady"), (2, "Big Swing"), (3, "Trailing")] {
+        state.teams.push(Team {
+            id,
+            name: name.to_string(),
+            score: 0,
+            emoji: None,
+        });
+    }
+    // Both reach 500: team 1 via 200 + 300, team 2 via a single 500 clue
+    let changes = [
+        (1, 200, (0, 1)),
+        (2, 500, (1, 4)),
+        (1, 300, (0, 2)),
+        (3, 100, (0, 0)),
+    ];
+    for (team_id, delta, clue) in changes {
+        state.log_score_change(team_id, delta, Some(clue));
+        state
+            .teams
+            .iter_mut()
+            .find(|t| t.id == team_id)
+            .unwrap()
+            .score += delta;
+    }
+
+    assert_eq!(state.largest_single_clue(1), 300);
+    assert_eq!(state.largest_single_clue(2), 500);
+    assert_eq!(state.determine_winner(), Some(2));
+
+    // Host adjustments don't count as a clue
+    state.log_score_change(1, 900, None);
+    state.teams[0].score += 900;
+    state.log_score_change(2, 900, None);
+    state.teams[1].score += 900;
+    assert_eq!(state.largest_single_clue(1), 300);
+    assert_eq!(state.determine_winner(), Some(2));
+}
+
+#[test]
+fn test_determine_winner_falls_back_to_earliest_to_reach_score() {
+    let mut state = GameState::new(Board::default_with_dimensions(2, 2));
+    for id in [1, 2] {
+        state.teams.push(Team {
+            id,
+            name: format!("Team {}", id),
+            score: 200,
+            emoji: None,
+        });
+    }
+    state.log_score_change(2, 200, Some((1, 1)));
+    state.log_score_change(1, 200, Some((0, 1)));
+
+    assert_eq!(state.determine_winner(), Some(2));
+    assert_eq!(GameState::new(Board::default()).determine_winner(), None);
+}
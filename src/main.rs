@@ -4,17 +4,22 @@ mod game;
 mod theme;
 mod ui;
 
+use app::settings::{BuzzerSource, LaunchMode};
+
 fn main() -> eframe::Result<()> {
+    let launch = LaunchMode::from_args(std::env::args());
+    let buzzers = BuzzerSource::from_args(std::env::args());
     let options = eframe::NativeOptions {
         viewport: eframe::egui::ViewportBuilder::default()
             .with_inner_size([1200.0, 800.0])
             .with_min_inner_size([900.0, 600.0])
+            .with_fullscreen(launch == LaunchMode::Kiosk)
             .with_title("Jacko's Jeopardy"),
         ..Default::default()
     };
     eframe::run_native(
         "LNS with Jay",
         options,
-        Box::new(|cc| Box::new(app::PartyJeopardyApp::new(cc))),
+        Box::new(move |cc| Box::new(app::PartyJeopardyApp::new(cc, launch, buzzers))),
     )
 }
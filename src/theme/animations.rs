@@ -1,4 +1,6 @@
 // Enhanced animation system with easing functions and state management
+use eframe::egui;
+use serde::{Deserialize, Serialize};
 use std::time::{Duration, Instant};
 
 pub type EasingFunction = fn(f32) -> f32;
@@ -99,4 +101,156 @@ pub fn ease_out_bounce(t: f32) -> f32 {
 }
 
 // Removed several unused easing helpers to silence warnings: smooth_step, smoother_step,
-// ease_out_elastic, ease_in_cubic, ease_out_cubic, ease_in_out_cubic, linear.
\ No newline at end of file
+// ease_out_elastic, ease_in_cubic, ease_out_cubic, ease_in_out_cubic, linear.
+
+/// How much visual noise the correct/incorrect bursts make
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AnimationIntensity {
+    Subtle,
+    #[default]
+    Normal,
+    Intense,
+}
+
+/// Element counts and opacity for a fullscreen answer burst
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BurstParams {
+    pub rings: usize,
+    pub lines: usize,
+    pub particles: usize,
+    pub waves: usize,
+    /// Multiplier applied to every overlay and element alpha
+    pub alpha_scale: f32,
+}
+
+impl AnimationIntensity {
+    pub fn burst_params(self) -> BurstParams {
+        match self {
+            AnimationIntensity::Subtle => BurstParams {
+                rings: 2,
+                lines: 6,
+                particles: 4,
+                waves: 1,
+                alpha_scale: 0.45,
+            },
+            AnimationIntensity::Normal => BurstParams {
+                rings: 4,
+                lines: 12,
+                particles: 8,
+                waves: 3,
+                alpha_scale: 1.0,
+            },
+            AnimationIntensity::Intense => BurstParams {
+                rings: 5,
+                lines: 20,
+                particles: 16,
+                waves: 4,
+                alpha_scale: 1.25,
+            },
+        }
+    }
+}
+
+impl BurstParams {
+    /// Scale a base alpha (0..=255) by this burst's opacity, saturating at opaque
+    pub fn alpha(&self, base: f32) -> u8 {
+        (base * self.alpha_scale).clamp(0.0, 255.0) as u8
+    }
+}
+
+/// Number of characters of a `total`-character text a typewriter reveal shows after
+/// `elapsed`, spreading the text evenly over `duration`. Skipping shows everything.
+pub fn typewriter_visible_chars(
+    total: usize,
+    elapsed: Duration,
+    duration: Duration,
+    skipped: bool,
+) -> usize {
+    if skipped || duration.is_zero() || elapsed >= duration {
+        return total;
+    }
+    let t = elapsed.as_secs_f32() / duration.as_secs_f32();
+    ((total as f32 * t).floor() as usize).min(total)
+}
+
+/// Rect partway from `from` to `to`, for zooming a board cell out to the full screen.
+/// `t` is clamped, so 0 gives `from` and 1 gives `to`.
+pub fn lerp_rect(from: egui::Rect, to: egui::Rect, t: f32) -> egui::Rect {
+    let t = t.clamp(0.0, 1.0);
+    egui::Rect::from_min_max(from.min.lerp(to.min, t), from.max.lerp(to.max, t))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typewriter_visible_chars_over_time() {
+        let duration = Duration::from_millis(1500);
+        assert_eq!(
+            typewriter_visible_chars(100, Duration::ZERO, duration, false),
+            0
+        );
+        assert_eq!(
+            typewriter_visible_chars(100, Duration::from_millis(750), duration, false),
+            50
+        );
+        assert_eq!(
+            typewriter_visible_chars(100, Duration::from_millis(1500), duration, false),
+            100
+        );
+        assert_eq!(
+            typewriter_visible_chars(100, Duration::from_secs(10), duration, false),
+            100
+        );
+        assert_eq!(
+            typewriter_visible_chars(0, Duration::ZERO, duration, false),
+            0
+        );
+    }
+
+    #[test]
+    fn test_subtle_bursts_are_calmer_than_intense() {
+        let subtle = AnimationIntensity::Subtle.burst_params();
+        let normal = AnimationIntensity::Normal.burst_params();
+        let intense = AnimationIntensity::Intense.burst_params();
+
+        let elements = |p: &BurstParams| p.rings + p.lines + p.particles + p.waves;
+        assert!(elements(&subtle) < elements(&normal));
+        assert!(elements(&normal) < elements(&intense));
+        assert!(subtle.alpha_scale < intense.alpha_scale);
+        assert!(subtle.alpha(180.0) < normal.alpha(180.0));
+        assert_eq!(intense.alpha(255.0), 255);
+    }
+
+    #[test]
+    fn test_typewriter_skip_reveals_everything() {
+        let duration = Duration::from_millis(1500);
+        assert_eq!(
+            typewriter_visible_chars(42, Duration::ZERO, duration, true),
+            42
+        );
+        assert_eq!(
+            typewriter_visible_chars(42, Duration::from_millis(100), duration, true),
+            42
+        );
+        assert_eq!(
+            typewriter_visible_chars(42, Duration::ZERO, Duration::ZERO, false),
+            42
+        );
+    }
+
+    #[test]
+    fn test_lerp_rect_runs_from_cell_to_screen() {
+        let cell = egui::Rect::from_min_size(egui::pos2(300.0, 200.0), egui::vec2(120.0, 80.0));
+        let screen = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(1200.0, 800.0));
+        assert_eq!(lerp_rect(cell, screen, 0.0), cell);
+        assert_eq!(lerp_rect(cell, screen, 1.0), screen);
+        let half = lerp_rect(cell, screen, 0.5);
+        assert_eq!(half.min, egui::pos2(150.0, 100.0));
+        assert_eq!(half.max, egui::pos2(810.0, 540.0));
+        // Out-of-range progress stays on the path
+        assert_eq!(lerp_rect(cell, screen, 1.5), screen);
+        assert_eq!(lerp_rect(cell, screen, -0.5), cell);
+    }
+}
@@ -30,4 +30,18 @@ impl Palette {
     pub const GLOW_MAGENTA_INNER: egui::Color32 = egui::Color32::from_rgb(255, 100, 200);
     pub const GLOW_BLUE_INNER: egui::Color32 = egui::Color32::from_rgb(100, 200, 255);
     pub const GLOW_YELLOW_INNER: egui::Color32 = egui::Color32::from_rgb(255, 200, 100);
+
+    // Distinct per-team accents, cycled by team position
+    pub const TEAM_COLORS: [egui::Color32; 6] = [
+        Self::CYAN,
+        Self::MAGENTA,
+        Self::CYBER_YELLOW,
+        Self::NEON_BLUE,
+        Self::AMBER_GLOW,
+        Self::GLOW_MAGENTA_INNER,
+    ];
+
+    pub fn team_color(index: usize) -> egui::Color32 {
+        Self::TEAM_COLORS[index % Self::TEAM_COLORS.len()]
+    }
 }
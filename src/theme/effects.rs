@@ -73,6 +73,9 @@ pub fn paint_glow_rect(
     }
 }
 
+/// Number of gradient steps for a smooth transition
+pub const GRADIENT_STEPS: usize = 32;
+
 /// Paint a gradient rectangle
 pub fn paint_gradient_rect(
     painter: &egui::Painter,
@@ -82,7 +85,28 @@ pub fn paint_gradient_rect(
     vertical: bool,
     rounding: f32,
 ) {
-    let steps = 32; // Number of gradient steps for smooth transition
+    paint_gradient_rect_with_steps(
+        painter,
+        rect,
+        color1,
+        color2,
+        vertical,
+        rounding,
+        GRADIENT_STEPS,
+    );
+}
+
+/// Paint a gradient rectangle in `steps` bands (at least two)
+pub fn paint_gradient_rect_with_steps(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    color1: egui::Color32,
+    color2: egui::Color32,
+    vertical: bool,
+    rounding: f32,
+    steps: usize,
+) {
+    let steps = steps.max(2);
 
     if vertical {
         let step_height = rect.height() / steps as f32;
@@ -19,8 +19,19 @@ pub use transitions::TransitionController;
 pub use utils::adjust_brightness;
 
 use eframe::egui;
+use serde::{Deserialize, Serialize};
+
+/// Panel contrast for the whole UI; High suits bright rooms and washed-out projectors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ContrastMode {
+    #[default]
+    Standard,
+    High,
+}
 
-pub fn apply_global_style(ctx: &egui::Context) {
+/// The app's visuals: the cyberpunk dark look, or its high-contrast variant
+/// with brighter panels and heavier borders
+pub fn global_visuals(contrast: ContrastMode) -> egui::Visuals {
     let mut visuals = egui::Visuals::dark();
 
     // Enhanced text styling
@@ -62,7 +73,23 @@ pub fn apply_global_style(ctx: &egui::Context) {
     // Enhanced hyperlink styling
     visuals.hyperlink_color = utils::adjust_brightness(Palette::NEON_BLUE, 1.2);
 
-    ctx.set_visuals(visuals);
+    if contrast == ContrastMode::High {
+        visuals.override_text_color = Some(egui::Color32::WHITE);
+        visuals.panel_fill = utils::adjust_brightness(Palette::PANEL_GRADIENT_START, 1.6);
+        visuals.window_fill = utils::adjust_brightness(Palette::BG_ACTIVE, 1.6);
+        visuals.window_stroke = egui::Stroke::new(2.0, Palette::CYAN);
+        visuals.widgets.noninteractive.bg_stroke = egui::Stroke::new(1.5, Palette::CYAN);
+        visuals.widgets.inactive.bg_fill = utils::adjust_brightness(Palette::BG_PANEL, 1.5);
+        visuals.widgets.inactive.bg_stroke = egui::Stroke::new(2.0, Palette::CYAN);
+        visuals.widgets.active.bg_stroke.width = 3.0;
+        visuals.widgets.hovered.bg_stroke.width = 3.5;
+        visuals.selection.stroke.width = 3.0;
+    }
+    visuals
+}
+
+pub fn apply_global_style(ctx: &egui::Context, contrast: ContrastMode) {
+    ctx.set_visuals(global_visuals(contrast));
 
     // Enhanced spacing and sizing
     let mut style = (*ctx.style()).clone();
@@ -104,4 +131,38 @@ pub fn paint_board_background(ui: &egui::Ui) {
         6.0,
         egui::Stroke::new(1.0, Palette::CYAN.linear_multiply(0.5)),
     );
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brightness(color: egui::Color32) -> u32 {
+        color.r() as u32 + color.g() as u32 + color.b() as u32
+    }
+
+    #[test]
+    fn test_high_contrast_is_brighter_with_heavier_borders() {
+        let standard = global_visuals(ContrastMode::Standard);
+        let high = global_visuals(ContrastMode::High);
+
+        assert!(brightness(high.panel_fill) > brightness(standard.panel_fill));
+        assert!(brightness(high.window_fill) > brightness(standard.window_fill));
+        assert!(
+            brightness(high.widgets.inactive.bg_fill)
+                > brightness(standard.widgets.inactive.bg_fill)
+        );
+        for (high, standard) in [
+            (
+                &high.widgets.noninteractive,
+                &standard.widgets.noninteractive,
+            ),
+            (&high.widgets.inactive, &standard.widgets.inactive),
+            (&high.widgets.hovered, &standard.widgets.hovered),
+            (&high.widgets.active, &standard.widgets.active),
+        ] {
+            assert!(high.bg_stroke.width > standard.bg_stroke.width);
+        }
+        assert!(high.window_stroke.width > standard.window_stroke.width);
+    }
+}
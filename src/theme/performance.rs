@@ -1,2 +1,83 @@
 // Performance monitoring and quality settings
-// Removed to reduce warnings - can be restored if needed
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+
+/// How much effect work the board spends on each cell; the lower presets suit
+/// integrated GPUs and very large boards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum QualityPreset {
+    Low,
+    Medium,
+    #[default]
+    High,
+    /// Full glow and gradients even on cells too small to show them
+    Ultra,
+}
+
+impl QualityPreset {
+    pub const ALL: [QualityPreset; 4] = [
+        QualityPreset::Low,
+        QualityPreset::Medium,
+        QualityPreset::High,
+        QualityPreset::Ultra,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            QualityPreset::Low => "Low",
+            QualityPreset::Medium => "Medium",
+            QualityPreset::High => "High",
+            QualityPreset::Ultra => "Ultra",
+        }
+    }
+}
+
+/// Clue count past which High and Ultra may stutter, unless the host picks another limit
+pub const DEFAULT_LARGE_BOARD_CLUES: usize = 48;
+
+/// Whether a board of `clues` cells is big enough, past `threshold`, that the
+/// host should hear about turning `quality` down
+pub fn should_suggest_lower_quality(
+    clues: usize,
+    threshold: usize,
+    quality: QualityPreset,
+) -> bool {
+    clues > threshold && matches!(quality, QualityPreset::High | QualityPreset::Ultra)
+}
+
+fn quality_id() -> egui::Id {
+    egui::Id::new("quality_preset")
+}
+
+/// Make `quality` the preset painters see for the rest of this frame
+pub fn set_quality(ctx: &egui::Context, quality: QualityPreset) {
+    ctx.data_mut(|d| d.insert_temp(quality_id(), quality));
+}
+
+/// Preset the app set for this frame, High if it hasn't set one
+pub fn quality(ctx: &egui::Context) -> QualityPreset {
+    ctx.data(|d| d.get_temp(quality_id())).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggest_lower_quality_only_for_large_boards_on_high_settings() {
+        use QualityPreset::*;
+        let suggest = |clues, quality| {
+            should_suggest_lower_quality(clues, DEFAULT_LARGE_BOARD_CLUES, quality)
+        };
+        // 10×8 board
+        assert!(suggest(80, High));
+        assert!(suggest(80, Ultra));
+        assert!(!suggest(80, Medium));
+        assert!(!suggest(80, Low));
+        // The standard 6×5 board and a board right at the limit are fine
+        assert!(!suggest(30, Ultra));
+        assert!(!suggest(DEFAULT_LARGE_BOARD_CLUES, High));
+        // A lower limit warns sooner
+        assert!(should_suggest_lower_quality(30, 24, High));
+    }
+}
@@ -1,90 +1,298 @@
 // Game board rendering components
+use crate::core::Difficulty;
 use crate::theme::{
     animations::ease_in_out,
     colors::Palette,
-    effects::{GlowConfig, paint_completion_particles, paint_glow_rect, paint_gradient_rect},
+    effects::{
+        GRADIENT_STEPS, GlowConfig, paint_completion_particles, paint_glow_rect,
+        paint_gradient_rect, paint_gradient_rect_with_steps,
+    },
+    performance::{QualityPreset, quality},
     utils::{adjust_brightness, lerp_color, with_alpha},
 };
 use eframe::egui;
+use serde::{Deserialize, Serialize};
+
+/// What a board cell shows: still open, already shown but never won, or done
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClueCellState {
+    Open,
+    RevealedUnsolved,
+    Solved,
+}
+
+impl ClueCellState {
+    pub fn from_flags(revealed: bool, solved: bool) -> Self {
+        if solved {
+            ClueCellState::Solved
+        } else if revealed {
+            ClueCellState::RevealedUnsolved
+        } else {
+            ClueCellState::Open
+        }
+    }
+}
+
+/// Cells smaller than this (in square points) skip glow and use a coarse gradient;
+/// at that size the difference can't be seen but the fill cost still adds up
+pub const TINY_CELL_AREA: f32 = 2400.0;
+/// Gradient bands used on tiny cells
+pub const MIN_GRADIENT_STEPS: usize = 4;
+
+/// How much effect work a cell of a given size is worth
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellEffectBudget {
+    pub glow: bool,
+    pub gradient_steps: usize,
+}
+
+pub fn cell_effect_budget(rect: egui::Rect, quality: QualityPreset) -> CellEffectBudget {
+    let tiny = rect.area() < TINY_CELL_AREA;
+    match quality {
+        QualityPreset::Low => CellEffectBudget {
+            glow: false,
+            gradient_steps: MIN_GRADIENT_STEPS,
+        },
+        QualityPreset::Medium | QualityPreset::High if tiny => CellEffectBudget {
+            glow: false,
+            gradient_steps: MIN_GRADIENT_STEPS,
+        },
+        QualityPreset::Medium => CellEffectBudget {
+            glow: false,
+            gradient_steps: GRADIENT_STEPS / 2,
+        },
+        QualityPreset::High | QualityPreset::Ultra => CellEffectBudget {
+            glow: true,
+            gradient_steps: GRADIENT_STEPS,
+        },
+    }
+}
+
+/// Colors picked for a clue cell before painting
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ClueCellColors {
+    bg_start: egui::Color32,
+    bg_end: egui::Color32,
+    border: egui::Color32,
+    text: egui::Color32,
+    glow: f32,
+}
+
+/// Spacing between board cells and how round their corners are
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BoardStyle {
+    pub grid_gap: f32,
+    pub cell_rounding: f32,
+}
+
+impl Default for BoardStyle {
+    fn default() -> Self {
+        Self {
+            grid_gap: 12.0,
+            cell_rounding: 8.0,
+        }
+    }
+}
+
+impl BoardStyle {
+    pub const GAP_RANGE: std::ops::RangeInclusive<f32> = 0.0..=32.0;
+    pub const ROUNDING_RANGE: std::ops::RangeInclusive<f32> = 0.0..=24.0;
+
+    /// The style pulled back into ranges where the board still reads as a grid
+    pub fn clamped(self) -> Self {
+        let clamp = |v: f32, range: std::ops::RangeInclusive<f32>| {
+            if v.is_finite() {
+                v.clamp(*range.start(), *range.end())
+            } else {
+                *range.start()
+            }
+        };
+        Self {
+            grid_gap: clamp(self.grid_gap, Self::GAP_RANGE),
+            cell_rounding: clamp(self.cell_rounding, Self::ROUNDING_RANGE),
+        }
+    }
+}
+
+/// Header and clue cell sizes for a `cols` x `rows` board filling `available`,
+/// with `gap` between cells and between the header and the first row
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoardCellSizes {
+    pub header: egui::Vec2,
+    pub cell: egui::Vec2,
+}
+
+pub fn board_cell_sizes(
+    available: egui::Vec2,
+    cols: usize,
+    rows: usize,
+    gap: f32,
+) -> BoardCellSizes {
+    let cols = cols.max(1);
+    let total_gap_x = gap * cols.saturating_sub(1) as f32;
+    let total_gap_y = gap * rows as f32;
+    let cell_w = ((available.x - total_gap_x) / cols as f32).max(60.0);
+    // The header takes 15% of the height, kept between 40 and 60px
+    let header_h = (available.y * 0.15).clamp(40.0, 60.0);
+    let cell_h = if rows > 0 {
+        ((available.y - header_h - total_gap_y) / rows as f32).max(50.0)
+    } else {
+        70.0
+    };
+    BoardCellSizes {
+        header: egui::vec2(cell_w, header_h),
+        cell: egui::vec2(cell_w, cell_h),
+    }
+}
+
+/// Shortest clue row in the scrolling layout; big enough to hit with a finger
+pub const SCROLL_MIN_CELL_HEIGHT: f32 = 96.0;
+
+/// The scrolling layout's split of the board: category headers pinned in `header`,
+/// clue rows scrolling inside `rows` below them
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoardRegions {
+    pub header: egui::Rect,
+    pub rows: egui::Rect,
+}
+
+pub fn split_board_regions(board: egui::Rect, header_h: f32, gap: f32) -> BoardRegions {
+    let header_bottom = (board.top() + header_h).min(board.bottom());
+    let rows_top = (header_bottom + gap).min(board.bottom());
+    BoardRegions {
+        header: egui::Rect::from_min_max(board.min, egui::pos2(board.right(), header_bottom)),
+        rows: egui::Rect::from_min_max(egui::pos2(board.left(), rows_top), board.max),
+    }
+}
 
 pub fn paint_enhanced_clue_cell(
     painter: &egui::Painter,
     rect: egui::Rect,
     points: u32,
-    is_solved: bool,
+    state: ClueCellState,
     is_hovered: bool,
+    show_value: bool,
+    rounding: f32,
 ) {
-    paint_enhanced_clue_cell_with_animation(painter, rect, points, is_solved, is_hovered, 1.0)
+    let label = clue_cell_label(points, show_value);
+    paint_enhanced_clue_cell_with_animation(painter, rect, &label, state, is_hovered, rounding, 1.0)
+}
+
+/// Text drawn on a clue cell: the value, or "?" on a mystery board
+pub fn clue_cell_label(points: u32, show_value: bool) -> String {
+    if show_value {
+        points.to_string()
+    } else {
+        "?".to_string()
+    }
+}
+
+fn clue_cell_colors(
+    state: ClueCellState,
+    is_hovered: bool,
+    animation_progress: f32,
+) -> ClueCellColors {
+    let animation_t = ease_in_out(animation_progress);
+    match state {
+        ClueCellState::Solved => {
+            let solved_bg_start = adjust_brightness(Palette::BG_PANEL, 0.8);
+            let solved_bg_end = adjust_brightness(Palette::BG_PANEL, 0.6);
+            let solved_border = adjust_brightness(Palette::CYAN, 0.5);
+            let solved_text = adjust_brightness(Palette::TEXT, 0.6);
+
+            if animation_progress < 1.0 {
+                // Animate transition to solved state
+                let active_bg_start = adjust_brightness(Palette::BG_ACTIVE, 1.1);
+                let active_bg_end = adjust_brightness(Palette::BG_ACTIVE, 0.9);
+                let active_border = Palette::CYAN;
+                let active_text = Palette::TEXT;
+
+                ClueCellColors {
+                    bg_start: lerp_color(active_bg_start, solved_bg_start, animation_t),
+                    bg_end: lerp_color(active_bg_end, solved_bg_end, animation_t),
+                    border: lerp_color(active_border, solved_border, animation_t),
+                    text: lerp_color(active_text, solved_text, animation_t),
+                    glow: (1.0 - animation_t) * 0.8, // Fade out glow as it becomes solved
+                }
+            } else {
+                ClueCellColors {
+                    bg_start: solved_bg_start,
+                    bg_end: solved_bg_end,
+                    border: solved_border,
+                    text: solved_text,
+                    glow: 0.0,
+                }
+            }
+        }
+        // Shown once but nobody won it: dimmer than open, amber-edged
+        ClueCellState::RevealedUnsolved => {
+            let lift = if is_hovered { 1.2 } else { 1.0 };
+            ClueCellColors {
+                bg_start: adjust_brightness(Palette::BG_ACTIVE, 0.8 * lift),
+                bg_end: adjust_brightness(Palette::BG_ACTIVE, 0.65 * lift),
+                border: adjust_brightness(Palette::AMBER_GLOW, 0.7 * lift),
+                text: adjust_brightness(Palette::TEXT, 0.8 * lift),
+                glow: if is_hovered { 0.3 } else { 0.0 },
+            }
+        }
+        ClueCellState::Open if is_hovered => {
+            let hover_intensity = 1.0 + (animation_t * 0.3); // Smooth hover animation
+            ClueCellColors {
+                bg_start: adjust_brightness(Palette::BG_ACTIVE, 1.3 * hover_intensity),
+                bg_end: adjust_brightness(Palette::BG_ACTIVE, 1.1 * hover_intensity),
+                border: adjust_brightness(Palette::CYAN, 1.4 * hover_intensity),
+                text: adjust_brightness(Palette::TEXT, 1.2 * hover_intensity),
+                glow: 0.6 * hover_intensity,
+            }
+        }
+        ClueCellState::Open => ClueCellColors {
+            bg_start: adjust_brightness(Palette::BG_ACTIVE, 1.1),
+            bg_end: adjust_brightness(Palette::BG_ACTIVE, 0.9),
+            border: Palette::CYAN,
+            text: Palette::TEXT,
+            glow: 0.2,
+        },
+    }
 }
 
 pub fn paint_enhanced_clue_cell_with_animation(
     painter: &egui::Painter,
     rect: egui::Rect,
-    points: u32,
-    is_solved: bool,
+    label: &str,
+    state: ClueCellState,
     is_hovered: bool,
+    rounding: f32,
     animation_progress: f32, // 0.0 to 1.0 for transition animations
 ) {
-    let rounding = 8.0;
-    let animation_t = ease_in_out(animation_progress);
+    let is_solved = state == ClueCellState::Solved;
 
     // Determine cell state colors with animation support
-    let (bg_start, bg_end, border_color, text_color, glow_intensity) = if is_solved {
-        let solved_bg_start = adjust_brightness(Palette::BG_PANEL, 0.8);
-        let solved_bg_end = adjust_brightness(Palette::BG_PANEL, 0.6);
-        let solved_border = adjust_brightness(Palette::CYAN, 0.5);
-        let solved_text = adjust_brightness(Palette::TEXT, 0.6);
-
-        if animation_progress < 1.0 {
-            // Animate transition to solved state
-            let active_bg_start = adjust_brightness(Palette::BG_ACTIVE, 1.1);
-            let active_bg_end = adjust_brightness(Palette::BG_ACTIVE, 0.9);
-            let active_border = Palette::CYAN;
-            let active_text = Palette::TEXT;
-
-            (
-                lerp_color(active_bg_start, solved_bg_start, animation_t),
-                lerp_color(active_bg_end, solved_bg_end, animation_t),
-                lerp_color(active_border, solved_border, animation_t),
-                lerp_color(active_text, solved_text, animation_t),
-                (1.0 - animation_t) * 0.8, // Fade out glow as it becomes solved
-            )
-        } else {
-            (
-                solved_bg_start,
-                solved_bg_end,
-                solved_border,
-                solved_text,
-                0.0,
-            )
-        }
-    } else if is_hovered {
-        let hover_intensity = 1.0 + (animation_t * 0.3); // Smooth hover animation
-        (
-            adjust_brightness(Palette::BG_ACTIVE, 1.3 * hover_intensity),
-            adjust_brightness(Palette::BG_ACTIVE, 1.1 * hover_intensity),
-            adjust_brightness(Palette::CYAN, 1.4 * hover_intensity),
-            adjust_brightness(Palette::TEXT, 1.2 * hover_intensity),
-            0.6 * hover_intensity,
-        )
-    } else {
-        (
-            adjust_brightness(Palette::BG_ACTIVE, 1.1),
-            adjust_brightness(Palette::BG_ACTIVE, 0.9),
-            Palette::CYAN,
-            Palette::TEXT,
-            0.2,
-        )
-    };
+    let ClueCellColors {
+        bg_start,
+        bg_end,
+        border: border_color,
+        text: text_color,
+        glow: glow_intensity,
+    } = clue_cell_colors(state, is_hovered, animation_progress);
+    let budget = cell_effect_budget(rect, quality(painter.ctx()));
 
     // Add glow effect for interactive cells
-    if !is_solved && glow_intensity > 0.0 {
+    if budget.glow && !is_solved && glow_intensity > 0.0 {
         let glow_config = GlowConfig::cyan_glow(glow_intensity, 6.0);
         paint_glow_rect(painter, rect, rounding, glow_config);
     }
 
     // Paint gradient background
-    paint_gradient_rect(painter, rect, bg_start, bg_end, true, rounding);
+    paint_gradient_rect_with_steps(
+        painter,
+        rect,
+        bg_start,
+        bg_end,
+        true,
+        rounding,
+        budget.gradient_steps,
+    );
 
     // Enhanced border with different thickness based on state
     let border_width = if is_hovered && !is_solved { 3.0 } else { 2.0 };
@@ -100,7 +308,7 @@ pub fn paint_enhanced_clue_cell_with_animation(
         let highlight_color = with_alpha(adjust_brightness(border_color, 1.5), 60);
         painter.rect_stroke(
             inner_rect,
-            rounding - 2.0,
+            (rounding - 2.0).max(0.0),
             egui::Stroke::new(1.0, highlight_color),
         );
     }
@@ -114,7 +322,7 @@ pub fn paint_enhanced_clue_cell_with_animation(
     painter.text(
         rect.center() + shadow_offset,
         egui::Align2::CENTER_CENTER,
-        format!("{}", points),
+        label,
         egui::FontId::proportional(font_size),
         shadow_color,
     );
@@ -123,11 +331,22 @@ pub fn paint_enhanced_clue_cell_with_animation(
     painter.text(
         rect.center(),
         egui::Align2::CENTER_CENTER,
-        format!("{}", points),
+        label,
         egui::FontId::proportional(font_size),
         text_color,
     );
 
+    // Eye marker so the host can tell which clues have already been shown
+    if state == ClueCellState::RevealedUnsolved {
+        painter.text(
+            rect.right_top() + egui::vec2(-6.0, 4.0),
+            egui::Align2::RIGHT_TOP,
+            "👁",
+            egui::FontId::proportional(12.0),
+            border_color,
+        );
+    }
+
     // Add particle effects for completion animation
     if is_solved && animation_progress < 1.0 {
         paint_completion_particles(painter, rect, animation_progress);
@@ -138,9 +357,8 @@ pub fn paint_enhanced_category_header(
     painter: &egui::Painter,
     rect: egui::Rect,
     category_name: &str,
+    rounding: f32,
 ) {
-    let rounding = 8.0;
-
     // Gradient background for header
     let bg_start = adjust_brightness(Palette::BG_ACTIVE, 1.2);
     let bg_end = adjust_brightness(Palette::BG_ACTIVE, 0.9);
@@ -176,6 +394,33 @@ pub fn paint_enhanced_category_header(
     );
 }
 
+/// Largest rect of the given width/height `aspect` that fits in `available`, centered.
+/// With no aspect (or a nonsensical one) the board fills the whole area.
+pub fn compute_board_layout(available: egui::Rect, aspect: Option<f32>) -> egui::Rect {
+    let Some(aspect) = aspect.filter(|a| a.is_finite() && *a > 0.0) else {
+        return available;
+    };
+    let size = available.size();
+    let board_size = if size.x / size.y > aspect {
+        egui::vec2(size.y * aspect, size.y)
+    } else {
+        egui::vec2(size.x, size.x / aspect)
+    };
+    egui::Rect::from_center_size(available.center(), board_size)
+}
+
+/// Heatmap tint for a clue's difficulty. Uses the Okabe-Ito bluish green, yellow and
+/// vermillion, which stay distinct under the common forms of color blindness; unrated
+/// clues get a neutral grey.
+pub fn difficulty_heat_color(difficulty: Option<Difficulty>) -> egui::Color32 {
+    match difficulty {
+        Some(Difficulty::Easy) => egui::Color32::from_rgb(0, 158, 115),
+        Some(Difficulty::Medium) => egui::Color32::from_rgb(240, 228, 66),
+        Some(Difficulty::Hard) => egui::Color32::from_rgb(213, 94, 0),
+        None => egui::Color32::from_gray(128),
+    }
+}
+
 /// Config-mode clue cell rendering with fill-status indicators
 ///
 /// - If `is_filled` is true (both question and answer provided), paint deep neon blue-ish look
@@ -249,4 +494,173 @@ pub fn paint_config_clue_cell(
         egui::FontId::proportional(font_size),
         text,
     );
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tiny_cells_skip_glow_and_use_minimum_gradient() {
+        let tiny = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(40.0, 30.0));
+        assert_eq!(
+            cell_effect_budget(tiny, QualityPreset::High),
+            CellEffectBudget {
+                glow: false,
+                gradient_steps: MIN_GRADIENT_STEPS,
+            }
+        );
+
+        let normal = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(160.0, 90.0));
+        assert_eq!(
+            cell_effect_budget(normal, QualityPreset::High),
+            CellEffectBudget {
+                glow: true,
+                gradient_steps: GRADIENT_STEPS,
+            }
+        );
+    }
+
+    #[test]
+    fn test_quality_presets_scale_cell_effects() {
+        let tiny = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(40.0, 30.0));
+        let normal = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(160.0, 90.0));
+        let low = cell_effect_budget(normal, QualityPreset::Low);
+        let medium = cell_effect_budget(normal, QualityPreset::Medium);
+        let high = cell_effect_budget(normal, QualityPreset::High);
+        assert!(!low.glow && !medium.glow && high.glow);
+        assert!(low.gradient_steps < medium.gradient_steps);
+        assert!(medium.gradient_steps < high.gradient_steps);
+        // Ultra spends the full budget even where it can barely be seen
+        assert_eq!(cell_effect_budget(tiny, QualityPreset::Ultra), high);
+    }
+
+    #[test]
+    fn test_clue_cell_label_hides_value_on_mystery_board() {
+        assert_eq!(clue_cell_label(400, true), "400");
+        assert_eq!(clue_cell_label(400, false), "?");
+    }
+
+    #[test]
+    fn test_cell_state_from_flags() {
+        assert_eq!(ClueCellState::from_flags(false, false), ClueCellState::Open);
+        assert_eq!(
+            ClueCellState::from_flags(true, false),
+            ClueCellState::RevealedUnsolved
+        );
+        assert_eq!(ClueCellState::from_flags(true, true), ClueCellState::Solved);
+        assert_eq!(
+            ClueCellState::from_flags(false, true),
+            ClueCellState::Solved
+        );
+    }
+
+    #[test]
+    fn test_three_cell_states_get_distinct_styling() {
+        let open = clue_cell_colors(ClueCellState::Open, false, 1.0);
+        let revealed = clue_cell_colors(ClueCellState::RevealedUnsolved, false, 1.0);
+        let solved = clue_cell_colors(ClueCellState::Solved, false, 1.0);
+
+        assert_ne!(open, revealed);
+        assert_ne!(open, solved);
+        assert_ne!(revealed, solved);
+        // Revealed cells are marked with their own border color, not just dimmed
+        assert_ne!(revealed.border, open.border);
+        assert_ne!(revealed.border, solved.border);
+    }
+
+    #[test]
+    fn test_board_layout_letterboxes_to_aspect() {
+        let window = egui::Rect::from_min_size(egui::pos2(10.0, 20.0), egui::vec2(900.0, 900.0));
+        let board = compute_board_layout(window, Some(16.0 / 9.0));
+
+        assert!((board.width() / board.height() - 16.0 / 9.0).abs() < 1e-4);
+        assert_eq!(board.width(), 900.0);
+        assert!((board.center() - window.center()).length() < 1e-3);
+        assert!(window.contains_rect(board));
+
+        // No lock keeps the current fill behaviour
+        assert_eq!(compute_board_layout(window, None), window);
+    }
+
+    #[test]
+    fn test_heat_color_is_total_and_distinct() {
+        let mut colors: Vec<egui::Color32> = Difficulty::ALL
+            .iter()
+            .map(|d| difficulty_heat_color(Some(*d)))
+            .collect();
+        colors.push(difficulty_heat_color(None));
+
+        for (i, a) in colors.iter().enumerate() {
+            assert_eq!(a.a(), 255);
+            for b in &colors[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+        // Unrated clues stay neutral
+        let neutral = difficulty_heat_color(None);
+        assert!(neutral.r() == neutral.g() && neutral.g() == neutral.b());
+    }
+
+    #[test]
+    fn test_configured_rounding_reaches_the_clue_cell() {
+        let ctx = egui::Context::default();
+        let rect = egui::Rect::from_min_size(egui::pos2(10.0, 10.0), egui::vec2(160.0, 90.0));
+        let output = ctx.run(egui::RawInput::default(), |ctx| {
+            let painter = ctx.layer_painter(egui::LayerId::background());
+            paint_enhanced_clue_cell(&painter, rect, 400, ClueCellState::Open, false, true, 14.0);
+        });
+        let roundings: Vec<egui::Rounding> = output
+            .shapes
+            .iter()
+            .filter_map(|clipped| match &clipped.shape {
+                egui::Shape::Rect(r) if r.rect == rect => Some(r.rounding),
+                _ => None,
+            })
+            .collect();
+        assert!(!roundings.is_empty());
+        assert!(roundings.iter().all(|r| *r == egui::Rounding::same(14.0)));
+    }
+
+    #[test]
+    fn test_board_cell_sizes_use_configured_gap() {
+        let available = egui::vec2(1000.0, 600.0);
+        let tight = board_cell_sizes(available, 5, 5, 0.0);
+        let loose = board_cell_sizes(available, 5, 5, 20.0);
+        assert_eq!(tight.cell.x, 200.0);
+        // Four gaps across, five down (one under the header)
+        assert_eq!(loose.cell.x, (1000.0 - 4.0 * 20.0) / 5.0);
+        assert_eq!(tight.cell.y - loose.cell.y, 5.0 * 20.0 / 5.0);
+        assert_eq!(tight.header, egui::vec2(200.0, 60.0));
+    }
+
+    #[test]
+    fn test_board_style_clamps_to_sane_ranges() {
+        let wild = BoardStyle {
+            grid_gap: 500.0,
+            cell_rounding: -3.0,
+        }
+        .clamped();
+        assert_eq!(wild.grid_gap, *BoardStyle::GAP_RANGE.end());
+        assert_eq!(wild.cell_rounding, 0.0);
+        assert_eq!(BoardStyle::default().clamped(), BoardStyle::default());
+    }
+
+    #[test]
+    fn test_split_board_regions_pins_header_above_scrolling_rows() {
+        let board = egui::Rect::from_min_size(egui::pos2(20.0, 40.0), egui::vec2(800.0, 600.0));
+        let regions = split_board_regions(board, 60.0, 12.0);
+        assert_eq!(regions.header.top(), 40.0);
+        assert_eq!(regions.header.height(), 60.0);
+        assert_eq!(regions.rows.top(), 40.0 + 60.0 + 12.0);
+        assert_eq!(regions.rows.bottom(), board.bottom());
+        assert_eq!(regions.rows.width(), board.width());
+        assert!(!regions.header.intersects(regions.rows));
+
+        // A board shorter than its header leaves nothing to scroll, never a negative area
+        let squat = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(800.0, 50.0));
+        let regions = split_board_regions(squat, 60.0, 12.0);
+        assert_eq!(regions.header.height(), 50.0);
+        assert_eq!(regions.rows.height(), 0.0);
+    }
+}
@@ -0,0 +1,90 @@
+use eframe::egui;
+
+/// Index Tab should land on next among a modal's `count` focusable widgets,
+/// wrapping at either end. With nothing in the modal focused yet it starts at
+/// the first widget (or the last, going backwards).
+pub fn next_focus_index(current: Option<usize>, count: usize, backwards: bool) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    let next = match (current.filter(|i| *i < count), backwards) {
+        (None, false) => 0,
+        (None, true) => count - 1,
+        (Some(i), false) => (i + 1) % count,
+        (Some(i), true) => (i + count - 1) % count,
+    };
+    Some(next)
+}
+
+/// Keeps Tab cycling through one modal's widgets instead of wandering into the
+/// board behind it, and reports Esc so the modal can cancel.
+/// Register focusable widgets with `add` in tab order, then call `finish` once.
+pub struct FocusTrap {
+    id: egui::Id,
+    widgets: Vec<egui::Id>,
+}
+
+impl FocusTrap {
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id: egui::Id::new(("focus_trap", id_source)),
+            widgets: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, response: &egui::Response) {
+        self.widgets.push(response.id);
+    }
+
+    /// Move Tab/Shift+Tab focus within the modal; true when Esc was pressed
+    pub fn finish(self, ctx: &egui::Context) -> bool {
+        let (tab, backwards, escape) = ctx.input(|i| {
+            (
+                i.key_pressed(egui::Key::Tab),
+                i.modifiers.shift,
+                i.key_pressed(egui::Key::Escape),
+            )
+        });
+        // egui has already moved focus for this Tab press, so go from where it was last frame
+        let last: Option<usize> = ctx.memory_mut(|m| m.data.get_temp(self.id));
+        let current = if tab {
+            let next = next_focus_index(last, self.widgets.len(), backwards);
+            if let Some(i) = next {
+                ctx.memory_mut(|m| m.request_focus(self.widgets[i]));
+            }
+            next
+        } else {
+            ctx.memory(|m| m.focused())
+                .and_then(|id| self.widgets.iter().position(|w| *w == id))
+        };
+        ctx.memory_mut(|m| match current {
+            Some(i) => m.data.insert_temp(self.id, i),
+            None => m.data.remove::<usize>(self.id),
+        });
+        escape
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_focus_index_wraps_within_modal() {
+        assert_eq!(next_focus_index(None, 3, false), Some(0));
+        assert_eq!(next_focus_index(Some(0), 3, false), Some(1));
+        assert_eq!(next_focus_index(Some(2), 3, false), Some(0));
+        // Shift+Tab goes the other way round
+        assert_eq!(next_focus_index(None, 3, true), Some(2));
+        assert_eq!(next_focus_index(Some(0), 3, true), Some(2));
+        assert_eq!(next_focus_index(Some(2), 3, true), Some(1));
+    }
+
+    #[test]
+    fn test_next_focus_index_handles_empty_and_stale() {
+        assert_eq!(next_focus_index(None, 0, false), None);
+        assert_eq!(next_focus_index(Some(1), 0, true), None);
+        // A remembered index past the end (widgets removed) starts over
+        assert_eq!(next_focus_index(Some(5), 2, false), Some(0));
+    }
+}
@@ -5,8 +5,7 @@ use std::collections::HashMap;
 #[derive(Default, Clone)]
 pub struct ManualPointsModal {
     pub visible: bool,
-    pub team_inputs: HashMap<u32, String>,
-    pub validation_errors: HashMap<u32, String>,
+    pub team_inputs: HashMap<u32, i32>,
     pub pending_changes: Vec<(u32, i32)>,
 }
 
@@ -30,69 +29,31 @@ impl ManualPointsModal {
 
     pub fn initialize_inputs(&mut self, teams: &[Team]) {
         self.team_inputs.clear();
-        self.validation_errors.clear();
         self.pending_changes.clear();
 
         for team in teams {
-            self.team_inputs.insert(team.id, team.score.to_string());
+            self.team_inputs.insert(team.id, team.score);
         }
     }
 
     fn clear_state(&mut self) {
         self.team_inputs.clear();
-        self.validation_errors.clear();
         self.pending_changes.clear();
     }
 
-    fn validate_input(&mut self, team_id: u32, input: &str) -> bool {
-        self.validation_errors.remove(&team_id);
-
-        if input.trim().is_empty() {
-            self.validation_errors
-                .insert(team_id, "Points cannot be empty".to_string());
-            return false;
-        }
-
-        match input.trim().parse::<i32>() {
-            Ok(points) => {
-                // Range validation - allow reasonable point values
-                if points < -999999 || points > 999999 {
-                    self.validation_errors.insert(
-                        team_id,
-                        "Points must be between -999,999 and 999,999".to_string(),
-                    );
-                    false
-                } else {
-                    true
-                }
-            }
-            Err(_) => {
-                self.validation_errors
-                    .insert(team_id, "Please enter a valid number".to_string());
-                false
-            }
-        }
-    }
-
+    /// Collect the scores that differ from the teams' current ones; false if there are none
     fn prepare_changes(&mut self, teams: &[Team]) -> bool {
         self.pending_changes.clear();
-        let mut all_valid = true;
 
         for team in teams {
-            if let Some(input) = self.team_inputs.get(&team.id).cloned() {
-                if self.validate_input(team.id, &input) {
-                    if let Ok(new_points) = input.trim().parse::<i32>() {
-                        if new_points != team.score {
-                            self.pending_changes.push((team.id, new_points));
-                        }
-                    }
-                } else {
-                    all_valid = false;
+            if let Some(&new_points) = self.team_inputs.get(&team.id) {
+                if new_points != team.score {
+                    self.pending_changes.push((team.id, new_points));
                 }
             }
         }
 
-        all_valid
+        !self.pending_changes.is_empty()
     }
 }
 
@@ -156,37 +117,18 @@ pub fn show_manual_points_modal(
                                             );
                                             ui.add_space(10.0);
 
-                                            // Points input
+                                            // Points input (always a whole number in range)
                                             let input = modal
                                                 .team_inputs
                                                 .entry(team.id)
-                                                .or_insert_with(|| team.score.to_string());
-                                            let _input_value = input.clone();
-                                            let response = ui.add(
-                                                egui::TextEdit::singleline(input)
-                                                    .desired_width(100.0)
-                                                    .hint_text("Points"),
+                                                .or_insert(team.score);
+                                            crate::ui::score_input(
+                                                ui,
+                                                input,
+                                                crate::ui::score_input::SCORE_MIN,
+                                                crate::ui::score_input::SCORE_MAX,
                                             );
-
-                                            // Real-time validation
-                                            if response.changed() {
-                                                let current_input = modal
-                                                    .team_inputs
-                                                    .get(&team.id)
-                                                    .cloned()
-                                                    .unwrap_or_default();
-                                                modal.validate_input(team.id, &current_input);
-                                            }
                                         });
-
-                                        // Show validation error if any
-                                        if let Some(error) = modal.validation_errors.get(&team.id) {
-                                            ui.label(
-                                                egui::RichText::new(error)
-                                                    .color(egui::Color32::from_rgb(255, 100, 100))
-                                                    .size(12.0),
-                                            );
-                                        }
                                         ui.add_space(8.0);
                                     }
                                 });
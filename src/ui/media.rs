@@ -0,0 +1,108 @@
+use eframe::egui;
+use egui::load::{TextureLoadResult, TexturePoll};
+
+use crate::core::ClueMedia;
+use crate::theme::Palette;
+
+/// What the overlay should draw for a clue's image this frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaView {
+    /// Still decoding; keep the space and wait
+    Loading,
+    Image,
+    /// First failure: forget the cached error and load again
+    Retry,
+    /// Failed after the retry: framed placeholder with the caption
+    Placeholder,
+}
+
+/// Decide how to render media from the loader's answer and whether a retry was spent
+pub fn media_view(load: &TextureLoadResult, retried: bool) -> MediaView {
+    match load {
+        Ok(TexturePoll::Ready { .. }) => MediaView::Image,
+        Ok(TexturePoll::Pending { .. }) => MediaView::Loading,
+        Err(_) if retried => MediaView::Placeholder,
+        Err(_) => MediaView::Retry,
+    }
+}
+
+/// Draw a clue's image within `max_size`, degrading to a captioned placeholder frame
+/// when the file is missing or can't be decoded. `id_source` keys the retry flag.
+pub fn show_clue_media(
+    ui: &mut egui::Ui,
+    id_source: impl std::hash::Hash,
+    media: &ClueMedia,
+    max_size: egui::Vec2,
+) {
+    let uri = media.uri();
+    let retry_id = egui::Id::new(("media_retry", id_source));
+    let retried: bool = ui
+        .ctx()
+        .memory_mut(|m| m.data.get_temp(retry_id))
+        .unwrap_or(false);
+
+    let image = egui::Image::new(uri.clone())
+        .max_size(max_size)
+        .maintain_aspect_ratio(true);
+    let load = image.load_for_size(ui.ctx(), max_size);
+    match media_view(&load, retried) {
+        MediaView::Image => {
+            ui.add(image);
+        }
+        MediaView::Loading => {
+            ui.add_sized(max_size * 0.5, egui::Spinner::new());
+        }
+        MediaView::Retry => {
+            ui.ctx().forget_image(&uri);
+            ui.ctx().memory_mut(|m| m.data.insert_temp(retry_id, true));
+            ui.ctx().request_repaint();
+            ui.allocate_space(max_size * 0.5);
+        }
+        MediaView::Placeholder => {
+            let (rect, _) = ui.allocate_exact_size(max_size * 0.5, egui::Sense::hover());
+            let painter = ui.painter_at(rect);
+            painter.rect_stroke(rect, 8.0, egui::Stroke::new(2.0, Palette::SUBTLE_TEAL));
+            painter.text(
+                rect.center(),
+                egui::Align2::CENTER_CENTER,
+                "🖼",
+                egui::FontId::proportional(48.0),
+                Palette::SUBTLE_TEAL,
+            );
+        }
+    }
+
+    if !media.caption.trim().is_empty() {
+        ui.label(
+            egui::RichText::new(&media.caption)
+                .size(18.0)
+                .italics()
+                .color(Palette::TEXT),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::load::{LoadError, SizedTexture};
+
+    #[test]
+    fn test_media_view_falls_back_after_failed_retry() {
+        let failed: TextureLoadResult = Err(LoadError::Loading("missing file".to_string()));
+        assert_eq!(media_view(&failed, false), MediaView::Retry);
+        assert_eq!(media_view(&failed, true), MediaView::Placeholder);
+    }
+
+    #[test]
+    fn test_media_view_renders_loaded_image() {
+        let ready: TextureLoadResult = Ok(TexturePoll::Ready {
+            texture: SizedTexture::new(egui::TextureId::default(), egui::vec2(64.0, 48.0)),
+        });
+        assert_eq!(media_view(&ready, false), MediaView::Image);
+        assert_eq!(media_view(&ready, true), MediaView::Image);
+
+        let pending: TextureLoadResult = Ok(TexturePoll::Pending { size: None });
+        assert_eq!(media_view(&pending, false), MediaView::Loading);
+    }
+}
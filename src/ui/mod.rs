@@ -1,8 +1,12 @@
 // UI module for game-specific components
 pub mod board;
+pub mod focus;
 pub mod indicators;
 pub mod manual_points_modal;
+pub mod media;
 pub mod modals;
+pub mod score_input;
+pub mod toasts;
 
 // Enhanced UI components
 pub mod cell_manager;
@@ -10,9 +14,17 @@ pub mod header_animations;
 pub mod layout_transitions;
 
 // Re-export commonly used items
-pub use board::{paint_config_clue_cell, paint_enhanced_category_header, paint_enhanced_clue_cell};
+pub use board::{
+    BoardStyle, ClueCellState, SCROLL_MIN_CELL_HEIGHT, board_cell_sizes, clue_cell_label,
+    compute_board_layout, difficulty_heat_color, paint_config_clue_cell,
+    paint_enhanced_category_header, paint_enhanced_clue_cell, split_board_regions,
+};
+pub use focus::FocusTrap;
 pub use manual_points_modal::{ManualPointsModal, show_manual_points_modal};
+pub use media::show_clue_media;
 pub use modals::paint_subtle_modal_background;
+pub use score_input::score_input;
+pub use toasts::{ToastKind, ToastQueue};
 
 // Re-export enhanced UI components
 pub use cell_manager::{CellId, CellManager};
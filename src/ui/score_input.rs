@@ -0,0 +1,72 @@
+use eframe::egui;
+
+/// Widest score the host can type in by hand
+pub const SCORE_MIN: i32 = -999_999;
+pub const SCORE_MAX: i32 = 999_999;
+
+/// Parse typed text as a whole number clamped to `min..=max`.
+/// Empty or non-numeric text is rejected; "1,000" and "1_000" are accepted.
+pub fn parse_clamped_score(text: &str, min: i32, max: i32) -> Option<i32> {
+    let cleaned: String = text
+        .trim()
+        .chars()
+        .filter(|c| *c != ',' && *c != '_')
+        .collect();
+    if cleaned.is_empty() {
+        return None;
+    }
+    let value: i64 = cleaned.parse().ok()?;
+    Some(value.clamp(min as i64, max as i64) as i32)
+}
+
+/// Numeric input for scores and wagers: drag or type, always a whole number in range
+pub fn score_input(ui: &mut egui::Ui, value: &mut i32, min: i32, max: i32) -> egui::Response {
+    *value = (*value).clamp(min, max);
+    ui.add(
+        egui::DragValue::new(value)
+            .clamp_range(min..=max)
+            .speed(10.0)
+            .custom_parser(move |text| parse_clamped_score(text, min, max).map(f64::from)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rejects_empty_and_non_numeric() {
+        assert_eq!(parse_clamped_score("", 0, 100), None);
+        assert_eq!(parse_clamped_score("   ", 0, 100), None);
+        assert_eq!(parse_clamped_score("abc", 0, 100), None);
+        assert_eq!(parse_clamped_score("12a", 0, 100), None);
+        assert_eq!(parse_clamped_score("1.5", 0, 100), None);
+    }
+
+    #[test]
+    fn test_parse_accepts_negative_within_range() {
+        assert_eq!(
+            parse_clamped_score("-250", SCORE_MIN, SCORE_MAX),
+            Some(-250)
+        );
+        assert_eq!(
+            parse_clamped_score(" 1,000 ", SCORE_MIN, SCORE_MAX),
+            Some(1000)
+        );
+        // Negative input is clamped up when the range doesn't allow it
+        assert_eq!(parse_clamped_score("-250", 0, 100), Some(0));
+    }
+
+    #[test]
+    fn test_parse_clamps_over_max() {
+        assert_eq!(parse_clamped_score("5000", 0, 1000), Some(1000));
+        assert_eq!(
+            parse_clamped_score("99999999999", SCORE_MIN, SCORE_MAX),
+            Some(SCORE_MAX)
+        );
+        assert_eq!(
+            parse_clamped_score("-99999999999", SCORE_MIN, SCORE_MAX),
+            Some(SCORE_MIN)
+        );
+    }
+}
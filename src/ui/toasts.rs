@@ -0,0 +1,133 @@
+use std::time::{Duration, Instant};
+
+use eframe::egui;
+
+use crate::theme::Palette;
+
+/// How long a toast stays on screen
+pub const TOAST_LIFETIME: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastKind {
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastKind {
+    fn color(self) -> egui::Color32 {
+        match self {
+            ToastKind::Success => Palette::CYAN,
+            ToastKind::Warning => Palette::AMBER_GLOW,
+            ToastKind::Error => Palette::MAGENTA,
+        }
+    }
+}
+
+/// A short, non-blocking notification
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub text: String,
+    pub kind: ToastKind,
+    pub created: Instant,
+}
+
+/// Toasts waiting to expire, oldest first
+#[derive(Debug, Default)]
+pub struct ToastQueue {
+    toasts: Vec<Toast>,
+}
+
+impl ToastQueue {
+    pub fn push(&mut self, kind: ToastKind, text: impl Into<String>) {
+        self.toasts.push(Toast {
+            text: text.into(),
+            kind,
+            created: Instant::now(),
+        });
+    }
+
+    /// Push unless a toast with the same text is still on screen
+    pub fn push_unique(&mut self, kind: ToastKind, text: impl Into<String>) {
+        let text = text.into();
+        if !self.iter().any(|t| t.text == text) {
+            self.push(kind, text);
+        }
+    }
+
+    /// Live toasts, oldest first
+    pub fn iter(&self) -> impl Iterator<Item = &Toast> {
+        self.toasts.iter()
+    }
+
+    /// Drop every toast that has been up for the full lifetime
+    pub fn prune(&mut self, now: Instant) {
+        self.toasts
+            .retain(|t| now.saturating_duration_since(t.created) < TOAST_LIFETIME);
+    }
+
+    /// Draw the live toasts stacked in the top-right corner
+    pub fn show(&mut self, ctx: &egui::Context) {
+        self.prune(Instant::now());
+        if self.toasts.is_empty() {
+            return;
+        }
+
+        egui::Area::new("toasts".into())
+            .order(egui::Order::Tooltip)
+            .anchor(egui::Align2::RIGHT_TOP, egui::vec2(-12.0, 56.0))
+            .interactable(false)
+            .show(ctx, |ui| {
+                for toast in &self.toasts {
+                    let color = toast.kind.color();
+                    egui::Frame::none()
+                        .fill(Palette::BG_PANEL)
+                        .stroke(egui::Stroke::new(1.5, color))
+                        .rounding(6.0)
+                        .inner_margin(egui::Margin::symmetric(12.0, 8.0))
+                        .show(ui, |ui| {
+                            ui.label(egui::RichText::new(&toast.text).color(color));
+                        });
+                    ui.add_space(6.0);
+                }
+            });
+        // Keep repainting so toasts disappear on time without other input
+        ctx.request_repaint_after(Duration::from_millis(250));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prune_drops_expired_and_keeps_active() {
+        let mut queue = ToastQueue::default();
+        queue.push(ToastKind::Success, "old");
+        queue.push(ToastKind::Error, "new");
+        let start = queue.toasts[0].created;
+        queue.toasts[0].created = start - Duration::from_secs(2);
+
+        // One second later the first toast has been up for three seconds
+        queue.prune(start + Duration::from_secs(1));
+        let texts: Vec<&str> = queue.toasts.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(texts, vec!["new"]);
+
+        queue.prune(start + TOAST_LIFETIME * 2);
+        assert!(queue.toasts.is_empty());
+    }
+
+    #[test]
+    fn test_push_unique_skips_text_already_showing() {
+        let mut queue = ToastQueue::default();
+        queue.push_unique(ToastKind::Error, "Nope");
+        queue.push_unique(ToastKind::Error, "Nope");
+        queue.push_unique(ToastKind::Error, "Other");
+        assert_eq!(queue.iter().count(), 2);
+
+        // Once the first has expired the same text can show again
+        queue.prune(Instant::now() + TOAST_LIFETIME);
+        queue.push_unique(ToastKind::Error, "Nope");
+        assert_eq!(queue.iter().count(), 1);
+    }
+}